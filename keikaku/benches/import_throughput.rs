@@ -0,0 +1,92 @@
+//! Скорость разбора слов при импорте: с новым токенизатором на каждое слово,
+//! как было раньше, и с общим токенизатором процесса.
+//!
+//! Запуск: `cargo bench -p keikaku --bench import_throughput`
+//!
+//! ## Замеры
+//!
+//! Результаты еще не записаны. Сборке нужна сеть: `lindera-unidic` при сборке скачивает
+//! словарь UniDic, а без него не собирается ни бенчмарк, ни WASM-сборка. После прогона
+//! сюда переносится вывод бенчмарка целиком: первая строка — платформа и профиль,
+//! затем `Tokenizer::new` на каждое слово (как было до изменения) и `Tokenizer::shared`.
+//!
+//! Память WASM-сборки меряется вручную: `dx build --release --platform web`, открыть
+//! приложение в Chrome, в DevTools → Memory снять Heap snapshot до и после импорта
+//! колоды и сравнить размер `WebAssembly.Memory`. Записывается прирост после первого
+//! разбора (загрузка словаря) и после импорта (с общим токенизатором он не должен расти
+//! с размером колоды)
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use keikaku::domain::{japanese::IsJapaneseText, tokenizer::Tokenizer};
+
+const WORDS: [&str; 20] = [
+    "食べる",
+    "飲む",
+    "日本語",
+    "勉強する",
+    "先生",
+    "学校",
+    "美味しい",
+    "静か",
+    "電車",
+    "友達",
+    "読む",
+    "書く",
+    "新しい",
+    "図書館",
+    "旅行",
+    "天気",
+    "病院",
+    "仕事",
+    "映画",
+    "音楽",
+];
+/// Слов в одном прогоне: как в небольшой колоде Anki
+const IMPORT_SIZE: usize = 200;
+
+fn main() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    println!(
+        "{} {}, профиль {profile}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+
+    let per_call = measure(|word| {
+        let tokenizer = Tokenizer::new().unwrap();
+        black_box(tokenizer.tokenize(word).unwrap());
+    });
+    report("Tokenizer::new на каждое слово", per_call);
+
+    let shared = measure(|word| {
+        black_box(Tokenizer::shared().unwrap().tokenize(word).unwrap());
+    });
+    report("Tokenizer::shared", shared);
+
+    let furigana = measure(|word| {
+        black_box(word.as_furigana().unwrap());
+    });
+    report("Фуригана через общий токенизатор", furigana);
+}
+
+fn measure(mut process: impl FnMut(&str)) -> Duration {
+    // Первый вызов загружает общий словарь, его время в замер не входит
+    process(WORDS[0]);
+
+    let started = Instant::now();
+    for word in WORDS.iter().cycle().take(IMPORT_SIZE) {
+        process(word);
+    }
+    started.elapsed()
+}
+
+fn report(name: &str, elapsed: Duration) {
+    let words_per_second = IMPORT_SIZE as f64 / elapsed.as_secs_f64();
+    println!("{name}: {IMPORT_SIZE} слов за {elapsed:.2?} ({words_per_second:.0} слов/с)");
+}
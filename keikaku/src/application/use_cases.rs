@@ -1,30 +1,76 @@
+pub mod add_card_tags;
+pub mod add_grammar_cards;
+pub mod apply_placement_result;
+pub mod check_conjugation_answer;
+pub mod check_typed_answer;
 pub mod complete_lesson;
+pub mod create_sentence_card;
 pub mod create_vocabulary_card;
 pub mod delete_card;
+pub mod detect_grammar;
+pub mod edit_card;
+pub mod find_duplicate_cards;
 pub mod generate_card_content;
+pub mod get_conjugation_table;
 pub mod get_kanji_info;
+pub mod get_known_kanji;
 pub mod get_user_info;
 pub mod import_anki_pack;
 pub mod import_jlpt_recommended;
 pub mod import_migii_pack;
 pub mod knowledge_set_cards;
+pub mod mark_cards_as_known;
+pub mod merge_cards;
+pub mod preview_vocabulary_cards;
 pub mod rate_card;
+pub mod remove_card_tags;
+pub mod rename_tag;
+pub mod reset_card_progress;
 pub mod select_cards_to_fixation;
 pub mod select_cards_to_lesson;
+pub mod set_card_notes;
+pub mod set_review_directions;
+pub mod start_conjugation_drill;
 pub mod sync_duolingo_words;
+pub mod sync_kanji_cards;
 pub mod update_user_settings;
 
+pub use add_card_tags::AddCardTagsUseCase;
+pub use add_grammar_cards::{AddGrammarCardsUseCase, GrammarRuleItem};
+pub use apply_placement_result::{
+    ApplyPlacementResult, ApplyPlacementResultOptions, ApplyPlacementResultUseCase,
+};
+pub use check_conjugation_answer::{CheckConjugationAnswerUseCase, ConjugationAnswerResult};
+pub use check_typed_answer::{CheckTypedAnswerUseCase, TypedAnswerResult};
 pub use complete_lesson::CompleteLessonUseCase;
+pub use create_sentence_card::{CreateSentenceCardUseCase, SentenceClozeCandidate};
 pub use create_vocabulary_card::CreateVocabularyCardUseCase;
 pub use delete_card::DeleteCardUseCase;
+pub use detect_grammar::{DetectGrammarUseCase, DetectedGrammar};
+pub use edit_card::{EditCardRequest, EditCardUseCase};
+pub use find_duplicate_cards::FindDuplicateCardsUseCase;
 pub use generate_card_content::GenerateCardContentUseCase;
+pub use get_conjugation_table::GetConjugationTableUseCase;
 pub use get_kanji_info::GetKanjiInfoUseCase;
+pub use get_known_kanji::GetKnownKanjiUseCase;
 pub use get_user_info::{GetUserInfoUseCase, UserProfile};
-pub use import_anki_pack::{AnkiCard, ExportAnkiPackUseCase, ImportAnkiPackResult};
+pub use import_anki_pack::{
+    AnkiCard, AnkiDryRunResult, ExportAnkiPackUseCase, ImportAnkiPackResult,
+};
 pub use import_jlpt_recommended::{ExportJlptRecommendedUseCase, ImportJlptRecommendedResult};
 pub use import_migii_pack::{ExportMigiiPackUseCase, ImportMigiiPackResult};
 pub use knowledge_set_cards::KnowledgeSetCardsUseCase;
+pub use mark_cards_as_known::{MarkAsKnownOptions, MarkCardsAsKnownUseCase};
+pub use merge_cards::MergeCardsUseCase;
+pub use preview_vocabulary_cards::{PreviewVocabularyCardsUseCase, VocabularyCandidate};
 pub use rate_card::RateCardUseCase;
+pub use remove_card_tags::RemoveCardTagsUseCase;
+pub use rename_tag::RenameTagUseCase;
+pub use reset_card_progress::ResetCardProgressUseCase;
 pub use select_cards_to_lesson::SelectCardsToLessonUseCase;
+pub use set_card_notes::SetCardNotesUseCase;
+pub use set_review_directions::SetReviewDirectionsUseCase;
+pub use start_conjugation_drill::{ConjugationRuleStats, StartConjugationDrillUseCase};
 pub use sync_duolingo_words::{SyncDuolingoWordsResult, SyncDuolingoWordsUseCase};
+pub use sync_kanji_cards::SyncKanjiCardsUseCase;
 pub use update_user_settings::{UpdateUserSettingsRequest, UpdateUserSettingsUseCase};
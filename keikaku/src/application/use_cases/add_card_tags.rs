@@ -0,0 +1,36 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use std::collections::BTreeSet;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct AddCardTagsUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> AddCardTagsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_ids: Vec<Ulid>,
+        tags: BTreeSet<String>,
+    ) -> Result<usize, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        for card_id in &card_ids {
+            user.add_card_tags(*card_id, &tags)?;
+        }
+
+        self.repository.save(&user).await?;
+
+        Ok(card_ids.len())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::grammar::{find_grammar_rule, grammar_rules};
+use crate::domain::knowledge::{Card, GrammarRuleCard, StudyCard};
+use crate::domain::value_objects::JapaneseLevel;
+use ulid::Ulid;
+
+/// Встроенное правило грамматики с отметкой, изучает ли его пользователь
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrammarRuleItem {
+    pub id: String,
+    pub title: String,
+    pub level: JapaneseLevel,
+    pub md_description: String,
+    pub is_studying: bool,
+}
+
+#[derive(Clone)]
+pub struct AddGrammarCardsUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> AddGrammarCardsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Все встроенные правила на языке пользователя, от простых к сложным
+    pub async fn rules(&self, user_id: Ulid) -> Result<Vec<GrammarRuleItem>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+        let lang = user.native_language();
+
+        let mut rules = grammar_rules()
+            .iter()
+            .map(|rule| GrammarRuleItem {
+                id: rule.id().to_string(),
+                title: rule.title(lang),
+                level: rule.level(),
+                md_description: rule.md_description(lang),
+                is_studying: user.knowledge_set().has_grammar_card(rule.as_ref(), lang),
+            })
+            .collect::<Vec<_>>();
+        rules.sort_by_key(|rule| rule.level);
+
+        Ok(rules)
+    }
+
+    /// Создает карточки выбранных правил. Уже изучаемые правила пропускаются
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        rule_ids: Vec<String>,
+    ) -> Result<Vec<StudyCard>, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+        let lang = user.native_language().clone();
+
+        let mut cards = Vec::new();
+        for rule_id in rule_ids {
+            let rule = find_grammar_rule(&rule_id).ok_or(KeikakuError::InvalidValues {
+                reason: format!("Unknown grammar rule: {rule_id}"),
+            })?;
+            if user.knowledge_set().has_grammar_card(rule.as_ref(), &lang) {
+                continue;
+            }
+
+            let card = GrammarRuleCard::new(rule, &lang)?;
+            cards.push(user.create_card(Card::Grammar(card))?);
+        }
+
+        self.repository.save(&user).await?;
+
+        Ok(cards)
+    }
+
+    /// Создает карточки всех правил не выше текущего уровня пользователя
+    pub async fn execute_up_to_level(&self, user_id: Ulid) -> Result<Vec<StudyCard>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+        let level = *user.current_japanese_level();
+
+        let rule_ids = grammar_rules()
+            .iter()
+            .filter(|rule| rule.level() <= level)
+            .map(|rule| rule.id().to_string())
+            .collect();
+
+        self.execute(user_id, rule_ids).await
+    }
+}
@@ -0,0 +1,128 @@
+use super::generate_card_content::dictionary_content;
+use super::mark_cards_as_known::mark_as_known;
+use crate::application::{MarkAsKnownOptions, UserRepository};
+use crate::domain::User;
+use crate::domain::dictionary::JLPT_DB;
+use crate::domain::error::KeikakuError;
+use crate::domain::knowledge::{Card, VocabularyCard};
+use crate::domain::placement::PlacementResult;
+use crate::domain::tokenizer::Tokenizer;
+use crate::domain::value_objects::{JapaneseLevel, Question};
+use std::collections::{BTreeSet, HashSet};
+use ulid::Ulid;
+
+pub struct ApplyPlacementResultOptions {
+    /// Установить оцененный уровень как текущий
+    pub set_level: bool,
+    /// Создать карточки для вероятно известных слов и отметить их известными
+    pub mark_as_known: Option<MarkAsKnownOptions>,
+}
+
+pub struct ApplyPlacementResult {
+    pub current_japanese_level: JapaneseLevel,
+    pub created_count: usize,
+    pub known_count: usize,
+    /// Слова, для которых карточка не создана: уже есть в колоде или нет в словаре
+    pub skipped_words: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct ApplyPlacementResultUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> ApplyPlacementResultUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Применяет результат теста за одно сохранение пользователя. Карточки создаются
+    /// только из словаря: слов из освоенных уровней сотни, и генерировать их через LLM
+    /// слишком долго, поэтому слова без перевода в словаре пропускаются
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        result: &PlacementResult,
+        options: ApplyPlacementResultOptions,
+    ) -> Result<ApplyPlacementResult, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        if options.set_level {
+            user.set_current_japanese_level(*result.estimated_level());
+        }
+
+        let mut created_card_ids = Vec::new();
+        let mut skipped_words = Vec::new();
+        let mut known_count = 0;
+
+        if let Some(mark_as_known_options) = options.mark_as_known {
+            let mut seen = HashSet::new();
+            let words = result
+                .mastered_levels()
+                .iter()
+                .flat_map(|level| JLPT_DB.get_words_for_level(level))
+                .chain(result.known_words().iter().cloned())
+                .filter(|word| seen.insert(word.clone()));
+
+            let tokenizer = Tokenizer::shared()?;
+            for word in words {
+                match create_dictionary_card(&mut user, tokenizer, &word)? {
+                    Some(card_id) => created_card_ids.push(card_id),
+                    None => skipped_words.push(word),
+                }
+            }
+
+            known_count = mark_as_known(&mut user, &created_card_ids, mark_as_known_options)?;
+        }
+
+        self.repository.save(&user).await?;
+
+        Ok(ApplyPlacementResult {
+            current_japanese_level: *user.current_japanese_level(),
+            created_count: created_card_ids.len(),
+            known_count,
+            skipped_words,
+        })
+    }
+}
+
+/// Карточка слова из словаря с тегом уровня JLPT. `None`, если слова нет в словаре
+/// или карточка для него уже есть
+fn create_dictionary_card(
+    user: &mut User,
+    tokenizer: &Tokenizer,
+    word: &str,
+) -> Result<Option<Ulid>, KeikakuError> {
+    let Some(content) = dictionary_content(word, user.native_language())? else {
+        return Ok(None);
+    };
+
+    let reading = tokenizer
+        .tokenize(word)?
+        .iter()
+        .map(|token| token.kana_surface_form())
+        .collect::<String>();
+    let question = Question::new(word.to_string())?;
+    let card = Card::Vocabulary(VocabularyCard::new(
+        question,
+        content.answer,
+        content.examples,
+        Some(reading),
+    ));
+
+    let card_id = match user.create_card(card) {
+        Ok(study_card) => *study_card.card_id(),
+        Err(KeikakuError::DuplicateCard { .. }) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    if let Some(level) = JLPT_DB.get_level(word) {
+        user.add_card_tags(card_id, &BTreeSet::from([format!("jlpt:{}", level.code())]))?;
+    }
+
+    Ok(Some(card_id))
+}
@@ -0,0 +1,45 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::knowledge::ConjugationExercise;
+use crate::domain::typed_answer::check_conjugation;
+use ulid::Ulid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConjugationAnswerResult {
+    pub is_correct: bool,
+    pub expected: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct CheckConjugationAnswerUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> CheckConjugationAnswerUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Проверяет ответ на упражнение и записывает его в статистику правила
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        exercise: &ConjugationExercise,
+        answer: &str,
+    ) -> Result<ConjugationAnswerResult, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let check = check_conjugation(exercise.expected(), answer)?;
+        user.record_conjugation_answer(exercise.rule_id(), check.is_correct());
+        self.repository.save(&user).await?;
+
+        Ok(ConjugationAnswerResult {
+            is_correct: check.is_correct(),
+            expected: exercise.expected().to_vec(),
+        })
+    }
+}
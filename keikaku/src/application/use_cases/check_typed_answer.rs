@@ -0,0 +1,69 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::knowledge::Card;
+use crate::domain::typed_answer::{check_reading, check_word};
+use crate::domain::value_objects::{Rating, ReviewDirection};
+use chrono::Duration;
+use ulid::Ulid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedAnswerResult {
+    pub is_correct: bool,
+    pub expected: Vec<String>,
+    pub suggested_rating: Rating,
+}
+
+#[derive(Clone)]
+pub struct CheckTypedAnswerUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> CheckTypedAnswerUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Проверяет введенный ответ. Для чтения ожидается кана, для воспроизведения — слово
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_id: Ulid,
+        direction: ReviewDirection,
+        answer: String,
+        answer_time: Duration,
+    ) -> Result<TypedAnswerResult, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let card = user
+            .knowledge_set()
+            .get_card(card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+
+        let Card::Vocabulary(vocabulary) = card.card() else {
+            return Err(KeikakuError::InvalidValues {
+                reason: "Typed answers are supported only for vocabulary cards".to_string(),
+            });
+        };
+
+        let word = vocabulary.word().text();
+        let check = match direction {
+            ReviewDirection::Reading => check_reading(word, vocabulary.reading(), &answer)?,
+            ReviewDirection::Production => check_word(word, &answer)?,
+            ReviewDirection::Recognition => {
+                return Err(KeikakuError::InvalidValues {
+                    reason: "Typed answers are not supported for recognition".to_string(),
+                });
+            }
+        };
+
+        Ok(TypedAnswerResult {
+            is_correct: check.is_correct(),
+            expected: check.expected().to_vec(),
+            suggested_rating: check.suggested_rating(answer_time),
+        })
+    }
+}
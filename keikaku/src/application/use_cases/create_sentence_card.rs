@@ -0,0 +1,97 @@
+use crate::application::UserRepository;
+use crate::domain::User;
+use crate::domain::error::KeikakuError;
+use crate::domain::knowledge::{
+    Card, ClozeCandidate, ClozeDeletion, SentenceCard, StudyCard, propose_clozes,
+};
+use crate::domain::tokenizer::Tokenizer;
+use crate::domain::value_objects::{Answer, Question};
+use std::collections::{BTreeSet, HashMap};
+use ulid::Ulid;
+
+/// Возможный пропуск и карточка этого слова, если она уже есть
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentenceClozeCandidate {
+    pub candidate: ClozeCandidate,
+    pub vocabulary_card_id: Option<Ulid>,
+}
+
+#[derive(Clone)]
+pub struct CreateSentenceCardUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> CreateSentenceCardUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Разбирает предложение на слова, которые можно вырезать в пропуски
+    pub async fn propose_clozes(
+        &self,
+        user_id: Ulid,
+        sentence: &str,
+    ) -> Result<Vec<SentenceClozeCandidate>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let vocabulary = vocabulary_card_ids(&user);
+        let tokenizer = Tokenizer::shared()?;
+
+        Ok(propose_clozes(tokenizer, sentence.trim())?
+            .into_iter()
+            .map(|candidate| SentenceClozeCandidate {
+                vocabulary_card_id: vocabulary.get(candidate.base_form()).copied(),
+                candidate,
+            })
+            .collect())
+    }
+
+    /// Создает карточку предложения и связывает ее с карточками встречающихся в нем слов
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        sentence: String,
+        translation: String,
+        clozes: Vec<ClozeDeletion>,
+    ) -> Result<StudyCard, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let sentence = Question::new(sentence)?;
+        let vocabulary = vocabulary_card_ids(&user);
+        let tokenizer = Tokenizer::shared()?;
+        let linked_card_ids = tokenizer
+            .tokenize(sentence.text())?
+            .iter()
+            .filter_map(|token| vocabulary.get(token.orthographic_base_form()).copied())
+            .collect::<BTreeSet<_>>();
+
+        let card = SentenceCard::new(sentence, Answer::new(translation)?, clozes, linked_card_ids)?;
+        let card = user.create_card(Card::Sentence(card))?;
+
+        self.repository.save(&user).await?;
+
+        Ok(card)
+    }
+}
+
+/// Карточки слов по их словарной форме
+fn vocabulary_card_ids(user: &User) -> HashMap<String, Ulid> {
+    user.knowledge_set()
+        .study_cards()
+        .values()
+        .filter_map(|card| match card.card() {
+            Card::Vocabulary(vocabulary) => {
+                Some((vocabulary.word().text().to_string(), *card.card_id()))
+            }
+            _ => None,
+        })
+        .collect()
+}
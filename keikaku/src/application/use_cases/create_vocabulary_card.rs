@@ -1,9 +1,11 @@
 use super::generate_card_content::GenerateCardContentUseCase;
+use super::preview_vocabulary_cards::VocabularyCandidate;
 use crate::application::UserRepository;
 use crate::domain::error::KeikakuError;
 use crate::domain::knowledge::{Card, StudyCard, VocabularyCard};
 use crate::domain::tokenizer::Tokenizer;
 use crate::domain::value_objects::Question;
+use std::collections::BTreeSet;
 use tracing::error;
 use ulid::Ulid;
 
@@ -27,6 +29,17 @@ impl<'a, R: UserRepository, L: crate::application::LlmService>
         &self,
         user_id: Ulid,
         question_text: String,
+    ) -> Result<Vec<StudyCard>, KeikakuError> {
+        self.execute_with_tags(user_id, question_text, BTreeSet::new())
+            .await
+    }
+
+    /// Создает карточки и сразу помечает их тегами (например, источником импорта)
+    pub async fn execute_with_tags(
+        &self,
+        user_id: Ulid,
+        question_text: String,
+        tags: BTreeSet<String>,
     ) -> Result<Vec<StudyCard>, KeikakuError> {
         let mut user = self
             .repository
@@ -34,7 +47,32 @@ impl<'a, R: UserRepository, L: crate::application::LlmService>
             .await?
             .ok_or(KeikakuError::UserNotFound { user_id })?;
 
-        let cards = self.create(&mut user, question_text).await?;
+        let cards = self.create(&mut user, question_text, &tags).await?;
+
+        self.repository.save(&user).await?;
+
+        Ok(cards)
+    }
+
+    /// Создает карточки только для слов, выбранных в предпросмотре
+    pub async fn execute_candidates(
+        &self,
+        user_id: Ulid,
+        candidates: Vec<VocabularyCandidate>,
+    ) -> Result<Vec<StudyCard>, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let words = candidates
+            .into_iter()
+            .map(|candidate| (candidate.base_form, candidate.reading))
+            .collect();
+        let cards = self
+            .create_words(&mut user, words, &BTreeSet::new())
+            .await?;
 
         self.repository.save(&user).await?;
 
@@ -45,31 +83,51 @@ impl<'a, R: UserRepository, L: crate::application::LlmService>
         &self,
         user: &mut crate::domain::User,
         question_text: String,
+        tags: &BTreeSet<String>,
     ) -> Result<Vec<StudyCard>, KeikakuError> {
-        let tokenizer = Tokenizer::new()?;
-        let tokens = tokenizer.tokenize(question_text.as_str())?;
-        let mut cards = Vec::new();
+        let tokenizer = Tokenizer::shared()?;
+        let words = tokenizer
+            .tokenize(question_text.as_str())?
+            .into_iter()
+            .filter(|token| token.part_of_speech().is_vocabulary_word())
+            .map(|token| {
+                (
+                    token.orthographic_base_form().to_string(),
+                    token.kana_base_form(),
+                )
+            })
+            .collect();
 
-        for token in tokens {
-            if !token.part_of_speech().is_vocabulary_word() {
-                continue;
-            }
+        self.create_words(user, words, tags).await
+    }
 
-            let question_text = token.orthographic_base_form();
-            let question = Question::new(question_text.to_string())?;
+    /// Создает карточки слов по словарной форме и чтению
+    async fn create_words(
+        &self,
+        user: &mut crate::domain::User,
+        words: Vec<(String, String)>,
+        tags: &BTreeSet<String>,
+    ) -> Result<Vec<StudyCard>, KeikakuError> {
+        let mut cards = Vec::new();
+
+        for (question_text, reading) in words {
+            let question = Question::new(question_text.clone())?;
             let content = self
                 .generate_content_use_case
                 .generate_content(
-                    question_text,
+                    &question_text,
                     user.native_language(),
                     user.current_japanese_level(),
                 )
                 .await?;
 
-            let vocabulary_card = VocabularyCard::new(question, content.answer, content.examples);
+            let vocabulary_card =
+                VocabularyCard::new(question, content.answer, content.examples, Some(reading));
             let card = Card::Vocabulary(vocabulary_card);
 
-            let card_result = user.create_card(card);
+            let card_result = user
+                .create_card(card)
+                .and_then(|card| tag_card(user, card, tags));
 
             if let Ok(card) = card_result {
                 cards.push(card);
@@ -81,3 +139,21 @@ impl<'a, R: UserRepository, L: crate::application::LlmService>
         Ok(cards)
     }
 }
+
+fn tag_card(
+    user: &mut crate::domain::User,
+    card: StudyCard,
+    tags: &BTreeSet<String>,
+) -> Result<StudyCard, KeikakuError> {
+    if tags.is_empty() {
+        return Ok(card);
+    }
+
+    let card_id = *card.card_id();
+    user.add_card_tags(card_id, tags)?;
+
+    user.knowledge_set()
+        .get_card(card_id)
+        .cloned()
+        .ok_or(KeikakuError::CardNotFound { card_id })
+}
@@ -0,0 +1,62 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::grammar::detection::detect_grammar;
+use crate::domain::grammar::find_grammar_rule;
+use crate::domain::value_objects::JapaneseLevel;
+use ulid::Ulid;
+
+/// Правило грамматики, использованное в тексте
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedGrammar {
+    pub rule_id: String,
+    pub title: String,
+    pub level: JapaneseLevel,
+    pub fragment: String,
+    pub is_studying: bool,
+}
+
+#[derive(Clone)]
+pub struct DetectGrammarUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> DetectGrammarUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Правила, конструкции которых встречаются в тексте, с отметкой о карточке пользователя.
+    /// Каждое правило возвращается один раз, по первому вхождению
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        text: &str,
+    ) -> Result<Vec<DetectedGrammar>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+        let lang = user.native_language();
+
+        let mut detected: Vec<DetectedGrammar> = Vec::new();
+        for found in detect_grammar(text)? {
+            if detected.iter().any(|item| item.rule_id == found.rule_id()) {
+                continue;
+            }
+            let Some(rule) = find_grammar_rule(found.rule_id()) else {
+                continue;
+            };
+
+            detected.push(DetectedGrammar {
+                rule_id: rule.id().to_string(),
+                title: rule.title(lang),
+                level: rule.level(),
+                fragment: found.fragment().to_string(),
+                is_studying: user.knowledge_set().has_grammar_card(rule.as_ref(), lang),
+            });
+        }
+
+        Ok(detected)
+    }
+}
@@ -0,0 +1,101 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::knowledge::{Card, ClozeDeletion, ExampleKanjiWord, StudyCard, VocabularyCard};
+use crate::domain::value_objects::{Answer, ExamplePhrase, Question};
+use ulid::Ulid;
+
+/// Новое содержимое карты. Вариант должен совпадать с типом редактируемой карты
+#[derive(Debug, Clone)]
+pub enum EditCardRequest {
+    Vocabulary {
+        word: String,
+        meaning: String,
+        example_phrases: Vec<ExamplePhrase>,
+        reading: Option<String>,
+    },
+    Kanji {
+        description: String,
+        example_words: Vec<ExampleKanjiWord>,
+    },
+    Grammar {
+        description: String,
+    },
+    Sentence {
+        translation: String,
+        clozes: Vec<ClozeDeletion>,
+    },
+}
+
+#[derive(Clone)]
+pub struct EditCardUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> EditCardUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_id: Ulid,
+        request: EditCardRequest,
+    ) -> Result<StudyCard, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let current = user
+            .knowledge_set()
+            .get_card(card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+
+        let card = match (current.card(), request) {
+            (
+                Card::Vocabulary(_),
+                EditCardRequest::Vocabulary {
+                    word,
+                    meaning,
+                    example_phrases,
+                    reading,
+                },
+            ) => Card::Vocabulary(VocabularyCard::new(
+                Question::new(word)?,
+                Answer::new(meaning)?,
+                example_phrases,
+                reading,
+            )),
+            (
+                Card::Kanji(kanji),
+                EditCardRequest::Kanji {
+                    description,
+                    example_words,
+                },
+            ) => Card::Kanji(kanji.with_content(Answer::new(description)?, example_words)),
+            (Card::Grammar(grammar), EditCardRequest::Grammar { description }) => {
+                Card::Grammar(grammar.with_description(Answer::new(description)?))
+            }
+            (
+                Card::Sentence(sentence),
+                EditCardRequest::Sentence {
+                    translation,
+                    clozes,
+                },
+            ) => Card::Sentence(sentence.with_content(Answer::new(translation)?, clozes)?),
+            _ => {
+                return Err(KeikakuError::InvalidValues {
+                    reason: "Edit request does not match card type".to_string(),
+                });
+            }
+        };
+
+        let card = user.edit_card(card_id, card)?;
+
+        self.repository.save(&user).await?;
+
+        Ok(card)
+    }
+}
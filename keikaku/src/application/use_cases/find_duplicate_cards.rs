@@ -0,0 +1,58 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::knowledge::StudyCard;
+use crate::domain::tokenizer::Tokenizer;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct FindDuplicateCardsUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> FindDuplicateCardsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Отчет о вероятных дубликатах: группы карточек слов с одной словарной формой и чтением
+    pub async fn execute(&self, user_id: Ulid) -> Result<Vec<Vec<StudyCard>>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let tokenizer = Tokenizer::shared()?;
+        let groups = user
+            .knowledge_set()
+            .find_duplicate_groups(tokenizer)?
+            .into_iter()
+            .map(|group| group.into_iter().cloned().collect())
+            .collect();
+
+        Ok(groups)
+    }
+
+    /// Существующие карточки, которые скорее всего записывают то же слово, что и текст
+    pub async fn find_for_text(
+        &self,
+        user_id: Ulid,
+        text: &str,
+    ) -> Result<Vec<StudyCard>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let tokenizer = Tokenizer::shared()?;
+        let duplicates = user
+            .knowledge_set()
+            .find_likely_duplicates(tokenizer, text)?
+            .into_iter()
+            .cloned()
+            .collect();
+
+        Ok(duplicates)
+    }
+}
@@ -34,7 +34,7 @@ impl<'a, L: LlmService> GenerateCardContentUseCase<'a, L> {
         native_language: &NativeLanguage,
         japanese_level: &JapaneseLevel,
     ) -> Result<CardContent, KeikakuError> {
-        if let Some(result) = self.try_get_from_dictionary(question_text, native_language)? {
+        if let Some(result) = dictionary_content(question_text, native_language)? {
             return Ok(result);
         }
 
@@ -42,23 +42,6 @@ impl<'a, L: LlmService> GenerateCardContentUseCase<'a, L> {
             .await
     }
 
-    fn try_get_from_dictionary(
-        &self,
-        question_text: &str,
-        native_language: &NativeLanguage,
-    ) -> Result<Option<CardContent>, KeikakuError> {
-        if let Some(translation) = VOCABULARY_DB.get_translation(question_text, native_language) {
-            let answer = Answer::new(translation)?;
-            if let Some(examples) = VOCABULARY_DB.get_examples(question_text, native_language)
-                && !examples.is_empty()
-            {
-                return Ok(Some(CardContent { answer, examples }));
-            }
-        }
-
-        Ok(None)
-    }
-
     async fn generate_with_llm(
         &self,
         question_text: &str,
@@ -110,6 +93,33 @@ impl<'a, L: LlmService> GenerateCardContentUseCase<'a, L> {
     }
 }
 
+/// Перевод с примерами из словаря. `None`, если для карточки нужен LLM
+pub fn dictionary_content(
+    question_text: &str,
+    native_language: &NativeLanguage,
+) -> Result<Option<CardContent>, KeikakuError> {
+    if let Some(translation) = VOCABULARY_DB.get_translation(question_text, native_language) {
+        let answer = Answer::new(translation)?;
+        if let Some(examples) = VOCABULARY_DB.get_examples(question_text, native_language)
+            && !examples.is_empty()
+        {
+            return Ok(Some(CardContent { answer, examples }));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Есть ли у слова перевод с примерами в словаре, то есть карточку можно создать без LLM
+pub fn has_dictionary_content(question_text: &str, native_language: &NativeLanguage) -> bool {
+    VOCABULARY_DB
+        .get_translation(question_text, native_language)
+        .is_some()
+        && VOCABULARY_DB
+            .get_examples(question_text, native_language)
+            .is_some_and(|examples| !examples.is_empty())
+}
+
 fn build_prompt(
     question_text: &str,
     native_language: &NativeLanguage,
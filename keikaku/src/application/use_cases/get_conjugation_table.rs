@@ -0,0 +1,20 @@
+use crate::domain::{error::KeikakuError, grammar::conjugation::ConjugationTable};
+
+pub struct GetConjugationTableUseCase;
+
+impl Default for GetConjugationTableUseCase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GetConjugationTableUseCase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Таблица спряжения слова. Ошибка, если слово не глагол и не прилагательное
+    pub fn execute(&self, word: &str) -> Result<ConjugationTable, KeikakuError> {
+        ConjugationTable::for_word(word)
+    }
+}
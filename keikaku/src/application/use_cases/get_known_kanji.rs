@@ -0,0 +1,31 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::FuriganaMode;
+use crate::domain::error::KeikakuError;
+use std::collections::HashSet;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct GetKnownKanjiUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> GetKnownKanjiUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Кандзи, над которыми не нужна фуригана. `None` — по настройкам пользователя
+    /// фуригана показывается над всеми кандзи
+    pub async fn execute(&self, user_id: Ulid) -> Result<Option<HashSet<char>>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        match user.settings().furigana_mode() {
+            FuriganaMode::All => Ok(None),
+            FuriganaMode::UnknownKanji => Ok(Some(user.knowledge_set().known_kanji())),
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use crate::application::{CreateVocabularyCardUseCase, LlmService, UserRepository};
 use crate::domain::error::KeikakuError;
+use crate::domain::knowledge::Card;
+use crate::domain::tokenizer::Tokenizer;
 use regex::Regex;
 use rusqlite::Connection;
 use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
 use std::fs::File;
 use std::io::Cursor;
 use std::path::PathBuf;
@@ -11,11 +14,13 @@ use zip::ZipArchive;
 
 const ANKI_DATABASE_FILE: &str = "collection.anki21";
 const FIELD_SEPARATOR: char = '\x1f';
+const DECK_NAME_SEPARATOR: &str = "::";
 
 #[derive(Debug, Clone)]
 pub struct AnkiCard {
     pub word: String,
     pub translation: Option<String>,
+    pub deck: Option<String>,
 }
 
 pub struct ImportAnkiPackResult {
@@ -23,6 +28,14 @@ pub struct ImportAnkiPackResult {
     pub skipped_words: Vec<String>,
 }
 
+/// Пробный импорт: какие слова колоды новые, а какие уже есть среди карточек
+pub struct AnkiDryRunResult {
+    pub new_words: Vec<String>,
+    pub duplicate_words: Vec<String>,
+    /// Слово колоды и карточки, которые скорее всего записывают то же слово (食べる и たべる)
+    pub likely_duplicates: Vec<(String, Vec<String>)>,
+}
+
 pub struct ExportAnkiPackUseCase<'a, R: UserRepository, L: LlmService> {
     repository: &'a R,
     create_card_use_case: CreateVocabularyCardUseCase<'a, R, L>,
@@ -56,6 +69,54 @@ impl<'a, R: UserRepository, L: LlmService> ExportAnkiPackUseCase<'a, R, L> {
         })
     }
 
+    /// Разбирает колоду и сверяет слова с карточками пользователя, ничего не сохраняя
+    pub async fn dry_run(
+        &self,
+        user_id: Ulid,
+        file_path: &str,
+        word_tag: &str,
+        translation_tag: Option<&str>,
+    ) -> Result<AnkiDryRunResult, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let cards = self
+            .extract_cards(file_path, word_tag, translation_tag)
+            .await?;
+
+        let tokenizer = Tokenizer::shared()?;
+        let lexeme_index = user.knowledge_set().lexeme_index(tokenizer)?;
+        let mut result = AnkiDryRunResult {
+            new_words: Vec::new(),
+            duplicate_words: Vec::new(),
+            likely_duplicates: Vec::new(),
+        };
+
+        for anki_card in cards {
+            let existing = lexeme_index
+                .find_likely_duplicates(tokenizer, &anki_card.word)?
+                .into_iter()
+                .filter_map(|study_card| match study_card.card() {
+                    Card::Vocabulary(vocabulary) => Some(vocabulary.word().text().to_string()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            if existing.is_empty() {
+                result.new_words.push(anki_card.word);
+            } else if existing.contains(&anki_card.word) {
+                result.duplicate_words.push(anki_card.word);
+            } else {
+                result.likely_duplicates.push((anki_card.word, existing));
+            }
+        }
+
+        Ok(result)
+    }
+
     pub async fn execute(
         &self,
         user_id: Ulid,
@@ -85,10 +146,14 @@ impl<'a, R: UserRepository, L: LlmService> ExportAnkiPackUseCase<'a, R, L> {
 
         for anki_card in cards {
             let question = anki_card.word.clone();
+            let tags = anki_card
+                .deck
+                .map(|deck| BTreeSet::from([format!("anki:{}", deck)]))
+                .unwrap_or_default();
 
             match self
                 .create_card_use_case
-                .execute(user_id, question.clone())
+                .execute_with_tags(user_id, question.clone(), tags)
                 .await
             {
                 Ok(_) => {
@@ -118,7 +183,9 @@ impl<'a, R: UserRepository, L: LlmService> ExportAnkiPackUseCase<'a, R, L> {
         let conn = Connection::open(&db_path)?;
         let (word_index, translation_index) =
             Self::find_field_indices(&conn, word_tag, translation_tag)?;
-        let cards = Self::read_cards_from_database(&conn, word_index, translation_index)?;
+        let deck_names = Self::read_deck_names(&conn);
+        let cards =
+            Self::read_cards_from_database(&conn, word_index, translation_index, &deck_names)?;
         Ok(cards)
     }
 
@@ -179,15 +246,59 @@ impl<'a, R: UserRepository, L: LlmService> ExportAnkiPackUseCase<'a, R, L> {
         Ok((word_index, translation_index))
     }
 
+    /// Имена колод по их id. Старые коллекции хранят колоды в JSON `col.decks`,
+    /// новые — в отдельной таблице `decks`
+    fn read_deck_names(conn: &Connection) -> HashMap<i64, String> {
+        let from_col = conn
+            .query_row("SELECT decks FROM col", [], |row| row.get::<_, String>(0))
+            .ok()
+            .and_then(|json_str| serde_json::from_str::<Value>(&json_str).ok())
+            .and_then(|decks| {
+                decks.as_object().map(|decks_map| {
+                    decks_map
+                        .iter()
+                        .filter_map(|(deck_id, deck)| {
+                            Some((
+                                deck_id.parse::<i64>().ok()?,
+                                deck["name"].as_str()?.to_string(),
+                            ))
+                        })
+                        .collect::<HashMap<_, _>>()
+                })
+            })
+            .filter(|decks| !decks.is_empty());
+
+        if let Some(decks) = from_col {
+            return decks;
+        }
+
+        conn.prepare("SELECT id, name FROM decks")
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| {
+                    let name: String = row.get(1)?;
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        name.replace(FIELD_SEPARATOR, DECK_NAME_SEPARATOR),
+                    ))
+                })?
+                .collect::<Result<HashMap<_, _>, _>>()
+            })
+            .unwrap_or_default()
+    }
+
     fn read_cards_from_database(
         conn: &Connection,
         word_index: usize,
         translation_index: Option<usize>,
+        deck_names: &HashMap<i64, String>,
     ) -> Result<Vec<AnkiCard>, Box<dyn std::error::Error>> {
-        let mut stmt = conn.prepare("SELECT flds FROM notes")?;
+        let mut stmt = conn.prepare(
+            "SELECT n.flds, (SELECT c.did FROM cards c WHERE c.nid = n.id LIMIT 1) FROM notes n",
+        )?;
         let rows = stmt.query_map([], |row| {
             let flds: String = row.get(0)?;
-            Ok(flds)
+            let deck_id: Option<i64> = row.get(1)?;
+            Ok((flds, deck_id))
         })?;
 
         let re_html = Regex::new(r"<[^>]*>")?;
@@ -196,7 +307,7 @@ impl<'a, R: UserRepository, L: LlmService> ExportAnkiPackUseCase<'a, R, L> {
         let mut cards = Vec::new();
 
         for row in rows {
-            let flds_str = row?;
+            let (flds_str, deck_id) = row?;
             let fields: Vec<&str> = flds_str.split(FIELD_SEPARATOR).collect();
 
             let raw_word = fields.get(word_index).unwrap_or(&"");
@@ -209,8 +320,14 @@ impl<'a, R: UserRepository, L: LlmService> ExportAnkiPackUseCase<'a, R, L> {
                 None
             };
 
+            let deck = deck_id.and_then(|deck_id| deck_names.get(&deck_id).cloned());
+
             if !word.is_empty() {
-                cards.push(AnkiCard { word, translation });
+                cards.push(AnkiCard {
+                    word,
+                    translation,
+                    deck,
+                });
             }
         }
 
@@ -1,20 +1,27 @@
-use crate::application::{CreateVocabularyCardUseCase, LlmService, UserRepository};
+use crate::application::{
+    CreateVocabularyCardUseCase, LlmService, MarkAsKnownOptions, MarkCardsAsKnownUseCase,
+    UserRepository,
+};
 use crate::domain::{dictionary::JLPT_DB, error::KeikakuError, value_objects::JapaneseLevel};
+use std::collections::BTreeSet;
 use ulid::Ulid;
 
 pub struct ImportJlptRecommendedResult {
     pub total_created_count: usize,
+    pub known_count: usize,
     pub skipped_words: Vec<String>,
 }
 
 pub struct ExportJlptRecommendedUseCase<'a, R: UserRepository, L: LlmService> {
     create_card_use_case: CreateVocabularyCardUseCase<'a, R, L>,
+    mark_as_known_use_case: MarkCardsAsKnownUseCase<'a, R>,
 }
 
 impl<'a, R: UserRepository, L: LlmService> ExportJlptRecommendedUseCase<'a, R, L> {
     pub fn new(repository: &'a R, llm_service: &'a L) -> Self {
         Self {
             create_card_use_case: CreateVocabularyCardUseCase::new(repository, llm_service),
+            mark_as_known_use_case: MarkCardsAsKnownUseCase::new(repository),
         }
     }
 
@@ -22,21 +29,39 @@ impl<'a, R: UserRepository, L: LlmService> ExportJlptRecommendedUseCase<'a, R, L
         &self,
         user_id: Ulid,
         levels: Vec<JapaneseLevel>,
+        mark_as_known: Option<MarkAsKnownOptions>,
     ) -> Result<ImportJlptRecommendedResult, KeikakuError> {
-        let mut total_created_count = 0;
-        let mut total_skipped_words = Vec::new();
+        let words = levels
+            .iter()
+            .flat_map(|level| JLPT_DB.get_words_for_level(level))
+            .collect();
 
-        for level in levels {
-            let words = JLPT_DB.get_words_for_level(&level);
-            let (created, skipped) = self.process_words(user_id, words).await?;
+        self.execute_words(user_id, words, mark_as_known).await
+    }
 
-            total_created_count += created;
-            total_skipped_words.extend(skipped);
-        }
+    /// Импортирует произвольный список слов (например, по итогам теста на уровень)
+    pub async fn execute_words(
+        &self,
+        user_id: Ulid,
+        words: Vec<String>,
+        mark_as_known: Option<MarkAsKnownOptions>,
+    ) -> Result<ImportJlptRecommendedResult, KeikakuError> {
+        let (created_card_ids, skipped_words) = self.process_words(user_id, words).await?;
+        let total_created_count = created_card_ids.len();
+
+        let known_count = match mark_as_known {
+            Some(options) if !created_card_ids.is_empty() => {
+                self.mark_as_known_use_case
+                    .execute(user_id, created_card_ids, options)
+                    .await?
+            }
+            _ => 0,
+        };
 
         Ok(ImportJlptRecommendedResult {
             total_created_count,
-            skipped_words: total_skipped_words,
+            known_count,
+            skipped_words,
         })
     }
 
@@ -44,20 +69,27 @@ impl<'a, R: UserRepository, L: LlmService> ExportJlptRecommendedUseCase<'a, R, L
         &self,
         user_id: Ulid,
         words: Vec<String>,
-    ) -> Result<(usize, Vec<String>), KeikakuError> {
-        let mut created_count = 0;
+    ) -> Result<(Vec<Ulid>, Vec<String>), KeikakuError> {
+        let mut created_card_ids = Vec::new();
         let mut skipped_words = Vec::new();
 
         for word in words {
             let question = word.clone();
+            let tags = JLPT_DB
+                .get_level(&word)
+                .map(|level| BTreeSet::from([format!("jlpt:{}", level.code())]))
+                .unwrap_or_default();
 
             match self
                 .create_card_use_case
-                .execute(user_id, question.clone())
+                .execute_with_tags(user_id, question.clone(), tags)
                 .await
             {
-                Ok(_) => {
-                    created_count += 1;
+                Ok(cards) if cards.is_empty() => {
+                    skipped_words.push(question);
+                }
+                Ok(cards) => {
+                    created_card_ids.extend(cards.iter().map(|card| *card.card_id()));
                 }
                 Err(KeikakuError::DuplicateCard { .. }) => {
                     skipped_words.push(question);
@@ -69,6 +101,6 @@ impl<'a, R: UserRepository, L: LlmService> ExportJlptRecommendedUseCase<'a, R, L
             }
         }
 
-        Ok((created_count, skipped_words))
+        Ok((created_card_ids, skipped_words))
     }
 }
@@ -2,6 +2,7 @@ use crate::application::{
     CreateVocabularyCardUseCase, LlmService, MigiiClient, MigiiWord, UserRepository,
 };
 use crate::domain::error::KeikakuError;
+use std::collections::BTreeSet;
 use ulid::Ulid;
 
 pub struct ImportMigiiPackResult {
@@ -48,7 +49,12 @@ impl<'a, R: UserRepository, L: LlmService, M: MigiiClient> ExportMigiiPackUseCas
                 )
                 .await?;
 
-            let (created, skipped) = self.process_words(user_id, words).await?;
+            let tags = BTreeSet::from([format!(
+                "migii:{}:lesson_{}",
+                user.current_japanese_level().code(),
+                lesson
+            )]);
+            let (created, skipped) = self.process_words(user_id, words, &tags).await?;
 
             total_created_count += created;
             total_skipped_words.extend(skipped);
@@ -64,6 +70,7 @@ impl<'a, R: UserRepository, L: LlmService, M: MigiiClient> ExportMigiiPackUseCas
         &self,
         user_id: Ulid,
         words: Vec<MigiiWord>,
+        tags: &BTreeSet<String>,
     ) -> Result<(usize, Vec<String>), KeikakuError> {
         let mut created_count = 0;
         let mut skipped_words = Vec::new();
@@ -73,7 +80,7 @@ impl<'a, R: UserRepository, L: LlmService, M: MigiiClient> ExportMigiiPackUseCas
 
             match self
                 .create_card_use_case
-                .execute(user_id, question.clone())
+                .execute_with_tags(user_id, question.clone(), tags.clone())
                 .await
             {
                 Ok(_) => {
@@ -0,0 +1,81 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::User;
+use crate::domain::error::KeikakuError;
+use crate::domain::review::MemoryState;
+use crate::domain::value_objects::{Difficulty, Stability};
+use chrono::{Duration, Utc};
+use ulid::Ulid;
+
+const KNOWN_CARD_DIFFICULTY: f64 = 1.0;
+const DEFAULT_KNOWN_STABILITY: f64 = 30.0;
+const DEFAULT_SPREAD_DAYS: u32 = 28;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarkAsKnownOptions {
+    /// Начальная стабильность (в днях) для известных карт
+    pub stability: f64,
+    /// На сколько дней вперед распределить первые повторения
+    pub spread_days: u32,
+}
+
+impl Default for MarkAsKnownOptions {
+    fn default() -> Self {
+        Self {
+            stability: DEFAULT_KNOWN_STABILITY,
+            spread_days: DEFAULT_SPREAD_DAYS,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MarkCardsAsKnownUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> MarkCardsAsKnownUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_ids: Vec<Ulid>,
+        options: MarkAsKnownOptions,
+    ) -> Result<usize, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let known_count = mark_as_known(&mut user, &card_ids, options)?;
+
+        self.repository.save(&user).await?;
+
+        Ok(known_count)
+    }
+}
+
+/// Отмечает карты известными, распределяя первые повторения на `spread_days` дней вперед
+pub(crate) fn mark_as_known(
+    user: &mut User,
+    card_ids: &[Ulid],
+    options: MarkAsKnownOptions,
+) -> Result<usize, KeikakuError> {
+    let stability = Stability::new(options.stability)?;
+    let difficulty = Difficulty::new(KNOWN_CARD_DIFFICULTY)?;
+    let spread_days = options.spread_days.max(1) as i64;
+    let total = card_ids.len().max(1) as i64;
+    let now = Utc::now();
+
+    for (index, card_id) in card_ids.iter().enumerate() {
+        let offset_days = 1 + index as i64 * spread_days / total;
+        let memory_state =
+            MemoryState::new(stability, difficulty, now + Duration::days(offset_days));
+
+        user.mark_card_as_known(*card_id, memory_state)?;
+    }
+
+    Ok(card_ids.len())
+}
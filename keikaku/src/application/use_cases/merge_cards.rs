@@ -0,0 +1,35 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::knowledge::StudyCard;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct MergeCardsUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> MergeCardsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Сливает дубликат `source_id` в карту `target_id` вместе с историей повторений
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        target_id: Ulid,
+        source_id: Ulid,
+    ) -> Result<StudyCard, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let card = user.merge_cards(target_id, source_id)?;
+
+        self.repository.save(&user).await?;
+
+        Ok(card)
+    }
+}
@@ -0,0 +1,76 @@
+use super::generate_card_content::has_dictionary_content;
+use crate::application::user_repository::UserRepository;
+use crate::domain::dictionary::{JLPT_DB, VOCABULARY_DB};
+use crate::domain::error::KeikakuError;
+use crate::domain::tokenizer::{PartOfSpeech, Tokenizer};
+use crate::domain::value_objects::JapaneseLevel;
+use ulid::Ulid;
+
+/// Слово из введенного текста, для которого можно создать карточку
+#[derive(Debug, Clone, PartialEq)]
+pub struct VocabularyCandidate {
+    /// Слово в том виде, в каком оно стоит в тексте
+    pub surface: String,
+    pub base_form: String,
+    /// Чтение словарной формы хираганой
+    pub reading: String,
+    pub part_of_speech: PartOfSpeech,
+    pub jlpt_level: Option<JapaneseLevel>,
+    /// Карточки пользователя, которые уже записывают это слово
+    pub existing_cards: Vec<String>,
+    /// Перевод и примеры есть в словаре, генерация через LLM не понадобится
+    pub has_dictionary_content: bool,
+}
+
+#[derive(Clone)]
+pub struct PreviewVocabularyCardsUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> PreviewVocabularyCardsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Разбирает текст на слова без создания карточек, чтобы пользователь выбрал нужные
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        text: &str,
+    ) -> Result<Vec<VocabularyCandidate>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let candidates = user
+            .knowledge_set()
+            .propose_vocabulary_cards(Tokenizer::shared()?, text)?
+            .into_iter()
+            .map(|proposal| {
+                let base_form = proposal.base_form();
+                let jlpt_level = JLPT_DB.get_level(base_form).or_else(|| {
+                    VOCABULARY_DB
+                        .get_vocabulary_info(base_form)
+                        .map(|info| *info.level())
+                });
+
+                VocabularyCandidate {
+                    surface: proposal.surface().to_string(),
+                    base_form: base_form.to_string(),
+                    reading: proposal.reading().to_string(),
+                    part_of_speech: proposal.part_of_speech().clone(),
+                    jlpt_level,
+                    existing_cards: proposal.existing_cards().to_vec(),
+                    has_dictionary_content: has_dictionary_content(
+                        base_form,
+                        user.native_language(),
+                    ),
+                }
+            })
+            .collect();
+
+        Ok(candidates)
+    }
+}
@@ -2,7 +2,7 @@ use crate::application::SrsService;
 use crate::application::srs_service::{NextReview, RateMode};
 use crate::application::user_repository::UserRepository;
 use crate::domain::error::KeikakuError;
-use crate::domain::value_objects::Rating;
+use crate::domain::value_objects::{Rating, ReviewDirection};
 use ulid::Ulid;
 
 #[derive(Clone, Copy)]
@@ -25,6 +25,19 @@ impl<'a, R: UserRepository, S: SrsService> RateCardUseCase<'a, R, S> {
         card_id: Ulid,
         mode: RateMode,
         rating: Rating,
+    ) -> Result<(), KeikakuError> {
+        self.execute_direction(user_id, card_id, ReviewDirection::Recognition, mode, rating)
+            .await
+    }
+
+    /// Оценивает повторение конкретного направления карты
+    pub async fn execute_direction(
+        &self,
+        user_id: Ulid,
+        card_id: Ulid,
+        direction: ReviewDirection,
+        mode: RateMode,
+        rating: Rating,
     ) -> Result<(), KeikakuError> {
         let mut user = self
             .repository
@@ -40,9 +53,12 @@ impl<'a, R: UserRepository, S: SrsService> RateCardUseCase<'a, R, S> {
         let NextReview {
             interval,
             memory_state,
-        } = self.srs_service.rate(mode, rating, card.memory()).await?;
+        } = self
+            .srs_service
+            .rate(mode, rating, card.memory_for(direction))
+            .await?;
 
-        user.rate_card(card_id, rating, interval, memory_state)?;
+        user.rate_card(card_id, direction, rating, interval, memory_state)?;
 
         self.repository.save(&user).await?;
 
@@ -0,0 +1,33 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct RenameTagUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> RenameTagUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        from: String,
+        to: String,
+    ) -> Result<usize, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let renamed_count = user.rename_tag(&from, &to)?;
+
+        self.repository.save(&user).await?;
+
+        Ok(renamed_count)
+    }
+}
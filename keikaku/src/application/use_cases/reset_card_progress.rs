@@ -0,0 +1,35 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct ResetCardProgressUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> ResetCardProgressUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_ids: Vec<Ulid>,
+        archive_reviews: bool,
+    ) -> Result<usize, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        for card_id in &card_ids {
+            user.reset_card_progress(*card_id, archive_reviews)?;
+        }
+
+        self.repository.save(&user).await?;
+
+        Ok(card_ids.len())
+    }
+}
@@ -1,7 +1,8 @@
 use crate::application::user_repository::UserRepository;
 use crate::domain::error::KeikakuError;
 use crate::domain::knowledge::Card;
-use std::collections::HashMap;
+use crate::domain::value_objects::ReviewDirection;
+use std::collections::{BTreeSet, HashMap};
 use ulid::Ulid;
 
 #[derive(Clone)]
@@ -14,13 +15,17 @@ impl<'a, R: UserRepository> SelectCardsToLessonUseCase<'a, R> {
         Self { repository }
     }
 
-    pub async fn execute(&self, user_id: Ulid) -> Result<HashMap<Ulid, Card>, KeikakuError> {
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        tags: BTreeSet<String>,
+    ) -> Result<HashMap<Ulid, (ReviewDirection, Card)>, KeikakuError> {
         let user = self
             .repository
             .find_by_id(user_id)
             .await?
             .ok_or(KeikakuError::UserNotFound { user_id })?;
 
-        Ok(user.knowledge_set().cards_to_lesson())
+        Ok(user.cards_to_lesson(&tags))
     }
 }
@@ -0,0 +1,34 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct SetCardNotesUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> SetCardNotesUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Сохраняет заметки карты в markdown. Пустой текст удаляет заметки
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_id: Ulid,
+        notes: String,
+    ) -> Result<(), KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        user.set_card_notes(card_id, &notes)?;
+
+        self.repository.save(&user).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::value_objects::ReviewDirection;
+use std::collections::BTreeSet;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct SetReviewDirectionsUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> SetReviewDirectionsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Задает направления повторения словарных карт.
+    /// `None` возвращает картам направления из настроек
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_ids: Vec<Ulid>,
+        directions: Option<BTreeSet<ReviewDirection>>,
+    ) -> Result<usize, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        for card_id in &card_ids {
+            user.set_card_review_directions(*card_id, directions.clone())?;
+        }
+
+        self.repository.save(&user).await?;
+
+        Ok(card_ids.len())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::grammar::{GrammarRule, grammar_rules};
+use crate::domain::knowledge::{ConjugationExercise, ConjugationStats};
+use crate::domain::tokenizer::Tokenizer;
+use ulid::Ulid;
+
+/// Статистика упражнений по правилу для отображения пользователю
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConjugationRuleStats {
+    pub rule_id: String,
+    pub title: String,
+    pub stats: ConjugationStats,
+}
+
+#[derive(Clone)]
+pub struct StartConjugationDrillUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> StartConjugationDrillUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Упражнения по правилам не выше уровня пользователя на уже изучаемых словах
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        count: usize,
+    ) -> Result<Vec<ConjugationExercise>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let level = *user.current_japanese_level();
+        let rules = grammar_rules()
+            .iter()
+            .filter(|rule| rule.level() <= level)
+            .map(|rule| rule.as_ref())
+            .collect::<Vec<&dyn GrammarRule>>();
+
+        user.knowledge_set().conjugation_exercises(
+            Tokenizer::shared()?,
+            &rules,
+            user.native_language(),
+            count,
+        )
+    }
+
+    /// Статистика по правилам, которые уже тренировались, от слабых к сильным
+    pub async fn rule_stats(
+        &self,
+        user_id: Ulid,
+    ) -> Result<Vec<ConjugationRuleStats>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let stats = user.knowledge_set().conjugation_stats();
+        let mut rule_stats = grammar_rules()
+            .iter()
+            .filter_map(|rule| {
+                stats.get(rule.id()).map(|stats| ConjugationRuleStats {
+                    rule_id: rule.id().to_string(),
+                    title: rule.title(user.native_language()),
+                    stats: *stats,
+                })
+            })
+            .collect::<Vec<_>>();
+        rule_stats.sort_by(|left, right| right.stats.weight().total_cmp(&left.stats.weight()));
+
+        Ok(rule_stats)
+    }
+}
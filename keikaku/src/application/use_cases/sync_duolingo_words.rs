@@ -1,7 +1,10 @@
 use crate::application::{CreateVocabularyCardUseCase, DuolingoClient, LlmService, UserRepository};
 use crate::domain::error::KeikakuError;
+use std::collections::BTreeSet;
 use ulid::Ulid;
 
+const DUOLINGO_TAG: &str = "duolingo";
+
 pub struct SyncDuolingoWordsResult {
     pub total_created_count: usize,
     pub skipped_words: Vec<String>,
@@ -48,7 +51,11 @@ impl<'a, R: UserRepository, L: LlmService, D: DuolingoClient>
 
             match self
                 .create_card_use_case
-                .execute(user_id, question.clone())
+                .execute_with_tags(
+                    user_id,
+                    question.clone(),
+                    BTreeSet::from([DUOLINGO_TAG.to_string()]),
+                )
                 .await
             {
                 Ok(_) => {
@@ -0,0 +1,58 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::error::KeikakuError;
+use crate::domain::knowledge::{Card, KanjiCard, KanjiProposal, StudyCard};
+use tracing::error;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct SyncKanjiCardsUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> SyncKanjiCardsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Кандзи из карточек слов, которых еще нет в колоде кандзи. Повторный вызов
+    /// предлагает только кандзи из слов, добавленных с прошлой синхронизации
+    pub async fn propose(&self, user_id: Ulid) -> Result<Vec<KanjiProposal>, KeikakuError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        Ok(user
+            .knowledge_set()
+            .propose_kanji_cards(user.current_japanese_level()))
+    }
+
+    /// Создает карточки для выбранных кандзи
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        kanji: Vec<char>,
+    ) -> Result<Vec<StudyCard>, KeikakuError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(KeikakuError::UserNotFound { user_id })?;
+
+        let mut cards = Vec::new();
+        for kanji in kanji {
+            let card_result = KanjiCard::new(kanji.to_string(), user.native_language())
+                .and_then(|card| user.create_card(Card::Kanji(card)));
+
+            match card_result {
+                Ok(card) => cards.push(card),
+                Err(e) => error!("Failed to create kanji card {kanji}: {e}"),
+            }
+        }
+
+        self.repository.save(&user).await?;
+
+        Ok(cards)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::application::UserRepository;
-use crate::domain::LlmSettings;
 use crate::domain::error::KeikakuError;
+use crate::domain::value_objects::ReviewDirection;
+use crate::domain::{FuriganaMode, LlmSettings};
+use std::collections::BTreeSet;
 use ulid::Ulid;
 
 #[derive(Clone)]
@@ -12,6 +14,8 @@ pub struct UpdateUserSettingsUseCase<'a, R: UserRepository> {
 pub struct UpdateUserSettingsRequest {
     pub llm: Option<LlmSettings>,
     pub duolingo_jwt_token: Option<Option<String>>,
+    pub vocabulary_review_directions: Option<BTreeSet<ReviewDirection>>,
+    pub furigana_mode: Option<FuriganaMode>,
 }
 
 impl<'a, R: UserRepository> UpdateUserSettingsUseCase<'a, R> {
@@ -40,6 +44,14 @@ impl<'a, R: UserRepository> UpdateUserSettingsUseCase<'a, R> {
             settings.set_duolingo_jwt_token(duolingo_jwt_token);
         }
 
+        if let Some(directions) = request.vocabulary_review_directions {
+            settings.set_vocabulary_review_directions(directions);
+        }
+
+        if let Some(furigana_mode) = request.furigana_mode {
+            settings.set_furigana_mode(furigana_mode);
+        }
+
         self.repository.save(&user).await?;
         Ok(())
     }
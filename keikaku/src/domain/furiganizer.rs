@@ -1,23 +1,65 @@
 use crate::domain::{
     KeikakuError,
     japanese::{IsJapanese, IsJapaneseText},
-    tokenizer::Tokenizer,
+    tokenizer::{TokenInfo, Tokenizer},
 };
 
 pub struct Furiganizer {
-    tokenizer: Tokenizer,
+    tokenizer: &'static Tokenizer,
     format: FuriganaFormat,
 }
 
+/// Формат вывода фуриганы
 pub enum FuriganaFormat {
+    /// `<ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>`
     Html,
+    /// `[漢字](かんじ)`
     Markdown,
+    /// `漢字[かんじ]`, как в полях Anki. Перед основой ставится пробел,
+    /// если его нет, чтобы Anki не захватил предыдущий текст
+    Anki,
+    /// `｜漢字《かんじ》`, как в текстах Aozora Bunko
+    Aozora,
+    /// Текст без кандзи: группы кандзи заменяются чтением
+    KanaOnly,
+}
+
+/// Часть текста: группа кандзи с чтением или текст, который выводится как есть
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuriganaSpan {
+    text: String,
+    reading: Option<String>,
+}
+
+impl FuriganaSpan {
+    fn plain(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            reading: None,
+        }
+    }
+
+    fn ruby(text: &str, reading: String) -> Self {
+        Self {
+            text: text.to_string(),
+            reading: Some(reading),
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Чтение хираганой. Пусто у каны и неяпонского текста
+    pub fn reading(&self) -> Option<&str> {
+        self.reading.as_deref()
+    }
 }
 
 impl Furiganizer {
     pub fn new(format: FuriganaFormat) -> Result<Self, KeikakuError> {
         Ok(Self {
-            tokenizer: Tokenizer::new()?,
+            tokenizer: Tokenizer::shared()?,
             format,
         })
     }
@@ -25,7 +67,21 @@ impl Furiganizer {
 
 impl Furiganizer {
     pub fn furiganize(&self, text: &str) -> Result<String, KeikakuError> {
-        let mut result = String::new();
+        Ok(self.render(&self.spans(text)?))
+    }
+
+    /// Фуригана только над группами, в которых есть незнакомый кандзи
+    pub fn furiganize_unknown(
+        &self,
+        text: &str,
+        is_known: impl Fn(char) -> bool,
+    ) -> Result<String, KeikakuError> {
+        Ok(self.render(&self.unknown_spans(text, is_known)?))
+    }
+
+    /// Текст, разбитый на части с чтениями. Соседние части без чтения объединяются
+    pub fn spans(&self, text: &str) -> Result<Vec<FuriganaSpan>, KeikakuError> {
+        let mut spans = Vec::new();
         let mut current_segment = String::new();
         let mut is_current_japanese = false;
 
@@ -38,11 +94,7 @@ impl Furiganizer {
             } else if is_japanese == is_current_japanese {
                 current_segment.push(ch);
             } else {
-                if is_current_japanese {
-                    result.push_str(&self.furiganize_clear_japanese(&current_segment)?);
-                } else {
-                    result.push_str(&current_segment);
-                }
+                self.push_segment(&mut spans, &current_segment, is_current_japanese)?;
                 current_segment.clear();
                 current_segment.push(ch);
                 is_current_japanese = is_japanese;
@@ -50,49 +102,201 @@ impl Furiganizer {
         }
 
         if !current_segment.is_empty() {
-            if is_current_japanese {
-                result.push_str(&self.furiganize_clear_japanese(&current_segment)?);
+            self.push_segment(&mut spans, &current_segment, is_current_japanese)?;
+        }
+
+        Ok(spans)
+    }
+
+    /// Как `spans`, но группы, все кандзи которых знакомы, остаются без чтения
+    pub fn unknown_spans(
+        &self,
+        text: &str,
+        is_known: impl Fn(char) -> bool,
+    ) -> Result<Vec<FuriganaSpan>, KeikakuError> {
+        let mut spans = Vec::new();
+
+        for span in self.spans(text)? {
+            let is_known_group = span.reading.is_some()
+                && span.text.chars().filter(|ch| ch.is_kanji()).all(&is_known);
+            if is_known_group {
+                push_span(&mut spans, FuriganaSpan::plain(&span.text));
             } else {
-                result.push_str(&current_segment);
+                push_span(&mut spans, span);
             }
         }
 
-        Ok(result)
+        Ok(spans)
     }
 
-    fn furiganize_clear_japanese(&self, text: &str) -> Result<String, KeikakuError> {
+    /// Чтение текста хираганой: японские фрагменты заменяются чтениями токенов
+    pub fn reading(&self, text: &str) -> Result<String, KeikakuError> {
         let tokens = self.tokenizer.tokenize(text)?;
+        let mut result = String::with_capacity(text.len());
+
+        for (index, token) in tokens.iter().enumerate() {
+            let reading = token_reading(token, tokens.get(index + 1));
+            if token.orthographic_surface_form().contains_japanese() && !reading.is_empty() {
+                result.push_str(&reading);
+            } else {
+                result.push_str(token.orthographic_surface_form());
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn push_segment(
+        &self,
+        spans: &mut Vec<FuriganaSpan>,
+        segment: &str,
+        is_japanese: bool,
+    ) -> Result<(), KeikakuError> {
+        if !is_japanese {
+            push_span(spans, FuriganaSpan::plain(segment));
+            return Ok(());
+        }
+
+        let tokens = self.tokenizer.tokenize(segment)?;
+        for (index, token) in tokens.iter().enumerate() {
+            let surface = token.orthographic_surface_form();
+            if !surface.contains_kanji() {
+                push_span(spans, FuriganaSpan::plain(surface));
+                continue;
+            }
+
+            let reading = token_reading(token, tokens.get(index + 1));
+            for span in align_reading(surface, &reading) {
+                push_span(spans, span);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&self, spans: &[FuriganaSpan]) -> String {
         let mut result = String::new();
 
-        for token in tokens {
-            let furigana = if token.orthographic_surface_form().contains_kanji() {
-                match self.format {
-                    FuriganaFormat::Html => self.format_html(
-                        token.orthographic_surface_form(),
-                        token.phonological_surface_form(),
-                    ),
-                    FuriganaFormat::Markdown => self.format_md(
-                        token.orthographic_surface_form(),
-                        token.phonological_surface_form(),
-                    ),
+        for span in spans {
+            match span.reading() {
+                Some(reading) => self.push_ruby(&mut result, span.text(), reading),
+                None => result.push_str(span.text()),
+            }
+        }
+
+        result
+    }
+
+    fn push_ruby(&self, result: &mut String, base: &str, text: &str) {
+        match self.format {
+            FuriganaFormat::Html => result.push_str(&format!(
+                "<ruby>{base}<rp>(</rp><rt>{text}</rt><rp>)</rp></ruby>"
+            )),
+            FuriganaFormat::Markdown => result.push_str(&format!("[{base}]({text})")),
+            FuriganaFormat::Anki => {
+                if result.chars().last().is_some_and(|ch| !ch.is_whitespace()) {
+                    result.push(' ');
                 }
-            } else {
-                token.orthographic_surface_form().to_string()
-            };
+                result.push_str(&format!("{base}[{text}]"));
+            }
+            FuriganaFormat::Aozora => result.push_str(&format!("｜{base}《{text}》")),
+            FuriganaFormat::KanaOnly => result.push_str(text),
+        }
+    }
+}
 
-            result.push_str(&furigana);
+/// Добавляет часть, объединяя подряд идущий текст без чтения
+fn push_span(spans: &mut Vec<FuriganaSpan>, span: FuriganaSpan) {
+    match spans.last_mut() {
+        Some(last) if last.reading.is_none() && span.reading.is_none() => {
+            last.text.push_str(&span.text)
         }
+        _ => spans.push(span),
+    }
+}
 
-        Ok(result)
+/// Узкие исправления чтения, выбранного UniDic: (слово, начало следующего слова, чтение).
+///
+/// У 日本 в словаре два равноправных произношения, и разбор выбирает одно по стоимости,
+/// не глядя на устойчивые названия вроде 日本銀行. Пустое следующее слово — чтение
+/// в остальных случаях. Все прочие слова читаются так, как их разобрал токенизатор
+const READING_OVERRIDES: [(&str, &str, &str); 3] = [
+    ("日本", "銀行", "にっぽん"),
+    ("日本", "放送", "にっぽん"),
+    ("日本", "", "にほん"),
+];
+
+/// Чтение токена хираганой: выбранное UniDic, если для слова нет исправления
+fn token_reading(token: &TokenInfo, next: Option<&TokenInfo>) -> String {
+    let surface = token.orthographic_surface_form();
+    let next_surface = next
+        .map(TokenInfo::orthographic_surface_form)
+        .unwrap_or_default();
+
+    READING_OVERRIDES
+        .iter()
+        .find(|(word, following, _)| {
+            *word == surface && (following.is_empty() || next_surface.starts_with(following))
+        })
+        .map(|(_, _, reading)| reading.to_string())
+        .unwrap_or_else(|| token.kana_surface_form())
+}
+
+/// Сопоставляет чтение группам кандзи слова, оставляя окуригану вне фуриганы:
+/// 食べ + たべ → 食(た) べ. Если чтение не согласуется с каной слова,
+/// фуригана ставится над словом целиком
+fn align_reading(surface: &str, reading: &str) -> Vec<FuriganaSpan> {
+    if reading.is_empty() {
+        return vec![FuriganaSpan::plain(surface)];
     }
 
-    fn format_html(&self, base: &str, text: &str) -> String {
-        format!("<ruby>{base}<rp>(</rp><rt>{text}</rt><rp>)</rp></ruby>")
+    let groups = split_kana_groups(surface);
+    let reading = reading.chars().collect::<Vec<_>>();
+
+    align_groups(&groups, &reading)
+        .unwrap_or_else(|| vec![FuriganaSpan::ruby(surface, reading.iter().collect())])
+}
+
+/// Группы подряд идущих символов: (является ли группа каной, текст)
+fn split_kana_groups(surface: &str) -> Vec<(bool, String)> {
+    let mut groups: Vec<(bool, String)> = Vec::new();
+
+    for ch in surface.chars() {
+        let is_kana = ch.is_hiragana() || ch.is_katakana();
+        match groups.last_mut() {
+            Some((group_is_kana, text)) if *group_is_kana == is_kana => text.push(ch),
+            _ => groups.push((is_kana, ch.to_string())),
+        }
     }
 
-    fn format_md(&self, base: &str, text: &str) -> String {
-        format!("[{base}]({text})")
+    groups
+}
+
+/// Перебирает длину чтения каждой группы кандзи, начиная с самой короткой,
+/// пока кана слова не совпадет с чтением
+fn align_groups(groups: &[(bool, String)], reading: &[char]) -> Option<Vec<FuriganaSpan>> {
+    let Some(((is_kana, text), rest)) = groups.split_first() else {
+        return reading.is_empty().then(Vec::new);
+    };
+
+    if *is_kana {
+        let kana = text.to_hiragana().chars().collect::<Vec<_>>();
+        if !reading.starts_with(&kana) {
+            return None;
+        }
+        let mut spans = align_groups(rest, &reading[kana.len()..])?;
+        spans.insert(0, FuriganaSpan::plain(text));
+        return Some(spans);
     }
+
+    (1..=reading.len()).find_map(|length| {
+        let mut spans = align_groups(rest, &reading[length..])?;
+        spans.insert(
+            0,
+            FuriganaSpan::ruby(text, reading[..length].iter().collect()),
+        );
+        Some(spans)
+    })
 }
 
 #[cfg(test)]
@@ -109,7 +313,7 @@ mod tests {
         let output = furiganizer.furiganize(input).unwrap();
 
         // Assert
-        assert_eq!(output, "Hello [日本](ニッポン)[語](ゴ) world");
+        assert_eq!(output, "Hello [日本](にほん)[語](ご) world");
     }
 
     #[test]
@@ -148,6 +352,180 @@ mod tests {
         let output = furiganizer.furiganize(input).unwrap();
 
         // Assert
-        assert_eq!(output, "A[日](ヒ)B[本](ホン)C");
+        assert_eq!(output, "A[日](ひ)B[本](ほん)C");
+    }
+
+    #[test]
+    fn okurigana_stays_outside_of_ruby() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Markdown).unwrap();
+        let input = "食べる";
+
+        // Act
+        let output = furiganizer.furiganize(input).unwrap();
+
+        // Assert
+        assert_eq!(output, "[食](た)べる");
+    }
+
+    #[test]
+    fn reading_is_aligned_to_each_kanji_group() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Markdown).unwrap();
+        let input = "美味しい";
+
+        // Act
+        let output = furiganizer.furiganize(input).unwrap();
+
+        // Assert
+        assert_eq!(output, "[美味](おい)しい");
+    }
+
+    #[test]
+    fn nihon_reading_depends_on_context() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Markdown).unwrap();
+
+        // Act
+        let nihon = furiganizer.furiganize("日本").unwrap();
+        let nippon = furiganizer.furiganize("日本銀行").unwrap();
+
+        // Assert
+        assert_eq!(nihon, "[日本](にほん)");
+        assert!(nippon.contains("にっぽん"));
+    }
+
+    #[test]
+    fn long_vowels_are_spelled_as_written() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Markdown).unwrap();
+        let input = "大きい";
+
+        // Act
+        let output = furiganizer.furiganize(input).unwrap();
+
+        // Assert
+        assert_eq!(output, "[大](おお)きい");
+    }
+
+    #[test]
+    fn html_ruby_contains_hiragana_reading() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Html).unwrap();
+        let input = "先生";
+
+        // Act
+        let output = furiganizer.furiganize(input).unwrap();
+
+        // Assert
+        assert_eq!(
+            output,
+            "<ruby>先生<rp>(</rp><rt>せんせい</rt><rp>)</rp></ruby>"
+        );
+    }
+
+    #[test]
+    fn mismatched_reading_falls_back_to_whole_word() {
+        // Arrange
+        let surface = "今日は";
+
+        // Act
+        let spans = align_reading(surface, "こんにちわ");
+
+        // Assert
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].text, "今日は");
+        assert_eq!(spans[0].reading.as_deref(), Some("こんにちわ"));
+    }
+
+    #[test]
+    fn spans_separate_kanji_from_okurigana() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Html).unwrap();
+        let input = "Hi 食べる";
+
+        // Act
+        let spans = furiganizer.spans(input).unwrap();
+
+        // Assert
+        assert_eq!(
+            spans,
+            vec![
+                FuriganaSpan::plain("Hi "),
+                FuriganaSpan::ruby("食", "た".to_string()),
+                FuriganaSpan::plain("べる"),
+            ]
+        );
+    }
+
+    #[test]
+    fn anki_format_separates_ruby_with_space() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Anki).unwrap();
+        let input = "食べる";
+
+        // Act
+        let output = furiganizer.furiganize(input).unwrap();
+
+        // Assert
+        assert_eq!(output, "食[た]べる");
+        assert_eq!(
+            furiganizer.furiganize("Hi 日本").unwrap(),
+            "Hi 日本[にほん]"
+        );
+    }
+
+    #[test]
+    fn aozora_format_marks_ruby_base() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Aozora).unwrap();
+        let input = "食べる";
+
+        // Act
+        let output = furiganizer.furiganize(input).unwrap();
+
+        // Assert
+        assert_eq!(output, "｜食《た》べる");
+    }
+
+    #[test]
+    fn kana_only_format_replaces_kanji_with_reading() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::KanaOnly).unwrap();
+        let input = "先生は美味しい";
+
+        // Act
+        let output = furiganizer.furiganize(input).unwrap();
+
+        // Assert
+        assert_eq!(output, "せんせいはおいしい");
+    }
+
+    #[test]
+    fn known_kanji_are_left_without_ruby() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Markdown).unwrap();
+        let input = "日本語を食べる";
+
+        // Act
+        let output = furiganizer
+            .furiganize_unknown(input, |kanji| kanji == '日' || kanji == '食')
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, "[日本](にほん)[語](ご)を食べる");
+    }
+
+    #[test]
+    fn all_known_text_has_no_readings() {
+        // Arrange
+        let furiganizer = Furiganizer::new(FuriganaFormat::Markdown).unwrap();
+        let input = "先生";
+
+        // Act
+        let spans = furiganizer.unknown_spans(input, |_| true).unwrap();
+
+        // Assert
+        assert_eq!(spans, vec![FuriganaSpan::plain("先生")]);
     }
 }
@@ -0,0 +1,452 @@
+use crate::domain::{
+    KeikakuError,
+    grammar::verb_forms::VerbClass,
+    tokenizer::{PartOfSpeech, Tokenizer},
+    value_objects::NativeLanguage,
+};
+
+/// Форма в таблице спряжения
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConjugationForm {
+    Present,
+    Negative,
+    Past,
+    NegativePast,
+    Te,
+    Adverbial,
+    Potential,
+    Passive,
+    Causative,
+    CausativePassive,
+    Volitional,
+    Imperative,
+    ConditionalBa,
+    ConditionalTara,
+}
+
+impl ConjugationForm {
+    pub fn title(&self, lang: &NativeLanguage) -> &'static str {
+        match lang {
+            NativeLanguage::Russian => match self {
+                ConjugationForm::Present => "Настоящее время",
+                ConjugationForm::Negative => "Отрицание",
+                ConjugationForm::Past => "Прошедшее время",
+                ConjugationForm::NegativePast => "Прошедшее отрицание",
+                ConjugationForm::Te => "て-форма",
+                ConjugationForm::Adverbial => "Наречная форма",
+                ConjugationForm::Potential => "Потенциальная форма",
+                ConjugationForm::Passive => "Страдательный залог",
+                ConjugationForm::Causative => "Побудительный залог",
+                ConjugationForm::CausativePassive => "Побудительно-страдательный залог",
+                ConjugationForm::Volitional => "Форма намерения",
+                ConjugationForm::Imperative => "Повелительная форма",
+                ConjugationForm::ConditionalBa => "Условная форма ～ば",
+                ConjugationForm::ConditionalTara => "Условная форма ～たら",
+            },
+            NativeLanguage::English => match self {
+                ConjugationForm::Present => "Present",
+                ConjugationForm::Negative => "Negative",
+                ConjugationForm::Past => "Past",
+                ConjugationForm::NegativePast => "Negative past",
+                ConjugationForm::Te => "Te-form",
+                ConjugationForm::Adverbial => "Adverbial",
+                ConjugationForm::Potential => "Potential",
+                ConjugationForm::Passive => "Passive",
+                ConjugationForm::Causative => "Causative",
+                ConjugationForm::CausativePassive => "Causative-passive",
+                ConjugationForm::Volitional => "Volitional",
+                ConjugationForm::Imperative => "Imperative",
+                ConjugationForm::ConditionalBa => "Conditional ～ば",
+                ConjugationForm::ConditionalTara => "Conditional ～たら",
+            },
+        }
+    }
+}
+
+/// Строка таблицы: простая и вежливая форма
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConjugationRow {
+    form: ConjugationForm,
+    plain: String,
+    polite: Option<String>,
+}
+
+impl ConjugationRow {
+    fn new(form: ConjugationForm, plain: String, polite: Option<String>) -> Self {
+        Self {
+            form,
+            plain,
+            polite,
+        }
+    }
+
+    pub fn form(&self) -> ConjugationForm {
+        self.form
+    }
+
+    pub fn plain(&self) -> &str {
+        &self.plain
+    }
+
+    pub fn polite(&self) -> Option<&str> {
+        self.polite.as_deref()
+    }
+}
+
+/// Все формы глагола или прилагательного
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConjugationTable {
+    word: String,
+    part_of_speech: PartOfSpeech,
+    rows: Vec<ConjugationRow>,
+}
+
+impl ConjugationTable {
+    /// Таблица для слова в словарной форме. Часть речи определяется токенизатором
+    pub fn for_word(word: &str) -> Result<Self, KeikakuError> {
+        let word = word.trim();
+        let tokens = Tokenizer::shared()?.tokenize(word)?;
+
+        let part_of_speech = match tokens.as_slice() {
+            [.., last] if last.part_of_speech() == &PartOfSpeech::Verb => PartOfSpeech::Verb,
+            [token] => token.part_of_speech().clone(),
+            _ => PartOfSpeech::Unspecified,
+        };
+
+        Self::new(word, &part_of_speech)
+    }
+
+    pub fn new(word: &str, part_of_speech: &PartOfSpeech) -> Result<Self, KeikakuError> {
+        let word = word.trim();
+        let rows = match part_of_speech {
+            PartOfSpeech::Verb => verb_rows(word)?,
+            PartOfSpeech::IAdjective => i_adjective_rows(word)?,
+            PartOfSpeech::NaAdjective => na_adjective_rows(word),
+            _ => {
+                return Err(KeikakuError::GrammarFormatError {
+                    reason: format!("'{word}' is not a verb or an adjective"),
+                });
+            }
+        };
+
+        Ok(Self {
+            word: word.to_string(),
+            part_of_speech: part_of_speech.clone(),
+            rows,
+        })
+    }
+
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn part_of_speech(&self) -> &PartOfSpeech {
+        &self.part_of_speech
+    }
+
+    pub fn rows(&self) -> &[ConjugationRow] {
+        &self.rows
+    }
+
+    pub fn get(&self, form: ConjugationForm) -> Option<&ConjugationRow> {
+        self.rows.iter().find(|row| row.form == form)
+    }
+}
+
+fn verb_rows(word: &str) -> Result<Vec<ConjugationRow>, KeikakuError> {
+    let class = VerbClass::of(word)?;
+    let stem = class.masu_stem(word)?;
+    let polite_ichidan = |plain: &str| plain.strip_suffix('る').map(|stem| format!("{stem}ます"));
+
+    let mut rows = vec![
+        ConjugationRow::new(
+            ConjugationForm::Present,
+            word.to_string(),
+            Some(format!("{stem}ます")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::Negative,
+            class.nai_form(word)?,
+            Some(format!("{stem}ません")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::Past,
+            class.ta_form(word)?,
+            Some(format!("{stem}ました")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::NegativePast,
+            class.nai_past_form(word)?,
+            Some(format!("{stem}ませんでした")),
+        ),
+        ConjugationRow::new(ConjugationForm::Te, class.te_form(word)?, None),
+    ];
+
+    // У ある нет потенциальной формы и залогов
+    if class != VerbClass::Aru {
+        for (form, plain) in [
+            (ConjugationForm::Potential, class.potential_form(word)?),
+            (ConjugationForm::Passive, class.passive_form(word)?),
+            (ConjugationForm::Causative, class.causative_form(word)?),
+            (
+                ConjugationForm::CausativePassive,
+                class.causative_passive_form(word)?,
+            ),
+        ] {
+            let polite = polite_ichidan(&plain);
+            rows.push(ConjugationRow::new(form, plain, polite));
+        }
+    }
+
+    rows.extend([
+        ConjugationRow::new(
+            ConjugationForm::Volitional,
+            class.volitional_form(word)?,
+            Some(format!("{stem}ましょう")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::Imperative,
+            class.imperative_form(word)?,
+            Some(format!("{}ください", class.te_form(word)?)),
+        ),
+        ConjugationRow::new(ConjugationForm::ConditionalBa, class.ba_form(word)?, None),
+        ConjugationRow::new(
+            ConjugationForm::ConditionalTara,
+            class.tara_form(word)?,
+            None,
+        ),
+    ]);
+
+    Ok(rows)
+}
+
+fn i_adjective_rows(word: &str) -> Result<Vec<ConjugationRow>, KeikakuError> {
+    let stem = i_adjective_stem(word)?;
+
+    Ok(vec![
+        ConjugationRow::new(
+            ConjugationForm::Present,
+            word.to_string(),
+            Some(format!("{word}です")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::Negative,
+            format!("{stem}くない"),
+            Some(format!("{stem}くないです")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::Past,
+            format!("{stem}かった"),
+            Some(format!("{stem}かったです")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::NegativePast,
+            format!("{stem}くなかった"),
+            Some(format!("{stem}くなかったです")),
+        ),
+        ConjugationRow::new(ConjugationForm::Te, format!("{stem}くて"), None),
+        ConjugationRow::new(ConjugationForm::Adverbial, format!("{stem}く"), None),
+        ConjugationRow::new(
+            ConjugationForm::ConditionalBa,
+            format!("{stem}ければ"),
+            None,
+        ),
+        ConjugationRow::new(
+            ConjugationForm::ConditionalTara,
+            format!("{stem}かったら"),
+            None,
+        ),
+    ])
+}
+
+/// Основа い-прилагательного. いい и составные на いい спрягаются от よい: よくない
+pub(super) fn i_adjective_stem(word: &str) -> Result<String, KeikakuError> {
+    let tokens = Tokenizer::shared()?.tokenize(word)?;
+    let is_ii = tokens
+        .last()
+        .is_some_and(|token| token.orthographic_base_form() == "良い")
+        && word.ends_with("いい");
+
+    if is_ii {
+        return Ok(format!("{}よ", &word[..word.len() - "いい".len()]));
+    }
+
+    word.strip_suffix('い')
+        .map(str::to_string)
+        .ok_or(KeikakuError::GrammarFormatError {
+            reason: format!("'{word}' is not an i-adjective"),
+        })
+}
+
+fn na_adjective_rows(word: &str) -> Vec<ConjugationRow> {
+    let stem = word
+        .strip_suffix('な')
+        .or_else(|| word.strip_suffix('だ'))
+        .unwrap_or(word);
+
+    vec![
+        ConjugationRow::new(
+            ConjugationForm::Present,
+            format!("{stem}だ"),
+            Some(format!("{stem}です")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::Negative,
+            format!("{stem}ではない"),
+            Some(format!("{stem}ではありません")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::Past,
+            format!("{stem}だった"),
+            Some(format!("{stem}でした")),
+        ),
+        ConjugationRow::new(
+            ConjugationForm::NegativePast,
+            format!("{stem}ではなかった"),
+            Some(format!("{stem}ではありませんでした")),
+        ),
+        ConjugationRow::new(ConjugationForm::Te, format!("{stem}で"), None),
+        ConjugationRow::new(ConjugationForm::Adverbial, format!("{stem}に"), None),
+        ConjugationRow::new(ConjugationForm::ConditionalBa, format!("{stem}なら"), None),
+        ConjugationRow::new(
+            ConjugationForm::ConditionalTara,
+            format!("{stem}だったら"),
+            None,
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(table: &ConjugationTable, form: ConjugationForm) -> &str {
+        table.get(form).unwrap().plain()
+    }
+
+    #[test]
+    fn godan_verb_has_full_paradigm() {
+        // Arrange
+        let word = "書く";
+
+        // Act
+        let table = ConjugationTable::new(word, &PartOfSpeech::Verb).unwrap();
+
+        // Assert
+        assert_eq!(plain(&table, ConjugationForm::Potential), "書ける");
+        assert_eq!(plain(&table, ConjugationForm::Passive), "書かれる");
+        assert_eq!(plain(&table, ConjugationForm::Causative), "書かせる");
+        assert_eq!(
+            plain(&table, ConjugationForm::CausativePassive),
+            "書かせられる"
+        );
+        assert_eq!(plain(&table, ConjugationForm::Volitional), "書こう");
+        assert_eq!(plain(&table, ConjugationForm::Imperative), "書け");
+        assert_eq!(plain(&table, ConjugationForm::ConditionalBa), "書けば");
+        assert_eq!(plain(&table, ConjugationForm::ConditionalTara), "書いたら");
+        assert_eq!(plain(&table, ConjugationForm::NegativePast), "書かなかった");
+        assert_eq!(
+            table.get(ConjugationForm::Potential).unwrap().polite(),
+            Some("書けます")
+        );
+    }
+
+    #[test]
+    fn ichidan_and_irregular_verbs_have_their_own_forms() {
+        // Arrange
+        let taberu = ConjugationTable::new("食べる", &PartOfSpeech::Verb).unwrap();
+        let suru = ConjugationTable::new("勉強する", &PartOfSpeech::Verb).unwrap();
+        let kuru = ConjugationTable::new("来る", &PartOfSpeech::Verb).unwrap();
+
+        // Act + Assert
+        assert_eq!(plain(&taberu, ConjugationForm::Potential), "食べられる");
+        assert_eq!(plain(&taberu, ConjugationForm::Volitional), "食べよう");
+        assert_eq!(plain(&taberu, ConjugationForm::Imperative), "食べろ");
+        assert_eq!(plain(&taberu, ConjugationForm::ConditionalBa), "食べれば");
+        assert_eq!(plain(&suru, ConjugationForm::Potential), "勉強できる");
+        assert_eq!(plain(&suru, ConjugationForm::Passive), "勉強される");
+        assert_eq!(plain(&suru, ConjugationForm::ConditionalBa), "勉強すれば");
+        assert_eq!(plain(&kuru, ConjugationForm::Causative), "来させる");
+        assert_eq!(plain(&kuru, ConjugationForm::Imperative), "来い");
+        assert_eq!(plain(&kuru, ConjugationForm::ConditionalBa), "来れば");
+    }
+
+    #[test]
+    fn aru_has_no_potential_form() {
+        // Arrange
+        let word = "ある";
+
+        // Act
+        let table = ConjugationTable::new(word, &PartOfSpeech::Verb).unwrap();
+
+        // Assert
+        assert!(table.get(ConjugationForm::Potential).is_none());
+        assert_eq!(plain(&table, ConjugationForm::Negative), "ない");
+        assert_eq!(plain(&table, ConjugationForm::NegativePast), "なかった");
+    }
+
+    #[test]
+    fn ii_conjugates_from_yoi() {
+        // Arrange
+        let word = "いい";
+
+        // Act
+        let table = ConjugationTable::new(word, &PartOfSpeech::IAdjective).unwrap();
+
+        // Assert
+        assert_eq!(plain(&table, ConjugationForm::Present), "いい");
+        assert_eq!(plain(&table, ConjugationForm::Negative), "よくない");
+        assert_eq!(plain(&table, ConjugationForm::Past), "よかった");
+        assert_eq!(plain(&table, ConjugationForm::ConditionalBa), "よければ");
+    }
+
+    #[test]
+    fn i_adjective_has_full_paradigm() {
+        // Arrange
+        let word = "高い";
+
+        // Act
+        let table = ConjugationTable::new(word, &PartOfSpeech::IAdjective).unwrap();
+
+        // Assert
+        assert_eq!(plain(&table, ConjugationForm::Negative), "高くない");
+        assert_eq!(plain(&table, ConjugationForm::NegativePast), "高くなかった");
+        assert_eq!(plain(&table, ConjugationForm::Te), "高くて");
+        assert_eq!(plain(&table, ConjugationForm::Adverbial), "高く");
+        assert_eq!(
+            plain(&table, ConjugationForm::ConditionalTara),
+            "高かったら"
+        );
+    }
+
+    #[test]
+    fn na_adjective_has_full_paradigm() {
+        // Arrange
+        let word = "静か";
+
+        // Act
+        let table = ConjugationTable::new(word, &PartOfSpeech::NaAdjective).unwrap();
+
+        // Assert
+        assert_eq!(plain(&table, ConjugationForm::Present), "静かだ");
+        assert_eq!(
+            table.get(ConjugationForm::Negative).unwrap().polite(),
+            Some("静かではありません")
+        );
+        assert_eq!(plain(&table, ConjugationForm::Adverbial), "静かに");
+        assert_eq!(plain(&table, ConjugationForm::ConditionalBa), "静かなら");
+    }
+
+    #[test]
+    fn part_of_speech_is_detected_by_tokenizer() {
+        // Arrange
+        let word = "飲む";
+
+        // Act
+        let table = ConjugationTable::for_word(word).unwrap();
+
+        // Assert
+        assert_eq!(table.part_of_speech(), &PartOfSpeech::Verb);
+        assert!(ConjugationTable::for_word("本").is_err());
+    }
+}
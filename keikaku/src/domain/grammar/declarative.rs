@@ -0,0 +1,458 @@
+//! Правило грамматики, описанное в TOML файле.
+//!
+//! ```toml
+//! id = "verb_tai"
+//! level = "N5"
+//! apply_to = ["Verb"]
+//!
+//! [title]
+//! ru = "Форма ～たいです"
+//! en = "Form ～たいです"
+//!
+//! [description]
+//! ru = '''# Markdown описание'''
+//! en = '''# Markdown description'''
+//!
+//! [[forms]]
+//! part_of_speech = ["Verb"]
+//! templates = ["{masu_stem}たいです"]
+//! patterns = ["{masu_stem}たい", "{masu_stem}たく"]
+//!
+//! [[examples]]
+//! word = "飲む"
+//! part_of_speech = "Verb"
+//! expected = ["飲みたいです"]
+//! ```
+//!
+//! `templates` — варианты конструкции, при генерации выбирается случайный.
+//! `patterns` — формы для поиска правила в тексте, по умолчанию совпадают с `templates`.
+//! `examples` — слова с ожидаемыми вариантами, по ним проверяется правило.
+//! В шаблонах подставляются формы слова, список в [`PLACEHOLDERS`]
+
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::domain::{
+    KeikakuError,
+    grammar::{
+        GrammarRule,
+        conjugation::i_adjective_stem,
+        verb_forms::{
+            VerbClass, to_masen_form, to_mashou_form, to_masu_form, to_masu_stem, to_nai_form,
+            to_ta_form, to_te_form,
+        },
+    },
+    tokenizer::PartOfSpeech,
+    value_objects::{JapaneseLevel, NativeLanguage},
+};
+
+/// Подстановки, доступные в шаблонах
+pub const PLACEHOLDERS: [&str; 22] = [
+    "word",
+    "adjective_stem",
+    "masu_stem",
+    "masu",
+    "masen",
+    "mashou",
+    "te",
+    "ta",
+    "nai",
+    "nai_past",
+    "potential",
+    "potential_stem",
+    "passive",
+    "passive_stem",
+    "causative",
+    "causative_stem",
+    "causative_passive",
+    "causative_passive_stem",
+    "volitional",
+    "imperative",
+    "ba",
+    "tara",
+];
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DeclarativeRule {
+    id: String,
+    level: JapaneseLevel,
+    apply_to: Vec<PartOfSpeech>,
+    title: LocalizedText,
+    description: LocalizedText,
+    forms: Vec<RuleForm>,
+    #[serde(default)]
+    examples: Vec<RuleExample>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct LocalizedText {
+    ru: String,
+    en: String,
+}
+
+impl LocalizedText {
+    fn get(&self, lang: &NativeLanguage) -> &str {
+        match lang {
+            NativeLanguage::Russian => &self.ru,
+            NativeLanguage::English => &self.en,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct RuleForm {
+    part_of_speech: Vec<PartOfSpeech>,
+    templates: Vec<String>,
+    #[serde(default)]
+    patterns: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct RuleExample {
+    word: String,
+    part_of_speech: PartOfSpeech,
+    expected: Vec<String>,
+}
+
+enum Segment<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+}
+
+impl DeclarativeRule {
+    pub fn from_toml(text: &str) -> Result<Self, KeikakuError> {
+        let rule: Self = toml::from_str(text).map_err(|e| KeikakuError::GrammarFormatError {
+            reason: format!("Invalid grammar rule: {e}"),
+        })?;
+        rule.validate()?;
+
+        Ok(rule)
+    }
+
+    fn validate(&self) -> Result<(), KeikakuError> {
+        let invalid = |reason: String| KeikakuError::GrammarFormatError {
+            reason: format!("Invalid grammar rule '{}': {reason}", self.id),
+        };
+
+        if self.id.is_empty()
+            || !self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(invalid(
+                "id must consist of lowercase latin letters, digits and '_'".to_string(),
+            ));
+        }
+        if self.apply_to.is_empty() {
+            return Err(invalid("apply_to is empty".to_string()));
+        }
+        if let Some(part_of_speech) = self.apply_to.iter().find(|pos| self.form(pos).is_err()) {
+            return Err(invalid(format!("no form for {part_of_speech:?}")));
+        }
+
+        for form in self.forms.iter() {
+            if form.templates.is_empty() {
+                return Err(invalid("form without templates".to_string()));
+            }
+            for template in form.templates.iter().chain(form.patterns.iter()) {
+                let unknown =
+                    split_template(template)?
+                        .into_iter()
+                        .find_map(|segment| match segment {
+                            Segment::Placeholder(name) if !PLACEHOLDERS.contains(&name) => {
+                                Some(name)
+                            }
+                            _ => None,
+                        });
+                if let Some(name) = unknown {
+                    return Err(invalid(format!("unknown placeholder {{{name}}}")));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Сверяет все варианты конструкции с примерами из файла правила
+    pub fn check_examples(&self) -> Result<(), KeikakuError> {
+        for example in self.examples.iter() {
+            let variants = self.variants(&example.word, &example.part_of_speech)?;
+            if variants != example.expected {
+                return Err(KeikakuError::GrammarFormatError {
+                    reason: format!(
+                        "Grammar rule '{}' builds {:?} from '{}', expected {:?}",
+                        self.id, variants, example.word, example.expected
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn has_examples(&self) -> bool {
+        !self.examples.is_empty()
+    }
+
+    fn form(&self, part_of_speech: &PartOfSpeech) -> Result<&RuleForm, KeikakuError> {
+        self.forms
+            .iter()
+            .find(|form| form.part_of_speech.contains(part_of_speech))
+            .ok_or(KeikakuError::GrammarFormatError {
+                reason: "Not supported part of speech".to_string(),
+            })
+    }
+}
+
+impl GrammarRule for DeclarativeRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn level(&self) -> JapaneseLevel {
+        self.level
+    }
+
+    fn title(&self, lang: &NativeLanguage) -> String {
+        self.title.get(lang).to_string()
+    }
+
+    fn md_description(&self, lang: &NativeLanguage) -> String {
+        self.description.get(lang).to_string()
+    }
+
+    fn apply_to(&self) -> Vec<PartOfSpeech> {
+        self.apply_to.clone()
+    }
+
+    fn format(&self, word: &str, part_of_speech: &PartOfSpeech) -> Result<String, KeikakuError> {
+        let form = self.form(part_of_speech)?;
+        let variant = rand::rng().random_range(0..form.templates.len());
+
+        render(&form.templates[variant], word, part_of_speech)
+    }
+
+    /// Все варианты конструкции по порядку шаблонов
+    fn variants(
+        &self,
+        word: &str,
+        part_of_speech: &PartOfSpeech,
+    ) -> Result<Vec<String>, KeikakuError> {
+        self.form(part_of_speech)?
+            .templates
+            .iter()
+            .map(|template| render(template, word, part_of_speech))
+            .collect()
+    }
+
+    fn patterns(
+        &self,
+        word: &str,
+        part_of_speech: &PartOfSpeech,
+    ) -> Result<Vec<String>, KeikakuError> {
+        let form = self.form(part_of_speech)?;
+        let patterns = if form.patterns.is_empty() {
+            &form.templates
+        } else {
+            &form.patterns
+        };
+
+        patterns
+            .iter()
+            .map(|pattern| render(pattern, word, part_of_speech))
+            .collect()
+    }
+}
+
+fn split_template(template: &str) -> Result<Vec<Segment<'_>>, KeikakuError> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..].find('}').map(|end| start + end).ok_or(
+            KeikakuError::GrammarFormatError {
+                reason: format!("Unclosed placeholder in template '{template}'"),
+            },
+        )?;
+
+        if start > 0 {
+            segments.push(Segment::Text(&rest[..start]));
+        }
+        segments.push(Segment::Placeholder(&rest[start + 1..end]));
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+
+    Ok(segments)
+}
+
+fn render(
+    template: &str,
+    word: &str,
+    part_of_speech: &PartOfSpeech,
+) -> Result<String, KeikakuError> {
+    split_template(template)?
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => Ok(text.to_string()),
+            Segment::Placeholder(name) => placeholder(name, word, part_of_speech),
+        })
+        .collect()
+}
+
+fn placeholder(
+    name: &str,
+    word: &str,
+    part_of_speech: &PartOfSpeech,
+) -> Result<String, KeikakuError> {
+    match name {
+        "word" => Ok(word.to_string()),
+        "adjective_stem" => match part_of_speech {
+            PartOfSpeech::IAdjective => i_adjective_stem(word),
+            PartOfSpeech::NaAdjective => Ok(word.trim_end_matches("な").to_string()),
+            _ => Err(KeikakuError::GrammarFormatError {
+                reason: format!("'{word}' is not an adjective"),
+            }),
+        },
+        "masu_stem" => to_masu_stem(word),
+        "masu" => to_masu_form(word),
+        "masen" => to_masen_form(word),
+        "mashou" => to_mashou_form(word),
+        "te" => to_te_form(word),
+        "ta" => to_ta_form(word),
+        "nai" => to_nai_form(word),
+        "nai_past" => VerbClass::of(word)?.nai_past_form(word),
+        "potential" => VerbClass::of(word)?.potential_form(word),
+        "passive" => VerbClass::of(word)?.passive_form(word),
+        "causative" => VerbClass::of(word)?.causative_form(word),
+        "causative_passive" => VerbClass::of(word)?.causative_passive_form(word),
+        // Производные глаголы спрягаются как ичидан, основа — без る
+        "potential_stem" | "passive_stem" | "causative_stem" | "causative_passive_stem" => {
+            let form = placeholder(name.trim_end_matches("_stem"), word, part_of_speech)?;
+            Ok(form.trim_end_matches('る').to_string())
+        }
+        "volitional" => VerbClass::of(word)?.volitional_form(word),
+        "imperative" => VerbClass::of(word)?.imperative_form(word),
+        "ba" => VerbClass::of(word)?.ba_form(word),
+        "tara" => VerbClass::of(word)?.tara_form(word),
+        _ => Err(KeikakuError::GrammarFormatError {
+            reason: format!("Unknown placeholder {{{name}}}"),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::grammar::BUILT_IN_RULES;
+
+    const RULE: &str = r#"
+id = "user_nagara"
+level = "N4"
+apply_to = ["Verb"]
+
+[title]
+ru = "Форма ～ながら"
+en = "Form ～ながら"
+
+[description]
+ru = "Одновременные действия"
+en = "Simultaneous actions"
+
+[[forms]]
+part_of_speech = ["Verb"]
+templates = ["{masu_stem}ながら"]
+"#;
+
+    #[test]
+    fn rule_is_loaded_from_toml() {
+        // Arrange
+        let text = RULE;
+
+        // Act
+        let rule = DeclarativeRule::from_toml(text).unwrap();
+
+        // Assert
+        assert_eq!(rule.id(), "user_nagara");
+        assert_eq!(rule.level(), JapaneseLevel::N4);
+        assert_eq!(rule.title(&NativeLanguage::English), "Form ～ながら");
+        assert_eq!(rule.apply_to(), vec![PartOfSpeech::Verb]);
+        assert_eq!(
+            rule.format("飲む", &PartOfSpeech::Verb).unwrap(),
+            "飲みながら"
+        );
+        assert_eq!(
+            rule.patterns("飲む", &PartOfSpeech::Verb).unwrap(),
+            vec!["飲みながら"]
+        );
+    }
+
+    #[test]
+    fn unsupported_part_of_speech_is_rejected() {
+        // Arrange
+        let rule = DeclarativeRule::from_toml(RULE).unwrap();
+
+        // Act
+        let result = rule.format("高い", &PartOfSpeech::IAdjective);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn unknown_placeholder_is_rejected() {
+        // Arrange
+        let text = RULE.replace("{masu_stem}", "{dictionary}");
+
+        // Act
+        let result = DeclarativeRule::from_toml(&text);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn part_of_speech_without_form_is_rejected() {
+        // Arrange
+        let text = RULE.replace(r#"apply_to = ["Verb"]"#, r#"apply_to = ["Verb", "Noun"]"#);
+
+        // Act
+        let result = DeclarativeRule::from_toml(&text);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn every_built_in_rule_matches_its_examples() {
+        for text in BUILT_IN_RULES {
+            // Arrange
+            let rule = DeclarativeRule::from_toml(text).unwrap();
+
+            // Act
+            let result = rule.check_examples();
+
+            // Assert
+            assert!(rule.has_examples(), "rule '{}' has no examples", rule.id());
+            assert!(result.is_ok(), "{result:?}");
+        }
+    }
+
+    #[test]
+    fn wrong_example_is_reported() {
+        // Arrange
+        let text = format!(
+            "{RULE}\n[[examples]]\nword = \"飲む\"\npart_of_speech = \"Verb\"\nexpected = [\"飲んでながら\"]\n"
+        );
+        let rule = DeclarativeRule::from_toml(&text).unwrap();
+
+        // Act
+        let result = rule.check_examples();
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use std::ops::Range;
+
+use crate::domain::{
+    KeikakuError,
+    grammar::grammar_rules,
+    tokenizer::{TokenInfo, Tokenizer},
+};
+
+/// Конструкция грамматического правила, найденная в тексте
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrammarMatch {
+    rule_id: &'static str,
+    word: String,
+    fragment: String,
+    range: Range<usize>,
+}
+
+impl GrammarMatch {
+    pub fn rule_id(&self) -> &'static str {
+        self.rule_id
+    }
+
+    /// Слово в словарной форме, от которого образована конструкция
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    /// Фрагмент текста с конструкцией
+    pub fn fragment(&self) -> &str {
+        &self.fragment
+    }
+
+    /// Положение фрагмента в тексте, в байтах
+    pub fn range(&self) -> &Range<usize> {
+        &self.range
+    }
+
+    /// Лежит ли конструкция внутри другой, более длинной
+    fn is_inside(&self, other: &GrammarMatch) -> bool {
+        other.range.start <= self.range.start
+            && self.range.end <= other.range.end
+            && other.range.len() > self.range.len()
+    }
+}
+
+/// Находит в тексте конструкции встроенных правил.
+///
+/// Для каждого слова текста правило строит свои формы и ищет все их вхождения.
+/// Если найденная конструкция лежит внутри другой (～ましょう в ～ましょうか),
+/// остается только более длинная. Результат упорядочен по позиции в тексте
+pub fn detect_grammar(text: &str) -> Result<Vec<GrammarMatch>, KeikakuError> {
+    let tokens = Tokenizer::shared()?.tokenize(text)?;
+
+    let mut matches: Vec<GrammarMatch> = Vec::new();
+    for token in tokens.iter() {
+        let word = written_base_form(token);
+        for rule in grammar_rules() {
+            if !rule.apply_to().contains(token.part_of_speech()) {
+                continue;
+            }
+            // Слова, для которых правило не строит форму, просто пропускаются
+            let Ok(patterns) = rule.patterns(&word, token.part_of_speech()) else {
+                continue;
+            };
+
+            for pattern in patterns {
+                for (position, fragment) in text.match_indices(&pattern) {
+                    let range = position..position + fragment.len();
+                    let is_known = matches
+                        .iter()
+                        .any(|known| known.rule_id == rule.id() && known.range == range);
+                    if !is_known {
+                        matches.push(GrammarMatch {
+                            rule_id: rule.id(),
+                            word: word.clone(),
+                            fragment: fragment.to_string(),
+                            range,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    let all_matches = matches.clone();
+    matches.retain(|found| !all_matches.iter().any(|other| found.is_inside(other)));
+    matches.sort_by_key(|found| found.range.start);
+
+    Ok(matches)
+}
+
+/// Словарная форма слова в том написании, что и в тексте.
+///
+/// UniDic возвращает лемму в каноническом написании (為る для し, 食べる для たべ),
+/// поэтому для слов, записанных иначе, форма берется из чтения
+fn written_base_form(token: &TokenInfo) -> String {
+    let lemma = token.orthographic_base_form();
+    let surface = token.orthographic_surface_form();
+
+    if surface.chars().next() == lemma.chars().next() {
+        lemma.to_string()
+    } else {
+        token.kana_base_form()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule_ids(text: &str) -> Vec<&'static str> {
+        detect_grammar(text)
+            .unwrap()
+            .iter()
+            .map(GrammarMatch::rule_id)
+            .collect()
+    }
+
+    #[test]
+    fn te_iru_is_detected() {
+        // Arrange
+        let text = "今、本を読んでいます。";
+
+        // Act
+        let matches = detect_grammar(text).unwrap();
+
+        // Assert
+        let found = matches
+            .iter()
+            .find(|found| found.rule_id() == "verb_te_iru")
+            .unwrap();
+        assert_eq!(found.word(), "読む");
+        assert_eq!(found.fragment(), "読んでいます");
+    }
+
+    #[test]
+    fn ta_koto_ga_aru_is_detected() {
+        // Arrange
+        let text = "日本に行ったことがあります。";
+
+        // Act
+        let rule_ids = rule_ids(text);
+
+        // Assert
+        assert!(rule_ids.contains(&"verb_ta_koto_ga_aru"));
+    }
+
+    #[test]
+    fn sugiru_is_detected_in_plain_form() {
+        // Arrange
+        let text = "昨日は食べすぎた。";
+
+        // Act
+        let rule_ids = rule_ids(text);
+
+        // Assert
+        assert!(rule_ids.contains(&"verb_sugiru"));
+    }
+
+    #[test]
+    fn conditional_and_nagara_are_detected() {
+        // Arrange
+        let text = "音楽を聞きながら勉強すれば、覚えられるようになる。";
+
+        // Act
+        let rule_ids = rule_ids(text);
+
+        // Assert
+        assert!(rule_ids.contains(&"verb_nagara"));
+        assert!(rule_ids.contains(&"conditional_ba"));
+        assert!(rule_ids.contains(&"verb_you_ni_naru"));
+    }
+
+    #[test]
+    fn longer_construction_hides_shorter_one() {
+        // Arrange
+        let text = "一緒に帰りましょうか。";
+
+        // Act
+        let rule_ids = rule_ids(text);
+
+        // Assert
+        assert!(rule_ids.contains(&"verb_mashouka"));
+        assert!(!rule_ids.contains(&"verb_mashou"));
+    }
+
+    #[test]
+    fn separate_occurrences_are_kept() {
+        // Arrange
+        let text = "帰りましょう。明日も帰りましょうか。";
+
+        // Act
+        let matches = detect_grammar(text).unwrap();
+
+        // Assert
+        let found = matches
+            .iter()
+            .filter(|found| ["verb_mashou", "verb_mashouka"].contains(&found.rule_id()))
+            .map(|found| (found.rule_id(), &text[found.range().clone()]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            [
+                ("verb_mashou", "帰りましょう"),
+                ("verb_mashouka", "帰りましょうか")
+            ]
+        );
+    }
+
+    #[test]
+    fn kana_spelling_is_detected() {
+        // Arrange
+        let text = "宿題をしています。";
+
+        // Act
+        let rule_ids = rule_ids(text);
+
+        // Assert
+        assert!(rule_ids.contains(&"verb_te_iru"));
+    }
+
+    #[test]
+    fn plain_sentence_has_no_constructions() {
+        // Arrange
+        let text = "これは本です。";
+
+        // Act
+        let matches = detect_grammar(text).unwrap();
+
+        // Assert
+        assert!(matches.is_empty());
+    }
+}
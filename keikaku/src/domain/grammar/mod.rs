@@ -1,77 +1,157 @@
-pub mod adjective_naru;
-pub mod adjective_past;
-pub mod nda;
+pub mod conjugation;
+pub mod declarative;
+pub mod detection;
 pub mod verb_forms;
-pub mod verb_hou_ga_ii;
-pub mod verb_mada_te_inai;
-pub mod verb_masenka;
-pub mod verb_mashou;
-pub mod verb_mashouka;
-pub mod verb_naide_kudasai;
-pub mod verb_ni_iku;
-pub mod verb_sugiru;
-pub mod verb_ta_koto_ga_aru;
-pub mod verb_tai;
-pub mod verb_tari;
-pub mod verb_te_iru;
-pub mod verb_te_kudasai;
-pub mod verb_te_wa_ikemasen;
-pub mod verb_tsumori;
 
-use std::sync::LazyLock;
+use std::sync::OnceLock;
 
 use crate::domain::{
     KeikakuError,
-    grammar::{
-        adjective_naru::AdjectiveNaruRule, adjective_past::AdjectivePastRule, nda::んだRule,
-        verb_hou_ga_ii::VerbHouGaIiRule, verb_mada_te_inai::VerbMadaTeInaiRule,
-        verb_masenka::VerbMasenkaRule, verb_mashou::VerbMashouRule,
-        verb_mashouka::VerbMashoukaRule, verb_naide_kudasai::VerbNaideKudasaiRule,
-        verb_ni_iku::VerbNiIkuRule, verb_sugiru::VerbSugiruRule,
-        verb_ta_koto_ga_aru::VerbTaKotoGaAruRule, verb_tai::VerbTaiRule, verb_tari::VerbTariRule,
-        verb_te_iru::VerbTeIruRule, verb_te_kudasai::VerbTeKudasaiRule,
-        verb_te_wa_ikemasen::VerbTeWaIkemasenRule, verb_tsumori::ConstructionTsumoriRule,
-    },
+    grammar::declarative::DeclarativeRule,
     tokenizer::PartOfSpeech,
     value_objects::{JapaneseLevel, NativeLanguage},
 };
 
-static GRAMMAR_LIST: LazyLock<Vec<Box<dyn GrammarRule>>> = LazyLock::new(|| {
-    vec![
-        // Существующие правила
-        Box::new(んだRule {}),
-        Box::new(AdjectivePastRule {}),
-        // Глагольные формы
-        Box::new(VerbMasenkaRule {}),
-        Box::new(VerbMashouRule {}),
-        Box::new(VerbMashoukaRule {}),
-        Box::new(VerbTeKudasaiRule {}),
-        Box::new(VerbTeWaIkemasenRule {}),
-        Box::new(VerbTeIruRule {}),
-        Box::new(VerbNiIkuRule {}),
-        Box::new(VerbNaideKudasaiRule {}),
-        Box::new(VerbMadaTeInaiRule {}),
-        Box::new(VerbTaiRule {}),
-        Box::new(VerbTariRule {}),
-        Box::new(VerbTaKotoGaAruRule {}),
-        Box::new(VerbSugiruRule {}),
-        Box::new(VerbHouGaIiRule {}),
-        // Конструкции с прилагательными
-        Box::new(AdjectiveNaruRule {}),
-        // Конструкции намерения
-        Box::new(ConstructionTsumoriRule {}),
-    ]
-});
+/// Встроенные правила, описанные в файлах rules/*.toml
+const BUILT_IN_RULES: [&str; 54] = [
+    include_str!("rules/nda.toml"),
+    include_str!("rules/adjective_past.toml"),
+    // Глагольные формы
+    include_str!("rules/verb_masenka.toml"),
+    include_str!("rules/verb_mashou.toml"),
+    include_str!("rules/verb_mashouka.toml"),
+    include_str!("rules/verb_te_kudasai.toml"),
+    include_str!("rules/verb_te_wa_ikemasen.toml"),
+    include_str!("rules/verb_te_iru.toml"),
+    include_str!("rules/verb_ni_iku.toml"),
+    include_str!("rules/verb_naide_kudasai.toml"),
+    include_str!("rules/verb_mada_te_inai.toml"),
+    include_str!("rules/verb_tai.toml"),
+    include_str!("rules/verb_tari.toml"),
+    include_str!("rules/verb_ta_koto_ga_aru.toml"),
+    include_str!("rules/verb_sugiru.toml"),
+    include_str!("rules/verb_hou_ga_ii.toml"),
+    // Конструкции с прилагательными
+    include_str!("rules/adjective_naru.toml"),
+    // Конструкции намерения
+    include_str!("rules/verb_tsumori.toml"),
+    // N4: условные формы
+    include_str!("rules/conditional_ba.toml"),
+    include_str!("rules/conditional_tara.toml"),
+    include_str!("rules/conditional_nara.toml"),
+    // N4: производные глаголы
+    include_str!("rules/verb_potential.toml"),
+    include_str!("rules/verb_passive.toml"),
+    include_str!("rules/verb_causative.toml"),
+    // N4: глагольные конструкции
+    include_str!("rules/verb_you_ni_suru.toml"),
+    include_str!("rules/verb_you_ni_naru.toml"),
+    include_str!("rules/verb_nagara.toml"),
+    include_str!("rules/verb_you_to_omou.toml"),
+    include_str!("rules/verb_te_shimau.toml"),
+    include_str!("rules/verb_te_oku.toml"),
+    include_str!("rules/verb_te_miru.toml"),
+    include_str!("rules/verb_tsuzukeru.toml"),
+    include_str!("rules/hazu.toml"),
+    // N3
+    include_str!("rules/verb_causative_passive.toml"),
+    include_str!("rules/ba_hodo.toml"),
+    include_str!("rules/wake.toml"),
+    include_str!("rules/wake_ga_nai.toml"),
+    include_str!("rules/beki.toml"),
+    include_str!("rules/tame_ni.toml"),
+    include_str!("rules/ba_yokatta.toml"),
+    include_str!("rules/verb_you_ni.toml"),
+    // N2
+    include_str!("rules/verb_gachi.toml"),
+    include_str!("rules/verb_ppanashi.toml"),
+    include_str!("rules/verb_kaneru.toml"),
+    include_str!("rules/verb_kanenai.toml"),
+    include_str!("rules/verb_uru.toml"),
+    include_str!("rules/verb_ue_de.toml"),
+    include_str!("rules/verb_tsutsu.toml"),
+    // N1
+    include_str!("rules/verb_te_yamanai.toml"),
+    include_str!("rules/verb_bekarazu.toml"),
+    include_str!("rules/verb_ni_taenai.toml"),
+    include_str!("rules/verb_ya_ina_ya.toml"),
+    include_str!("rules/verb_mai.toml"),
+    include_str!("rules/verb_ga_saigo.toml"),
+];
+
+static GRAMMAR_LIST: OnceLock<Vec<Box<dyn GrammarRule>>> = OnceLock::new();
+
+fn built_in_rules() -> Vec<Box<dyn GrammarRule>> {
+    BUILT_IN_RULES
+        .iter()
+        .map(|text| {
+            let rule = DeclarativeRule::from_toml(text).expect("built-in grammar rule is valid");
+            Box::new(rule) as Box<dyn GrammarRule>
+        })
+        .collect()
+}
 
 pub fn grammar_rules() -> &'static [Box<dyn GrammarRule>] {
-    &GRAMMAR_LIST
+    GRAMMAR_LIST.get_or_init(built_in_rules)
+}
+
+/// Правило по его идентификатору
+pub fn find_grammar_rule(id: &str) -> Option<&'static Box<dyn GrammarRule>> {
+    grammar_rules().iter().find(|rule| rule.id() == id)
+}
+
+/// Добавляет пользовательские правила к встроенным.
+///
+/// Список правил фиксируется при первом обращении, поэтому функция вызывается один раз
+/// при запуске приложения. Правила с уже занятым идентификатором пропускаются.
+/// Возвращает количество добавленных правил
+pub fn register_user_grammar_rules(rules: Vec<DeclarativeRule>) -> Result<usize, KeikakuError> {
+    let mut list = built_in_rules();
+    let mut added = 0;
+
+    for rule in rules {
+        if list.iter().any(|known| known.id() == rule.id()) {
+            tracing::warn!("Grammar rule '{}' is already defined, skipping", rule.id());
+            continue;
+        }
+        list.push(Box::new(rule));
+        added += 1;
+    }
+
+    GRAMMAR_LIST
+        .set(list)
+        .map_err(|_| KeikakuError::GrammarFormatError {
+            reason: "Grammar rules are already loaded".to_string(),
+        })?;
+
+    Ok(added)
 }
 
 pub trait GrammarRule: Send + Sync {
+    /// Стабильный идентификатор правила, не зависящий от языка интерфейса
+    fn id(&self) -> &str;
     fn level(&self) -> JapaneseLevel;
     fn title(&self, lang: &NativeLanguage) -> String;
     fn md_description(&self, lang: &NativeLanguage) -> String;
 
     fn apply_to(&self) -> Vec<PartOfSpeech>;
     fn format(&self, word: &str, part_of_speech: &PartOfSpeech) -> Result<String, KeikakuError>;
+
+    /// Все правильные формы конструкции для слова, из которых `format` выбирает одну
+    fn variants(
+        &self,
+        word: &str,
+        part_of_speech: &PartOfSpeech,
+    ) -> Result<Vec<String>, KeikakuError> {
+        Ok(vec![self.format(word, part_of_speech)?])
+    }
+
+    /// Все формы конструкции для слова, по которым правило находится в тексте
+    fn patterns(
+        &self,
+        word: &str,
+        part_of_speech: &PartOfSpeech,
+    ) -> Result<Vec<String>, KeikakuError> {
+        Ok(vec![self.format(word, part_of_speech)?])
+    }
 }
@@ -1,265 +1,963 @@
-/// Преобразует глагол в て-форму
-/// Правила для групп глаголов:
-/// - Группа II (годан): зависит от последнего слога
-/// - Группа I (итидан): る → て
-/// - Группа III (неправильные): する → して, くる/来る → きて
-pub fn to_te_form(word: &str) -> String {
-    // Проверяем на неправильные глаголы
-    if word == "する" {
-        return "して".to_string();
-    }
-    if word == "くる" || word == "来る" {
-        return "きて".to_string();
+use crate::domain::{
+    KeikakuError,
+    tokenizer::{PartOfSpeech, TokenInfo, Tokenizer},
+};
+
+/// Словарные формы 行く: て- и た-формы 行って・行った вместо 行いて
+const IKU_LEMMAS: [&str; 3] = ["行く", "逝く", "いく"];
+/// Словарные формы ある: отрицание ない вместо あらない
+const ARU_LEMMAS: [&str; 3] = ["有る", "在る", "ある"];
+/// Вежливые глаголы с ます-основой на い: くださる → ください
+const HONORIFIC_LEMMAS: [&str; 10] = [
+    "下さる",
+    "くださる",
+    "為さる",
+    "なさる",
+    "いらっしゃる",
+    "仰る",
+    "おっしゃる",
+    "御座る",
+    "ござる",
+    "居らっしゃる",
+];
+
+/// Ряды годан-глаголов: а, и, у, э, о
+const GODAN_ROWS: [[char; 5]; 9] = [
+    ['わ', 'い', 'う', 'え', 'お'],
+    ['か', 'き', 'く', 'け', 'こ'],
+    ['が', 'ぎ', 'ぐ', 'げ', 'ご'],
+    ['さ', 'し', 'す', 'せ', 'そ'],
+    ['た', 'ち', 'つ', 'て', 'と'],
+    ['な', 'に', 'ぬ', 'ね', 'の'],
+    ['ば', 'び', 'ぶ', 'べ', 'ぼ'],
+    ['ま', 'み', 'む', 'め', 'も'],
+    ['ら', 'り', 'る', 'れ', 'ろ'],
+];
+const A_ROW: usize = 0;
+const I_ROW: usize = 1;
+const E_ROW: usize = 3;
+const O_ROW: usize = 4;
+
+/// Класс спряжения глагола по типу спряжения UniDic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerbClass {
+    /// Группа I (годан): последний слог меняется по рядам каны
+    Godan,
+    /// 行く и составные глаголы на 行く: 行って, 行った
+    Iku,
+    /// ある: отрицание ない
+    Aru,
+    /// くださる, なさる, いらっしゃる, おっしゃる, ござる: ます-основа на い
+    Honorific,
+    /// Группа II (итидан): る отбрасывается
+    Ichidan,
+    /// する и составные глаголы на する
+    Suru,
+    /// 来る и составные глаголы на 来る
+    Kuru,
+}
+
+impl VerbClass {
+    /// Класс глагола в словарной форме. У составных глаголов (勉強する, 持って行く)
+    /// класс определяется по последнему слову
+    pub fn of(word: &str) -> Result<Self, KeikakuError> {
+        let tokens = Tokenizer::shared()?.tokenize(word.trim())?;
+
+        match tokens.last() {
+            Some(token) if token.part_of_speech() == &PartOfSpeech::Verb => Self::of_token(token),
+            _ => Err(KeikakuError::GrammarFormatError {
+                reason: format!("'{word}' is not a verb in dictionary form"),
+            }),
+        }
     }
 
-    // Определяем группу по последнему символу
-    let chars: Vec<char> = word.chars().collect();
-    if chars.is_empty() {
-        return word.to_string();
+    /// Класс глагола по токену UniDic
+    pub fn of_token(token: &TokenInfo) -> Result<Self, KeikakuError> {
+        let conjugation_type = token.conjugation_type();
+        let lemma = token.orthographic_base_form();
+
+        if conjugation_type.starts_with("五段") {
+            Ok(if IKU_LEMMAS.contains(&lemma) {
+                VerbClass::Iku
+            } else if ARU_LEMMAS.contains(&lemma) {
+                VerbClass::Aru
+            } else if HONORIFIC_LEMMAS.contains(&lemma) {
+                VerbClass::Honorific
+            } else {
+                VerbClass::Godan
+            })
+        } else if conjugation_type.starts_with("上一段") || conjugation_type.starts_with("下一段")
+        {
+            Ok(VerbClass::Ichidan)
+        } else if conjugation_type == "サ行変格" {
+            Ok(VerbClass::Suru)
+        } else if conjugation_type == "カ行変格" {
+            Ok(VerbClass::Kuru)
+        } else {
+            Err(KeikakuError::GrammarFormatError {
+                reason: format!(
+                    "Unsupported conjugation type '{conjugation_type}' for '{}'",
+                    token.orthographic_surface_form()
+                ),
+            })
+        }
     }
 
-    let last_char = chars[chars.len() - 1];
-
-    // Группа I (итидан) - оканчиваются на る, но не входят в группу II
-    if last_char == 'る' {
-        // Проверяем, не является ли это глаголом группы II
-        // Глаголы группы II не оканчиваются наiru, ari, ori, uri
-        if chars.len() >= 2 {
-            let second_last = chars[chars.len() - 2];
-            if !matches!(second_last, 'い' | 'え' | 'お' | 'う') {
-                // Группа I - меняем る на て
-                let mut result = word.to_string();
-                result.pop(); // убираем る
-                result.push('て');
-                return result;
+    pub fn te_form(self, word: &str) -> Result<String, KeikakuError> {
+        match self {
+            VerbClass::Godan | VerbClass::Aru | VerbClass::Honorific => {
+                let (stem, ending) = split_ending(word)?;
+                let suffix = match ending {
+                    'く' => "いて",
+                    'ぐ' => "いで",
+                    'す' => "して",
+                    'つ' | 'る' | 'う' => "って",
+                    'ぬ' | 'ぶ' | 'む' => "んで",
+                    _ => return Err(unexpected_ending(word)),
+                };
+                Ok(format!("{stem}{suffix}"))
             }
+            VerbClass::Iku => Ok(format!("{}って", strip_ending(word, 'く')?)),
+            VerbClass::Ichidan => Ok(format!("{}て", strip_ending(word, 'る')?)),
+            VerbClass::Suru => Ok(format!("{}して", strip_suru(word)?)),
+            VerbClass::Kuru => kuru_form(word, "きて"),
         }
     }
 
-    // Группа II (годан) - правила по последнему слогу
-    match last_char {
-        'く' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("いて");
-            result
-        }
-        'ぐ' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("いで");
-            result
-        }
-        'す' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("して");
-            result
-        }
-        'つ' | 'る' | 'う' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("って");
-            result
-        }
-        'ぬ' | 'ぶ' | 'む' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("んで");
-            result
+    pub fn nai_form(self, word: &str) -> Result<String, KeikakuError> {
+        match self {
+            VerbClass::Godan | VerbClass::Iku | VerbClass::Honorific => {
+                Ok(format!("{}ない", godan_shift(word, A_ROW)?))
+            }
+            VerbClass::Aru => Ok("ない".to_string()),
+            VerbClass::Ichidan => Ok(format!("{}ない", strip_ending(word, 'る')?)),
+            VerbClass::Suru => Ok(format!("{}しない", strip_suru(word)?)),
+            VerbClass::Kuru => kuru_form(word, "こない"),
         }
-        _ => word.to_string(), // на случай неизвестной формы
     }
-}
 
-/// Преобразует глагол в ない-форму
-/// Для этого нужно получить основу глагола и добавить ない
-pub fn to_nai_form(word: &str) -> String {
-    // Проверяем на неправильные глаголы
-    if word == "する" {
-        return "しない".to_string();
+    pub fn masu_stem(self, word: &str) -> Result<String, KeikakuError> {
+        match self {
+            VerbClass::Godan | VerbClass::Iku | VerbClass::Aru => godan_shift(word, I_ROW),
+            VerbClass::Honorific => Ok(format!("{}い", strip_ending(word, 'る')?)),
+            VerbClass::Ichidan => Ok(strip_ending(word, 'る')?.to_string()),
+            VerbClass::Suru => Ok(format!("{}し", strip_suru(word)?)),
+            VerbClass::Kuru => kuru_form(word, "き"),
+        }
     }
-    if word == "くる" || word == "来る" {
-        return "こない".to_string();
+
+    pub fn ta_form(self, word: &str) -> Result<String, KeikakuError> {
+        let te_form = self.te_form(word)?;
+        let (stem, ending) = split_ending(&te_form)?;
+        let ending = if ending == 'で' { 'だ' } else { 'た' };
+
+        Ok(format!("{stem}{ending}"))
     }
 
-    let chars: Vec<char> = word.chars().collect();
-    if chars.is_empty() {
-        return word.to_string();
+    /// Прошедшее отрицание: 書かなかった
+    pub fn nai_past_form(self, word: &str) -> Result<String, KeikakuError> {
+        let nai_form = self.nai_form(word)?;
+        Ok(format!("{}かった", strip_ending(&nai_form, 'い')?))
     }
 
-    let last_char = chars[chars.len() - 1];
-
-    // Группа I (итидан) - оканчиваются на る
-    if last_char == 'る' && chars.len() >= 2 {
-        let second_last = chars[chars.len() - 2];
-        if !matches!(second_last, 'い' | 'え' | 'お' | 'う') {
-            // Группа I - убираем る и добавляем ない
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("ない");
-            return result;
+    /// Потенциальная форма: 書ける, 食べられる, できる
+    pub fn potential_form(self, word: &str) -> Result<String, KeikakuError> {
+        match self {
+            VerbClass::Godan | VerbClass::Iku | VerbClass::Aru | VerbClass::Honorific => {
+                Ok(format!("{}る", godan_shift(word, E_ROW)?))
+            }
+            VerbClass::Ichidan => Ok(format!("{}られる", strip_ending(word, 'る')?)),
+            VerbClass::Suru => Ok(format!("{}できる", strip_suru(word)?)),
+            VerbClass::Kuru => kuru_form(word, "こられる"),
         }
     }
 
-    // Группа II (годан) - меняем последний слог
-    match last_char {
-        'う' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("わない");
-            result
-        }
-        'く' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("かない");
-            result
-        }
-        'ぐ' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("がない");
-            result
-        }
-        'す' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("さない");
-            result
-        }
-        'つ' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("たない");
-            result
+    /// Страдательный залог: 書かれる, 食べられる, される
+    pub fn passive_form(self, word: &str) -> Result<String, KeikakuError> {
+        match self {
+            VerbClass::Godan | VerbClass::Iku | VerbClass::Aru | VerbClass::Honorific => {
+                Ok(format!("{}れる", godan_shift(word, A_ROW)?))
+            }
+            VerbClass::Ichidan => Ok(format!("{}られる", strip_ending(word, 'る')?)),
+            VerbClass::Suru => Ok(format!("{}される", strip_suru(word)?)),
+            VerbClass::Kuru => kuru_form(word, "こられる"),
         }
-        'ぬ' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("なない");
-            result
+    }
+
+    /// Побудительный залог: 書かせる, 食べさせる, させる
+    pub fn causative_form(self, word: &str) -> Result<String, KeikakuError> {
+        match self {
+            VerbClass::Godan | VerbClass::Iku | VerbClass::Aru | VerbClass::Honorific => {
+                Ok(format!("{}せる", godan_shift(word, A_ROW)?))
+            }
+            VerbClass::Ichidan => Ok(format!("{}させる", strip_ending(word, 'る')?)),
+            VerbClass::Suru => Ok(format!("{}させる", strip_suru(word)?)),
+            VerbClass::Kuru => kuru_form(word, "こさせる"),
         }
-        'ぶ' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("ばない");
-            result
+    }
+
+    /// Побудительно-страдательный залог в полной форме: 書かせられる
+    pub fn causative_passive_form(self, word: &str) -> Result<String, KeikakuError> {
+        let causative = self.causative_form(word)?;
+        Ok(format!("{}られる", strip_ending(&causative, 'る')?))
+    }
+
+    /// Форма намерения: 書こう, 食べよう, しよう
+    pub fn volitional_form(self, word: &str) -> Result<String, KeikakuError> {
+        match self {
+            VerbClass::Godan | VerbClass::Iku | VerbClass::Aru | VerbClass::Honorific => {
+                Ok(format!("{}う", godan_shift(word, O_ROW)?))
+            }
+            VerbClass::Ichidan => Ok(format!("{}よう", strip_ending(word, 'る')?)),
+            VerbClass::Suru => Ok(format!("{}しよう", strip_suru(word)?)),
+            VerbClass::Kuru => kuru_form(word, "こよう"),
         }
-        'む' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("まない");
-            result
+    }
+
+    /// Повелительная форма: 書け, 食べろ, しろ, こい, ください
+    pub fn imperative_form(self, word: &str) -> Result<String, KeikakuError> {
+        match self {
+            VerbClass::Godan | VerbClass::Iku | VerbClass::Aru => godan_shift(word, E_ROW),
+            VerbClass::Honorific => Ok(format!("{}い", strip_ending(word, 'る')?)),
+            VerbClass::Ichidan => Ok(format!("{}ろ", strip_ending(word, 'る')?)),
+            VerbClass::Suru => Ok(format!("{}しろ", strip_suru(word)?)),
+            VerbClass::Kuru => kuru_form(word, "こい"),
         }
-        'る' => {
-            let mut result = word.to_string();
-            result.pop();
-            result.push_str("らない");
-            result
+    }
+
+    /// Условная форма на ば: 書けば, 食べれば, すれば
+    pub fn ba_form(self, word: &str) -> Result<String, KeikakuError> {
+        match self {
+            VerbClass::Godan | VerbClass::Iku | VerbClass::Aru | VerbClass::Honorific => {
+                Ok(format!("{}ば", godan_shift(word, E_ROW)?))
+            }
+            VerbClass::Ichidan => Ok(format!("{}れば", strip_ending(word, 'る')?)),
+            VerbClass::Suru => Ok(format!("{}すれば", strip_suru(word)?)),
+            VerbClass::Kuru => kuru_form(word, "くれば"),
         }
-        _ => format!("{}ない", word),
+    }
+
+    /// Условная форма на たら: 書いたら
+    pub fn tara_form(self, word: &str) -> Result<String, KeikakuError> {
+        Ok(format!("{}ら", self.ta_form(word)?))
+    }
+}
+
+fn unexpected_ending(word: &str) -> KeikakuError {
+    KeikakuError::GrammarFormatError {
+        reason: format!("Unexpected verb ending in '{word}'"),
     }
 }
 
-/// Преобразует глагол в た-форму (аналогично て-форме, но с た вместо て)
-pub fn to_ta_form(word: &str) -> String {
-    // Получаем て-форму и заменяем て на た
-    let te_form = to_te_form(word);
-    te_form.replace("て", "た").replace("で", "だ")
+/// Основа и последняя кана словарной формы
+fn split_ending(word: &str) -> Result<(&str, char), KeikakuError> {
+    let word = word.trim();
+    let ending = word.chars().last().ok_or_else(|| unexpected_ending(word))?;
+    Ok((&word[..word.len() - ending.len_utf8()], ending))
+}
+
+fn strip_ending(word: &str, ending: char) -> Result<&str, KeikakuError> {
+    word.trim()
+        .strip_suffix(ending)
+        .ok_or_else(|| unexpected_ending(word))
+}
+
+fn strip_suru(word: &str) -> Result<&str, KeikakuError> {
+    word.trim()
+        .strip_suffix("する")
+        .ok_or_else(|| unexpected_ending(word))
+}
+
+/// Форма 来る по ее записи каной `kana` (きて, こない). Если глагол записан кандзи,
+/// первый слог пишется как 来: 来て, 来ない
+fn kuru_form(word: &str, kana: &str) -> Result<String, KeikakuError> {
+    let word = word.trim();
+    if let Some(prefix) = word.strip_suffix("来る") {
+        let mut okurigana = kana.chars();
+        okurigana.next();
+        return Ok(format!("{prefix}来{}", okurigana.as_str()));
+    }
+    word.strip_suffix("くる")
+        .map(|prefix| format!("{prefix}{kana}"))
+        .ok_or_else(|| unexpected_ending(word))
+}
+
+/// Меняет последний слог годан-глагола на слог того же столбца из ряда `row`
+fn godan_shift(word: &str, row: usize) -> Result<String, KeikakuError> {
+    let (stem, ending) = split_ending(word)?;
+    let shifted = GODAN_ROWS
+        .iter()
+        .find(|columns| columns[2] == ending)
+        .map(|columns| columns[row])
+        .ok_or_else(|| unexpected_ending(word))?;
+
+    Ok(format!("{stem}{shifted}"))
+}
+
+/// Преобразует глагол в て-форму
+pub fn to_te_form(word: &str) -> Result<String, KeikakuError> {
+    VerbClass::of(word)?.te_form(word)
+}
+
+/// Преобразует глагол в ない-форму
+pub fn to_nai_form(word: &str) -> Result<String, KeikakuError> {
+    VerbClass::of(word)?.nai_form(word)
+}
+
+/// Преобразует глагол в た-форму: て-форма с た вместо て
+pub fn to_ta_form(word: &str) -> Result<String, KeikakuError> {
+    VerbClass::of(word)?.ta_form(word)
 }
 
 /// Преобразует глагол в ます-форму
-/// Просто добавляем ます к основе глагола
-pub fn to_masu_form(word: &str) -> String {
-    format!("{}ます", word)
+pub fn to_masu_form(word: &str) -> Result<String, KeikakuError> {
+    Ok(format!("{}ます", to_masu_stem(word)?))
 }
 
 /// Преобразует глагол в ません-форму
-/// Добавляем ません к основе глагола
-pub fn to_masen_form(word: &str) -> String {
-    format!("{}ません", word)
+pub fn to_masen_form(word: &str) -> Result<String, KeikakuError> {
+    Ok(format!("{}ません", to_masu_stem(word)?))
 }
 
 /// Преобразует глагол в ましょう-форму
-/// Добавляем ましょう к основе глагола
-pub fn to_mashou_form(word: &str) -> String {
-    format!("{}ましょう", word)
+pub fn to_mashou_form(word: &str) -> Result<String, KeikakuError> {
+    Ok(format!("{}ましょう", to_masu_stem(word)?))
 }
 
-/// Преобразует глагол в ます-форму без ます (основа для других конструкций)
-/// Возвращает основу глагола для конструкций типа ～たい, ～すぎる и т.д.
-pub fn to_masu_stem(word: &str) -> String {
-    word.to_string() // для базовых глаголов это просто исходная форма
+/// Основа ます-формы для конструкций ～たい, ～すぎる, ～に行く
+pub fn to_masu_stem(word: &str) -> Result<String, KeikakuError> {
+    VerbClass::of(word)?.masu_stem(word)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const VERBS: &[(&str, VerbClass, &str)] = &[
+        ("食べる", VerbClass::Ichidan, "食べて"),
+        ("見る", VerbClass::Ichidan, "見て"),
+        ("起きる", VerbClass::Ichidan, "起きて"),
+        ("寝る", VerbClass::Ichidan, "寝て"),
+        ("出る", VerbClass::Ichidan, "出て"),
+        ("着る", VerbClass::Ichidan, "着て"),
+        ("借りる", VerbClass::Ichidan, "借りて"),
+        ("降りる", VerbClass::Ichidan, "降りて"),
+        ("浴びる", VerbClass::Ichidan, "浴びて"),
+        ("できる", VerbClass::Ichidan, "できて"),
+        ("教える", VerbClass::Ichidan, "教えて"),
+        ("覚える", VerbClass::Ichidan, "覚えて"),
+        ("忘れる", VerbClass::Ichidan, "忘れて"),
+        ("開ける", VerbClass::Ichidan, "開けて"),
+        ("閉める", VerbClass::Ichidan, "閉めて"),
+        ("始める", VerbClass::Ichidan, "始めて"),
+        ("止める", VerbClass::Ichidan, "止めて"),
+        ("決める", VerbClass::Ichidan, "決めて"),
+        ("答える", VerbClass::Ichidan, "答えて"),
+        ("考える", VerbClass::Ichidan, "考えて"),
+        ("変える", VerbClass::Ichidan, "変えて"),
+        ("伝える", VerbClass::Ichidan, "伝えて"),
+        ("調べる", VerbClass::Ichidan, "調べて"),
+        ("比べる", VerbClass::Ichidan, "比べて"),
+        ("並べる", VerbClass::Ichidan, "並べて"),
+        ("集める", VerbClass::Ichidan, "集めて"),
+        ("続ける", VerbClass::Ichidan, "続けて"),
+        ("付ける", VerbClass::Ichidan, "付けて"),
+        ("受ける", VerbClass::Ichidan, "受けて"),
+        ("助ける", VerbClass::Ichidan, "助けて"),
+        ("届ける", VerbClass::Ichidan, "届けて"),
+        ("負ける", VerbClass::Ichidan, "負けて"),
+        ("逃げる", VerbClass::Ichidan, "逃げて"),
+        ("投げる", VerbClass::Ichidan, "投げて"),
+        ("上げる", VerbClass::Ichidan, "上げて"),
+        ("下げる", VerbClass::Ichidan, "下げて"),
+        ("曲げる", VerbClass::Ichidan, "曲げて"),
+        ("生きる", VerbClass::Ichidan, "生きて"),
+        ("過ぎる", VerbClass::Ichidan, "過ぎて"),
+        ("信じる", VerbClass::Ichidan, "信じて"),
+        ("感じる", VerbClass::Ichidan, "感じて"),
+        ("落ちる", VerbClass::Ichidan, "落ちて"),
+        ("足りる", VerbClass::Ichidan, "足りて"),
+        ("生まれる", VerbClass::Ichidan, "生まれて"),
+        ("遅れる", VerbClass::Ichidan, "遅れて"),
+        ("疲れる", VerbClass::Ichidan, "疲れて"),
+        ("入れる", VerbClass::Ichidan, "入れて"),
+        ("晴れる", VerbClass::Ichidan, "晴れて"),
+        ("流れる", VerbClass::Ichidan, "流れて"),
+        ("離れる", VerbClass::Ichidan, "離れて"),
+        ("壊れる", VerbClass::Ichidan, "壊れて"),
+        ("汚れる", VerbClass::Ichidan, "汚れて"),
+        ("慣れる", VerbClass::Ichidan, "慣れて"),
+        ("別れる", VerbClass::Ichidan, "別れて"),
+        ("倒れる", VerbClass::Ichidan, "倒れて"),
+        ("売れる", VerbClass::Ichidan, "売れて"),
+        ("見える", VerbClass::Ichidan, "見えて"),
+        ("聞こえる", VerbClass::Ichidan, "聞こえて"),
+        ("増える", VerbClass::Ichidan, "増えて"),
+        ("消える", VerbClass::Ichidan, "消えて"),
+        ("燃える", VerbClass::Ichidan, "燃えて"),
+        ("植える", VerbClass::Ichidan, "植えて"),
+        ("数える", VerbClass::Ichidan, "数えて"),
+        ("迎える", VerbClass::Ichidan, "迎えて"),
+        ("捕まえる", VerbClass::Ichidan, "捕まえて"),
+        ("与える", VerbClass::Ichidan, "与えて"),
+        ("抑える", VerbClass::Ichidan, "抑えて"),
+        ("備える", VerbClass::Ichidan, "備えて"),
+        ("鍛える", VerbClass::Ichidan, "鍛えて"),
+        ("訪れる", VerbClass::Ichidan, "訪れて"),
+        ("恐れる", VerbClass::Ichidan, "恐れて"),
+        ("隠れる", VerbClass::Ichidan, "隠れて"),
+        ("崩れる", VerbClass::Ichidan, "崩れて"),
+        ("溢れる", VerbClass::Ichidan, "溢れて"),
+        ("揺れる", VerbClass::Ichidan, "揺れて"),
+        ("触れる", VerbClass::Ichidan, "触れて"),
+        ("外れる", VerbClass::Ichidan, "外れて"),
+        ("現れる", VerbClass::Ichidan, "現れて"),
+        ("育てる", VerbClass::Ichidan, "育てて"),
+        ("建てる", VerbClass::Ichidan, "建てて"),
+        ("捨てる", VerbClass::Ichidan, "捨てて"),
+        ("立てる", VerbClass::Ichidan, "立てて"),
+        ("当てる", VerbClass::Ichidan, "当てて"),
+        ("慌てる", VerbClass::Ichidan, "慌てて"),
+        ("勤める", VerbClass::Ichidan, "勤めて"),
+        ("求める", VerbClass::Ichidan, "求めて"),
+        ("認める", VerbClass::Ichidan, "認めて"),
+        ("進める", VerbClass::Ichidan, "進めて"),
+        ("眺める", VerbClass::Ichidan, "眺めて"),
+        ("褒める", VerbClass::Ichidan, "褒めて"),
+        ("責める", VerbClass::Ichidan, "責めて"),
+        ("詰める", VerbClass::Ichidan, "詰めて"),
+        ("諦める", VerbClass::Ichidan, "諦めて"),
+        ("辞める", VerbClass::Ichidan, "辞めて"),
+        ("確かめる", VerbClass::Ichidan, "確かめて"),
+        ("温める", VerbClass::Ichidan, "温めて"),
+        ("冷める", VerbClass::Ichidan, "冷めて"),
+        ("冷える", VerbClass::Ichidan, "冷えて"),
+        ("締める", VerbClass::Ichidan, "締めて"),
+        ("混ぜる", VerbClass::Ichidan, "混ぜて"),
+        ("寄せる", VerbClass::Ichidan, "寄せて"),
+        ("乗せる", VerbClass::Ichidan, "乗せて"),
+        ("見せる", VerbClass::Ichidan, "見せて"),
+        ("任せる", VerbClass::Ichidan, "任せて"),
+        ("痩せる", VerbClass::Ichidan, "痩せて"),
+        ("似る", VerbClass::Ichidan, "似て"),
+        ("煮る", VerbClass::Ichidan, "煮て"),
+        ("居る", VerbClass::Ichidan, "居て"),
+        ("用いる", VerbClass::Ichidan, "用いて"),
+        ("伸びる", VerbClass::Ichidan, "伸びて"),
+        ("滅びる", VerbClass::Ichidan, "滅びて"),
+        ("詫びる", VerbClass::Ichidan, "詫びて"),
+        ("閉じる", VerbClass::Ichidan, "閉じて"),
+        ("生じる", VerbClass::Ichidan, "生じて"),
+        ("命じる", VerbClass::Ichidan, "命じて"),
+        ("応じる", VerbClass::Ichidan, "応じて"),
+        ("通じる", VerbClass::Ichidan, "通じて"),
+        ("禁じる", VerbClass::Ichidan, "禁じて"),
+        ("演じる", VerbClass::Ichidan, "演じて"),
+        ("試みる", VerbClass::Ichidan, "試みて"),
+        ("着替える", VerbClass::Ichidan, "着替えて"),
+        ("乗り換える", VerbClass::Ichidan, "乗り換えて"),
+        ("片付ける", VerbClass::Ichidan, "片付けて"),
+        ("見つける", VerbClass::Ichidan, "見つけて"),
+        ("出かける", VerbClass::Ichidan, "出かけて"),
+        ("知らせる", VerbClass::Ichidan, "知らせて"),
+        ("間違える", VerbClass::Ichidan, "間違えて"),
+        ("覚める", VerbClass::Ichidan, "覚めて"),
+        ("溶ける", VerbClass::Ichidan, "溶けて"),
+        ("焼ける", VerbClass::Ichidan, "焼けて"),
+        ("抜ける", VerbClass::Ichidan, "抜けて"),
+        ("避ける", VerbClass::Ichidan, "避けて"),
+        ("分ける", VerbClass::Ichidan, "分けて"),
+        ("掛ける", VerbClass::Ichidan, "掛けて"),
+        ("欠ける", VerbClass::Ichidan, "欠けて"),
+        ("明ける", VerbClass::Ichidan, "明けて"),
+        ("向ける", VerbClass::Ichidan, "向けて"),
+        ("預ける", VerbClass::Ichidan, "預けて"),
+        ("化ける", VerbClass::Ichidan, "化けて"),
+        ("尋ねる", VerbClass::Ichidan, "尋ねて"),
+        ("重ねる", VerbClass::Ichidan, "重ねて"),
+        ("真似る", VerbClass::Ichidan, "真似て"),
+        ("跳ねる", VerbClass::Ichidan, "跳ねて"),
+        ("兼ねる", VerbClass::Ichidan, "兼ねて"),
+        ("染める", VerbClass::Ichidan, "染めて"),
+        ("占める", VerbClass::Ichidan, "占めて"),
+        ("攻める", VerbClass::Ichidan, "攻めて"),
+        ("舐める", VerbClass::Ichidan, "舐めて"),
+        ("書く", VerbClass::Godan, "書いて"),
+        ("聞く", VerbClass::Godan, "聞いて"),
+        ("歩く", VerbClass::Godan, "歩いて"),
+        ("働く", VerbClass::Godan, "働いて"),
+        ("置く", VerbClass::Godan, "置いて"),
+        ("開く", VerbClass::Godan, "開いて"),
+        ("着く", VerbClass::Godan, "着いて"),
+        ("咲く", VerbClass::Godan, "咲いて"),
+        ("泣く", VerbClass::Godan, "泣いて"),
+        ("鳴く", VerbClass::Godan, "鳴いて"),
+        ("焼く", VerbClass::Godan, "焼いて"),
+        ("引く", VerbClass::Godan, "引いて"),
+        ("弾く", VerbClass::Godan, "弾いて"),
+        ("吹く", VerbClass::Godan, "吹いて"),
+        ("拭く", VerbClass::Godan, "拭いて"),
+        ("履く", VerbClass::Godan, "履いて"),
+        ("巻く", VerbClass::Godan, "巻いて"),
+        ("招く", VerbClass::Godan, "招いて"),
+        ("描く", VerbClass::Godan, "描いて"),
+        ("抱く", VerbClass::Godan, "抱いて"),
+        ("届く", VerbClass::Godan, "届いて"),
+        ("続く", VerbClass::Godan, "続いて"),
+        ("動く", VerbClass::Godan, "動いて"),
+        ("乾く", VerbClass::Godan, "乾いて"),
+        ("空く", VerbClass::Godan, "空いて"),
+        ("輝く", VerbClass::Godan, "輝いて"),
+        ("導く", VerbClass::Godan, "導いて"),
+        ("除く", VerbClass::Godan, "除いて"),
+        ("築く", VerbClass::Godan, "築いて"),
+        ("頂く", VerbClass::Godan, "頂いて"),
+        ("叩く", VerbClass::Godan, "叩いて"),
+        ("向く", VerbClass::Godan, "向いて"),
+        ("磨く", VerbClass::Godan, "磨いて"),
+        ("驚く", VerbClass::Godan, "驚いて"),
+        ("囁く", VerbClass::Godan, "囁いて"),
+        ("呟く", VerbClass::Godan, "呟いて"),
+        ("傾く", VerbClass::Godan, "傾いて"),
+        ("覗く", VerbClass::Godan, "覗いて"),
+        ("嘆く", VerbClass::Godan, "嘆いて"),
+        ("省く", VerbClass::Godan, "省いて"),
+        ("背く", VerbClass::Godan, "背いて"),
+        ("剥く", VerbClass::Godan, "剥いて"),
+        ("裂く", VerbClass::Godan, "裂いて"),
+        ("敷く", VerbClass::Godan, "敷いて"),
+        ("効く", VerbClass::Godan, "効いて"),
+        ("利く", VerbClass::Godan, "利いて"),
+        ("突く", VerbClass::Godan, "突いて"),
+        ("泳ぐ", VerbClass::Godan, "泳いで"),
+        ("急ぐ", VerbClass::Godan, "急いで"),
+        ("脱ぐ", VerbClass::Godan, "脱いで"),
+        ("騒ぐ", VerbClass::Godan, "騒いで"),
+        ("稼ぐ", VerbClass::Godan, "稼いで"),
+        ("防ぐ", VerbClass::Godan, "防いで"),
+        ("注ぐ", VerbClass::Godan, "注いで"),
+        ("研ぐ", VerbClass::Godan, "研いで"),
+        ("繋ぐ", VerbClass::Godan, "繋いで"),
+        ("嗅ぐ", VerbClass::Godan, "嗅いで"),
+        ("仰ぐ", VerbClass::Godan, "仰いで"),
+        ("漕ぐ", VerbClass::Godan, "漕いで"),
+        ("和らぐ", VerbClass::Godan, "和らいで"),
+        ("話す", VerbClass::Godan, "話して"),
+        ("出す", VerbClass::Godan, "出して"),
+        ("貸す", VerbClass::Godan, "貸して"),
+        ("返す", VerbClass::Godan, "返して"),
+        ("押す", VerbClass::Godan, "押して"),
+        ("消す", VerbClass::Godan, "消して"),
+        ("探す", VerbClass::Godan, "探して"),
+        ("指す", VerbClass::Godan, "指して"),
+        ("渡す", VerbClass::Godan, "渡して"),
+        ("直す", VerbClass::Godan, "直して"),
+        ("治す", VerbClass::Godan, "治して"),
+        ("起こす", VerbClass::Godan, "起こして"),
+        ("落とす", VerbClass::Godan, "落として"),
+        ("無くす", VerbClass::Godan, "無くして"),
+        ("残す", VerbClass::Godan, "残して"),
+        ("回す", VerbClass::Godan, "回して"),
+        ("冷やす", VerbClass::Godan, "冷やして"),
+        ("増やす", VerbClass::Godan, "増やして"),
+        ("減らす", VerbClass::Godan, "減らして"),
+        ("許す", VerbClass::Godan, "許して"),
+        ("隠す", VerbClass::Godan, "隠して"),
+        ("壊す", VerbClass::Godan, "壊して"),
+        ("倒す", VerbClass::Godan, "倒して"),
+        ("干す", VerbClass::Godan, "干して"),
+        ("示す", VerbClass::Godan, "示して"),
+        ("表す", VerbClass::Godan, "表して"),
+        ("果たす", VerbClass::Godan, "果たして"),
+        ("満たす", VerbClass::Godan, "満たして"),
+        ("試す", VerbClass::Godan, "試して"),
+        ("照らす", VerbClass::Godan, "照らして"),
+        ("鳴らす", VerbClass::Godan, "鳴らして"),
+        ("移す", VerbClass::Godan, "移して"),
+        ("写す", VerbClass::Godan, "写して"),
+        ("越す", VerbClass::Godan, "越して"),
+        ("引っ越す", VerbClass::Godan, "引っ越して"),
+        ("伸ばす", VerbClass::Godan, "伸ばして"),
+        ("飛ばす", VerbClass::Godan, "飛ばして"),
+        ("動かす", VerbClass::Godan, "動かして"),
+        ("乾かす", VerbClass::Godan, "乾かして"),
+        ("驚かす", VerbClass::Godan, "驚かして"),
+        ("溶かす", VerbClass::Godan, "溶かして"),
+        ("沸かす", VerbClass::Godan, "沸かして"),
+        ("殺す", VerbClass::Godan, "殺して"),
+        ("刺す", VerbClass::Godan, "刺して"),
+        ("申す", VerbClass::Godan, "申して"),
+        ("致す", VerbClass::Godan, "致して"),
+        ("暮らす", VerbClass::Godan, "暮らして"),
+        ("散らす", VerbClass::Godan, "散らして"),
+        ("揺らす", VerbClass::Godan, "揺らして"),
+        ("濡らす", VerbClass::Godan, "濡らして"),
+        ("漏らす", VerbClass::Godan, "漏らして"),
+        ("流す", VerbClass::Godan, "流して"),
+        ("外す", VerbClass::Godan, "外して"),
+        ("戻す", VerbClass::Godan, "戻して"),
+        ("通す", VerbClass::Godan, "通して"),
+        ("貸し出す", VerbClass::Godan, "貸し出して"),
+        ("待つ", VerbClass::Godan, "待って"),
+        ("持つ", VerbClass::Godan, "持って"),
+        ("立つ", VerbClass::Godan, "立って"),
+        ("打つ", VerbClass::Godan, "打って"),
+        ("勝つ", VerbClass::Godan, "勝って"),
+        ("育つ", VerbClass::Godan, "育って"),
+        ("役立つ", VerbClass::Godan, "役立って"),
+        ("目立つ", VerbClass::Godan, "目立って"),
+        ("経つ", VerbClass::Godan, "経って"),
+        ("保つ", VerbClass::Godan, "保って"),
+        ("放つ", VerbClass::Godan, "放って"),
+        ("撃つ", VerbClass::Godan, "撃って"),
+        ("死ぬ", VerbClass::Godan, "死んで"),
+        ("遊ぶ", VerbClass::Godan, "遊んで"),
+        ("呼ぶ", VerbClass::Godan, "呼んで"),
+        ("飛ぶ", VerbClass::Godan, "飛んで"),
+        ("学ぶ", VerbClass::Godan, "学んで"),
+        ("選ぶ", VerbClass::Godan, "選んで"),
+        ("並ぶ", VerbClass::Godan, "並んで"),
+        ("喜ぶ", VerbClass::Godan, "喜んで"),
+        ("運ぶ", VerbClass::Godan, "運んで"),
+        ("結ぶ", VerbClass::Godan, "結んで"),
+        ("転ぶ", VerbClass::Godan, "転んで"),
+        ("叫ぶ", VerbClass::Godan, "叫んで"),
+        ("浮かぶ", VerbClass::Godan, "浮かんで"),
+        ("滅ぶ", VerbClass::Godan, "滅んで"),
+        ("及ぶ", VerbClass::Godan, "及んで"),
+        ("読む", VerbClass::Godan, "読んで"),
+        ("飲む", VerbClass::Godan, "飲んで"),
+        ("住む", VerbClass::Godan, "住んで"),
+        ("休む", VerbClass::Godan, "休んで"),
+        ("頼む", VerbClass::Godan, "頼んで"),
+        ("込む", VerbClass::Godan, "込んで"),
+        ("混む", VerbClass::Godan, "混んで"),
+        ("進む", VerbClass::Godan, "進んで"),
+        ("包む", VerbClass::Godan, "包んで"),
+        ("盗む", VerbClass::Godan, "盗んで"),
+        ("踏む", VerbClass::Godan, "踏んで"),
+        ("噛む", VerbClass::Godan, "噛んで"),
+        ("望む", VerbClass::Godan, "望んで"),
+        ("悩む", VerbClass::Godan, "悩んで"),
+        ("楽しむ", VerbClass::Godan, "楽しんで"),
+        ("苦しむ", VerbClass::Godan, "苦しんで"),
+        ("悲しむ", VerbClass::Godan, "悲しんで"),
+        ("畳む", VerbClass::Godan, "畳んで"),
+        ("産む", VerbClass::Godan, "産んで"),
+        ("生む", VerbClass::Godan, "生んで"),
+        ("済む", VerbClass::Godan, "済んで"),
+        ("組む", VerbClass::Godan, "組んで"),
+        ("積む", VerbClass::Godan, "積んで"),
+        ("沈む", VerbClass::Godan, "沈んで"),
+        ("挟む", VerbClass::Godan, "挟んで"),
+        ("恨む", VerbClass::Godan, "恨んで"),
+        ("囲む", VerbClass::Godan, "囲んで"),
+        ("掴む", VerbClass::Godan, "掴んで"),
+        ("睨む", VerbClass::Godan, "睨んで"),
+        ("刻む", VerbClass::Godan, "刻んで"),
+        ("好む", VerbClass::Godan, "好んで"),
+        ("拝む", VerbClass::Godan, "拝んで"),
+        ("痛む", VerbClass::Godan, "痛んで"),
+        ("編む", VerbClass::Godan, "編んで"),
+        ("帰る", VerbClass::Godan, "帰って"),
+        ("入る", VerbClass::Godan, "入って"),
+        ("走る", VerbClass::Godan, "走って"),
+        ("知る", VerbClass::Godan, "知って"),
+        ("切る", VerbClass::Godan, "切って"),
+        ("要る", VerbClass::Godan, "要って"),
+        ("限る", VerbClass::Godan, "限って"),
+        ("減る", VerbClass::Godan, "減って"),
+        ("焦る", VerbClass::Godan, "焦って"),
+        ("蹴る", VerbClass::Godan, "蹴って"),
+        ("滑る", VerbClass::Godan, "滑って"),
+        ("喋る", VerbClass::Godan, "喋って"),
+        ("散る", VerbClass::Godan, "散って"),
+        ("握る", VerbClass::Godan, "握って"),
+        ("参る", VerbClass::Godan, "参って"),
+        ("覆る", VerbClass::Godan, "覆って"),
+        ("茂る", VerbClass::Godan, "茂って"),
+        ("湿る", VerbClass::Godan, "湿って"),
+        ("陥る", VerbClass::Godan, "陥って"),
+        ("遮る", VerbClass::Godan, "遮って"),
+        ("罵る", VerbClass::Godan, "罵って"),
+        ("練る", VerbClass::Godan, "練って"),
+        ("捻る", VerbClass::Godan, "捻って"),
+        ("嘲る", VerbClass::Godan, "嘲って"),
+        ("蘇る", VerbClass::Godan, "蘇って"),
+        ("翻る", VerbClass::Godan, "翻って"),
+        ("照る", VerbClass::Godan, "照って"),
+        ("分かる", VerbClass::Godan, "分かって"),
+        ("終わる", VerbClass::Godan, "終わって"),
+        ("座る", VerbClass::Godan, "座って"),
+        ("作る", VerbClass::Godan, "作って"),
+        ("取る", VerbClass::Godan, "取って"),
+        ("乗る", VerbClass::Godan, "乗って"),
+        ("送る", VerbClass::Godan, "送って"),
+        ("売る", VerbClass::Godan, "売って"),
+        ("降る", VerbClass::Godan, "降って"),
+        ("困る", VerbClass::Godan, "困って"),
+        ("止まる", VerbClass::Godan, "止まって"),
+        ("始まる", VerbClass::Godan, "始まって"),
+        ("決まる", VerbClass::Godan, "決まって"),
+        ("集まる", VerbClass::Godan, "集まって"),
+        ("変わる", VerbClass::Godan, "変わって"),
+        ("頑張る", VerbClass::Godan, "頑張って"),
+        ("渡る", VerbClass::Godan, "渡って"),
+        ("登る", VerbClass::Godan, "登って"),
+        ("守る", VerbClass::Godan, "守って"),
+        ("祈る", VerbClass::Godan, "祈って"),
+        ("怒る", VerbClass::Godan, "怒って"),
+        ("謝る", VerbClass::Godan, "謝って"),
+        ("触る", VerbClass::Godan, "触って"),
+        ("通る", VerbClass::Godan, "通って"),
+        ("残る", VerbClass::Godan, "残って"),
+        ("光る", VerbClass::Godan, "光って"),
+        ("曲がる", VerbClass::Godan, "曲がって"),
+        ("上がる", VerbClass::Godan, "上がって"),
+        ("下がる", VerbClass::Godan, "下がって"),
+        ("眠る", VerbClass::Godan, "眠って"),
+        ("踊る", VerbClass::Godan, "踊って"),
+        ("貼る", VerbClass::Godan, "貼って"),
+        ("塗る", VerbClass::Godan, "塗って"),
+        ("刷る", VerbClass::Godan, "刷って"),
+        ("剃る", VerbClass::Godan, "剃って"),
+        ("掘る", VerbClass::Godan, "掘って"),
+        ("叱る", VerbClass::Godan, "叱って"),
+        ("破る", VerbClass::Godan, "破って"),
+        ("配る", VerbClass::Godan, "配って"),
+        ("縛る", VerbClass::Godan, "縛って"),
+        ("断る", VerbClass::Godan, "断って"),
+        ("頼る", VerbClass::Godan, "頼って"),
+        ("盛る", VerbClass::Godan, "盛って"),
+        ("移る", VerbClass::Godan, "移って"),
+        ("写る", VerbClass::Godan, "写って"),
+        ("映る", VerbClass::Godan, "映って"),
+        ("治る", VerbClass::Godan, "治って"),
+        ("直る", VerbClass::Godan, "直って"),
+        ("当たる", VerbClass::Godan, "当たって"),
+        ("助かる", VerbClass::Godan, "助かって"),
+        ("見つかる", VerbClass::Godan, "見つかって"),
+        ("掛かる", VerbClass::Godan, "掛かって"),
+        ("儲かる", VerbClass::Godan, "儲かって"),
+        ("預かる", VerbClass::Godan, "預かって"),
+        ("泊まる", VerbClass::Godan, "泊まって"),
+        ("閉まる", VerbClass::Godan, "閉まって"),
+        ("温まる", VerbClass::Godan, "温まって"),
+        ("高まる", VerbClass::Godan, "高まって"),
+        ("広がる", VerbClass::Godan, "広がって"),
+        ("繋がる", VerbClass::Godan, "繋がって"),
+        ("転がる", VerbClass::Godan, "転がって"),
+        ("黙る", VerbClass::Godan, "黙って"),
+        ("張る", VerbClass::Godan, "張って"),
+        ("混じる", VerbClass::Godan, "混じって"),
+        ("計る", VerbClass::Godan, "計って"),
+        ("測る", VerbClass::Godan, "測って"),
+        ("量る", VerbClass::Godan, "量って"),
+        ("図る", VerbClass::Godan, "図って"),
+        ("絞る", VerbClass::Godan, "絞って"),
+        ("削る", VerbClass::Godan, "削って"),
+        ("飾る", VerbClass::Godan, "飾って"),
+        ("誇る", VerbClass::Godan, "誇って"),
+        ("劣る", VerbClass::Godan, "劣って"),
+        ("勝る", VerbClass::Godan, "勝って"),
+        ("粘る", VerbClass::Godan, "粘って"),
+        ("迫る", VerbClass::Godan, "迫って"),
+        ("偏る", VerbClass::Godan, "偏って"),
+        ("祭る", VerbClass::Godan, "祭って"),
+        ("釣る", VerbClass::Godan, "釣って"),
+        ("吊る", VerbClass::Godan, "吊って"),
+        ("殴る", VerbClass::Godan, "殴って"),
+        ("被る", VerbClass::Godan, "被って"),
+        ("隔たる", VerbClass::Godan, "隔たって"),
+        ("買う", VerbClass::Godan, "買って"),
+        ("会う", VerbClass::Godan, "会って"),
+        ("言う", VerbClass::Godan, "言って"),
+        ("使う", VerbClass::Godan, "使って"),
+        ("洗う", VerbClass::Godan, "洗って"),
+        ("歌う", VerbClass::Godan, "歌って"),
+        ("習う", VerbClass::Godan, "習って"),
+        ("払う", VerbClass::Godan, "払って"),
+        ("思う", VerbClass::Godan, "思って"),
+        ("笑う", VerbClass::Godan, "笑って"),
+        ("手伝う", VerbClass::Godan, "手伝って"),
+        ("違う", VerbClass::Godan, "違って"),
+        ("吸う", VerbClass::Godan, "吸って"),
+        ("拾う", VerbClass::Godan, "拾って"),
+        ("貰う", VerbClass::Godan, "貰って"),
+        ("誘う", VerbClass::Godan, "誘って"),
+        ("戦う", VerbClass::Godan, "戦って"),
+        ("迷う", VerbClass::Godan, "迷って"),
+        ("失う", VerbClass::Godan, "失って"),
+        ("扱う", VerbClass::Godan, "扱って"),
+        ("救う", VerbClass::Godan, "救って"),
+        ("従う", VerbClass::Godan, "従って"),
+        ("争う", VerbClass::Godan, "争って"),
+        ("疑う", VerbClass::Godan, "疑って"),
+        ("伴う", VerbClass::Godan, "伴って"),
+        ("補う", VerbClass::Godan, "補って"),
+        ("祝う", VerbClass::Godan, "祝って"),
+        ("敬う", VerbClass::Godan, "敬って"),
+        ("狙う", VerbClass::Godan, "狙って"),
+        ("奪う", VerbClass::Godan, "奪って"),
+        ("養う", VerbClass::Godan, "養って"),
+        ("縫う", VerbClass::Godan, "縫って"),
+        ("願う", VerbClass::Godan, "願って"),
+        ("向かう", VerbClass::Godan, "向かって"),
+        ("通う", VerbClass::Godan, "通って"),
+        ("間に合う", VerbClass::Godan, "間に合って"),
+        ("似合う", VerbClass::Godan, "似合って"),
+        ("追う", VerbClass::Godan, "追って"),
+        ("負う", VerbClass::Godan, "負って"),
+        ("酔う", VerbClass::Godan, "酔って"),
+        ("揃う", VerbClass::Godan, "揃って"),
+        ("伺う", VerbClass::Godan, "伺って"),
+        ("匂う", VerbClass::Godan, "匂って"),
+        ("雇う", VerbClass::Godan, "雇って"),
+        ("競う", VerbClass::Godan, "競って"),
+        ("食う", VerbClass::Godan, "食って"),
+        ("嫌う", VerbClass::Godan, "嫌って"),
+        ("行く", VerbClass::Iku, "行って"),
+        ("出て行く", VerbClass::Iku, "出て行って"),
+        ("持って行く", VerbClass::Iku, "持って行って"),
+        ("ある", VerbClass::Aru, "あって"),
+        ("くださる", VerbClass::Honorific, "くださって"),
+        ("なさる", VerbClass::Honorific, "なさって"),
+        ("いらっしゃる", VerbClass::Honorific, "いらっしゃって"),
+        ("おっしゃる", VerbClass::Honorific, "おっしゃって"),
+        ("する", VerbClass::Suru, "して"),
+        ("勉強する", VerbClass::Suru, "勉強して"),
+        ("運動する", VerbClass::Suru, "運動して"),
+        ("料理する", VerbClass::Suru, "料理して"),
+        ("掃除する", VerbClass::Suru, "掃除して"),
+        ("洗濯する", VerbClass::Suru, "洗濯して"),
+        ("電話する", VerbClass::Suru, "電話して"),
+        ("説明する", VerbClass::Suru, "説明して"),
+        ("練習する", VerbClass::Suru, "練習して"),
+        ("結婚する", VerbClass::Suru, "結婚して"),
+        ("旅行する", VerbClass::Suru, "旅行して"),
+        ("散歩する", VerbClass::Suru, "散歩して"),
+        ("準備する", VerbClass::Suru, "準備して"),
+        ("心配する", VerbClass::Suru, "心配して"),
+        ("約束する", VerbClass::Suru, "約束して"),
+        ("質問する", VerbClass::Suru, "質問して"),
+        ("紹介する", VerbClass::Suru, "紹介して"),
+        ("確認する", VerbClass::Suru, "確認して"),
+        ("連絡する", VerbClass::Suru, "連絡して"),
+        ("予約する", VerbClass::Suru, "予約して"),
+        ("案内する", VerbClass::Suru, "案内して"),
+        ("注意する", VerbClass::Suru, "注意して"),
+        ("失敗する", VerbClass::Suru, "失敗して"),
+        ("成功する", VerbClass::Suru, "成功して"),
+        ("出発する", VerbClass::Suru, "出発して"),
+        ("到着する", VerbClass::Suru, "到着して"),
+        ("卒業する", VerbClass::Suru, "卒業して"),
+        ("研究する", VerbClass::Suru, "研究して"),
+        ("経験する", VerbClass::Suru, "経験して"),
+        ("参加する", VerbClass::Suru, "参加して"),
+        ("来る", VerbClass::Kuru, "来て"),
+        ("くる", VerbClass::Kuru, "きて"),
+        ("持って来る", VerbClass::Kuru, "持って来て"),
+        ("連れて来る", VerbClass::Kuru, "連れて来て"),
+    ];
+
     #[test]
     fn test_te_form_group2() {
-        assert_eq!(to_te_form("行く"), "行って");
-        assert_eq!(to_te_form("話す"), "話して");
-        assert_eq!(to_te_form("読む"), "読んで");
-        assert_eq!(to_te_form("書く"), "書いて");
-        assert_eq!(to_te_form("泳ぐ"), "泳いで");
+        assert_eq!(to_te_form("行く").unwrap(), "行って");
+        assert_eq!(to_te_form("話す").unwrap(), "話して");
+        assert_eq!(to_te_form("読む").unwrap(), "読んで");
+        assert_eq!(to_te_form("書く").unwrap(), "書いて");
+        assert_eq!(to_te_form("泳ぐ").unwrap(), "泳いで");
     }
 
     #[test]
     fn test_te_form_group1() {
-        assert_eq!(to_te_form("食べる"), "食べて");
-        assert_eq!(to_te_form("見る"), "見て");
+        assert_eq!(to_te_form("食べる").unwrap(), "食べて");
+        assert_eq!(to_te_form("見る").unwrap(), "見て");
     }
 
     #[test]
     fn test_te_form_irregular() {
-        assert_eq!(to_te_form("する"), "して");
-        assert_eq!(to_te_form("くる"), "きて");
-        assert_eq!(to_te_form("来る"), "きて");
+        assert_eq!(to_te_form("する").unwrap(), "して");
+        assert_eq!(to_te_form("くる").unwrap(), "きて");
+        assert_eq!(to_te_form("来る").unwrap(), "来て");
     }
 
     #[test]
     fn test_nai_form_group2() {
-        assert_eq!(to_nai_form("行く"), "行かない");
-        assert_eq!(to_nai_form("話す"), "話さない");
-        assert_eq!(to_nai_form("読む"), "読まない");
+        assert_eq!(to_nai_form("行く").unwrap(), "行かない");
+        assert_eq!(to_nai_form("話す").unwrap(), "話さない");
+        assert_eq!(to_nai_form("読む").unwrap(), "読まない");
     }
 
     #[test]
     fn test_nai_form_group1() {
-        assert_eq!(to_nai_form("食べる"), "食べない");
-        assert_eq!(to_nai_form("見る"), "見ない");
+        assert_eq!(to_nai_form("食べる").unwrap(), "食べない");
+        assert_eq!(to_nai_form("見る").unwrap(), "見ない");
     }
 
     #[test]
     fn test_nai_form_irregular() {
-        assert_eq!(to_nai_form("する"), "しない");
-        assert_eq!(to_nai_form("くる"), "こない");
+        assert_eq!(to_nai_form("する").unwrap(), "しない");
+        assert_eq!(to_nai_form("くる").unwrap(), "こない");
+        assert_eq!(to_nai_form("来る").unwrap(), "来ない");
+        assert_eq!(to_nai_form("ある").unwrap(), "ない");
+        assert_eq!(to_nai_form("勉強する").unwrap(), "勉強しない");
+    }
+
+    #[test]
+    fn test_godan_exceptions_ending_in_iru_eru() {
+        assert_eq!(to_nai_form("帰る").unwrap(), "帰らない");
+        assert_eq!(to_te_form("入る").unwrap(), "入って");
+        assert_eq!(to_masu_form("走る").unwrap(), "走ります");
+        assert_eq!(to_ta_form("知る").unwrap(), "知った");
     }
 
     #[test]
     fn test_ta_form() {
-        assert_eq!(to_ta_form("行く"), "行った");
-        assert_eq!(to_ta_form("食べる"), "食べた");
-        assert_eq!(to_ta_form("する"), "した");
+        assert_eq!(to_ta_form("行く").unwrap(), "行った");
+        assert_eq!(to_ta_form("食べる").unwrap(), "食べた");
+        assert_eq!(to_ta_form("する").unwrap(), "した");
+        assert_eq!(to_ta_form("捨てる").unwrap(), "捨てた");
+        assert_eq!(to_ta_form("読む").unwrap(), "読んだ");
     }
 
     #[test]
     fn test_masu_form() {
-        assert_eq!(to_masu_form("行く"), "行くます");
-        assert_eq!(to_masu_form("食べる"), "食べます");
+        assert_eq!(to_masu_form("行く").unwrap(), "行きます");
+        assert_eq!(to_masu_form("食べる").unwrap(), "食べます");
+        assert_eq!(to_masu_form("くださる").unwrap(), "くださいます");
     }
 
     #[test]
     fn test_masen_form() {
-        assert_eq!(to_masen_form("行く"), "行きません");
-        assert_eq!(to_masen_form("食べる"), "食べません");
+        assert_eq!(to_masen_form("行く").unwrap(), "行きません");
+        assert_eq!(to_masen_form("食べる").unwrap(), "食べません");
     }
 
     #[test]
     fn test_mashou_form() {
-        assert_eq!(to_mashou_form("行く"), "行きましょう");
-        assert_eq!(to_mashou_form("食べる"), "食べましょう");
+        assert_eq!(to_mashou_form("行く").unwrap(), "行きましょう");
+        assert_eq!(to_mashou_form("食べる").unwrap(), "食べましょう");
+    }
+
+    #[test]
+    fn test_not_a_verb_is_rejected() {
+        assert!(VerbClass::of("本").is_err());
+    }
+
+    #[test]
+    fn test_verb_classes_regression_table() {
+        let failures = VERBS
+            .iter()
+            .filter_map(|(verb, class, te_form)| {
+                let actual_class = VerbClass::of(verb).ok();
+                let actual_te_form = to_te_form(verb).ok();
+
+                (actual_class != Some(*class) || actual_te_form.as_deref() != Some(*te_form))
+                    .then(|| format!("{verb}: {actual_class:?} {actual_te_form:?}"))
+            })
+            .collect::<Vec<_>>();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
     }
 }
@@ -1,62 +0,0 @@
-use serde::{Deserialize, Serialize};
-
-use crate::domain::{
-    KeikakuError,
-    grammar::{GrammarRule, verb_forms::to_nai_form},
-    tokenizer::PartOfSpeech,
-    value_objects::{JapaneseLevel, NativeLanguage},
-};
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct VerbNaideKudasaiRule;
-
-impl GrammarRule for VerbNaideKudasaiRule {
-    fn apply_to(&self) -> Vec<PartOfSpeech> {
-        vec![PartOfSpeech::Verb]
-    }
-
-    fn format(&self, word: &str, part_of_speech: &PartOfSpeech) -> Result<String, KeikakuError> {
-        match part_of_speech {
-            PartOfSpeech::Verb => Ok(format!("{}でください", to_nai_form(word))),
-            _ => Err(KeikakuError::GrammarFormatError {
-                reason: "Not supported part of speech".to_string(),
-            }),
-        }
-    }
-
-    fn title(&self, lang: &NativeLanguage) -> String {
-        match lang {
-            NativeLanguage::Russian => "Форма ないでください",
-            NativeLanguage::English => "Form ないでください",
-        }
-        .to_string()
-    }
-
-    fn md_description(&self, lang: &NativeLanguage) -> String {
-        match lang {
-            NativeLanguage::Russian => {
-                r#"# Форма ないでください
-
-Форма просьбы не делать ("Не делайте, пожалуйста").
-
-## Примеры
-- 忘れないでください (Не забудьте)
-- 話さないでください (Не говорите)"#
-            }
-            NativeLanguage::English => {
-                r#"# Form ないでください
-
-Form for request not to do ("Please don't do").
-
-## Examples
-- 忘れないでください (Please don't forget)
-- 話さないでください (Please don't speak)"#
-            }
-        }
-        .to_string()
-    }
-
-    fn level(&self) -> JapaneseLevel {
-        JapaneseLevel::N5
-    }
-}
@@ -1,8 +1,10 @@
+use std::collections::HashSet;
 use std::sync::LazyLock;
 
 use crate::domain::{
     KeikakuError,
-    furiganizer::{FuriganaFormat, Furiganizer},
+    furiganizer::{FuriganaFormat, FuriganaSpan, Furiganizer},
+    transliteration::katakana_to_hiragana,
 };
 
 pub trait IsJapanese {
@@ -19,7 +21,21 @@ pub trait IsJapaneseText {
 
     fn has_furigana(&self) -> Result<bool, KeikakuError>;
     fn as_furigana(&self) -> Result<String, KeikakuError>;
+    /// Текст, разбитый на группы кандзи с чтениями и остальной текст
+    fn furigana_spans(&self) -> Result<Vec<FuriganaSpan>, KeikakuError>;
+    /// Как `furigana_spans`, но чтения остаются только у групп с незнакомыми кандзи
+    fn unknown_furigana_spans(
+        &self,
+        known_kanji: &HashSet<char>,
+    ) -> Result<Vec<FuriganaSpan>, KeikakuError>;
+    /// Сравнивает тексты по произношению, поэтому 食べる и たべる совпадают
     fn equals_by_reading(&self, other: &Self) -> Result<bool, KeikakuError>;
+
+    /// Переводит катакану в хирагану. Знак долготы «ー» заменяется гласной
+    /// предыдущего слога (после «о» и «э» — «う» и «い», как в словарных чтениях).
+    /// Для чтений из токенизатора лучше `kana_reading`: по произношению нельзя
+    /// отличить おお и ええ (大きい, お姉さん) от おう и えい
+    fn to_hiragana(&self) -> String;
 }
 
 impl IsJapanese for char {
@@ -58,19 +74,99 @@ impl IsJapaneseText for str {
         FURIGANIZER.furiganize(self)
     }
 
+    fn furigana_spans(&self) -> Result<Vec<FuriganaSpan>, KeikakuError> {
+        FURIGANIZER.spans(self)
+    }
+
+    fn unknown_furigana_spans(
+        &self,
+        known_kanji: &HashSet<char>,
+    ) -> Result<Vec<FuriganaSpan>, KeikakuError> {
+        FURIGANIZER.unknown_spans(self, |kanji| known_kanji.contains(&kanji))
+    }
+
     fn has_furigana(&self) -> Result<bool, KeikakuError> {
-        self.as_furigana().map(|furigana| furigana != self)
+        self.furigana_spans()
+            .map(|spans| spans.iter().any(|span| span.reading().is_some()))
     }
 
     fn equals_by_reading(&self, other: &Self) -> Result<bool, KeikakuError> {
-        let left = self.as_furigana()?;
-        let right = other.as_furigana()?;
+        let left = FURIGANIZER.reading(self)?;
+        let right = FURIGANIZER.reading(other)?;
         Ok(left == right)
     }
 
     fn contains_kanji(&self) -> bool {
         self.chars().any(|c| c.is_kanji())
     }
+
+    fn to_hiragana(&self) -> String {
+        let mut result = String::with_capacity(self.len());
+
+        for c in katakana_to_hiragana(self).chars() {
+            let converted = match c {
+                'ー' => result
+                    .chars()
+                    .last()
+                    .and_then(|previous| long_vowels(previous).first().copied())
+                    .unwrap_or(c),
+                _ => c,
+            };
+            result.push(converted);
+        }
+
+        result
+    }
+}
+
+/// Чтение хираганой по произношению UniDic и чтению лексемы (lForm).
+///
+/// В произношении долгота записана знаком «ー» (`オーキー`), а в чтении лексемы — буквами
+/// (`オオキイ`). Пока произношение совпадает с чтением лексемы, долгота берется из него,
+/// поэтому 大きい читается おおきい, а 東京 — とうきょう. Дальше первого расхождения
+/// (окончание спрягаемой формы, другое чтение лексемы) действует правило `to_hiragana`
+pub(crate) fn kana_reading(pronunciation: &str, lemma_reading: &str) -> String {
+    let spelling = katakana_to_hiragana(lemma_reading)
+        .chars()
+        .collect::<Vec<_>>();
+    let mut result = String::with_capacity(pronunciation.len());
+    let mut agrees = true;
+
+    for (index, c) in katakana_to_hiragana(pronunciation).chars().enumerate() {
+        let written = spelling.get(index).copied();
+        let converted = match c {
+            'ー' => {
+                let vowels = result.chars().last().map(long_vowels).unwrap_or_default();
+                written
+                    .filter(|vowel| agrees && vowels.contains(vowel))
+                    .or_else(|| vowels.first().copied())
+                    .unwrap_or(c)
+            }
+            _ => c,
+        };
+
+        agrees = agrees && written == Some(converted);
+        result.push(converted);
+    }
+
+    result
+}
+
+/// Гласные, которыми на письме продлевается слог. Первая — чтение по умолчанию
+fn long_vowels(previous: char) -> &'static [char] {
+    match previous {
+        'あ' | 'ぁ' | 'か' | 'が' | 'さ' | 'ざ' | 'た' | 'だ' | 'な' | 'は' | 'ば' | 'ぱ'
+        | 'ま' | 'や' | 'ゃ' | 'ら' | 'わ' => &['あ'],
+        'い' | 'ぃ' | 'き' | 'ぎ' | 'し' | 'じ' | 'ち' | 'ぢ' | 'に' | 'ひ' | 'び' | 'ぴ'
+        | 'み' | 'り' => &['い'],
+        'う' | 'ぅ' | 'く' | 'ぐ' | 'す' | 'ず' | 'つ' | 'づ' | 'ぬ' | 'ふ' | 'ぶ' | 'ぷ'
+        | 'む' | 'ゆ' | 'ゅ' | 'る' => &['う'],
+        'え' | 'ぇ' | 'け' | 'げ' | 'せ' | 'ぜ' | 'て' | 'で' | 'ね' | 'へ' | 'べ' | 'ぺ'
+        | 'め' | 'れ' => &['い', 'え'],
+        'お' | 'ぉ' | 'こ' | 'ご' | 'そ' | 'ぞ' | 'と' | 'ど' | 'の' | 'ほ' | 'ぼ' | 'ぽ'
+        | 'も' | 'よ' | 'ょ' | 'ろ' | 'を' => &['う', 'お'],
+        _ => &[],
+    }
 }
 
 #[cfg(test)]
@@ -148,4 +244,76 @@ mod tests {
         // Assert
         assert!(has_furigana);
     }
+
+    #[test]
+    fn kana_and_kanji_spellings_are_equal_by_reading() {
+        // Arrange
+        let pairs = [("食べる", "たべる"), ("一緒", "いっしょ")];
+
+        // Act + Assert
+        for (kanji, kana) in pairs {
+            assert!(kanji.equals_by_reading(kana).unwrap());
+        }
+        assert!(!"食べる".equals_by_reading("飲む").unwrap());
+    }
+
+    #[test]
+    fn katakana_reading_converts_to_hiragana() {
+        // Arrange
+        let readings = [
+            ("タベル", "たべる"),
+            ("オイシー", "おいしい"),
+            ("ベンキョー", "べんきょう"),
+            ("センセー", "せんせい"),
+            ("ラーメン", "らあめん"),
+            ("ひらがな", "ひらがな"),
+        ];
+
+        // Act + Assert
+        for (katakana, hiragana) in readings {
+            assert_eq!(katakana.to_hiragana(), hiragana);
+        }
+    }
+
+    #[test]
+    fn kana_reading_spells_long_vowels_like_lemma() {
+        // Arrange
+        let readings = [
+            ("オーキー", "オオキイ", "おおきい"),
+            ("トーイ", "トオイ", "とおい"),
+            ("トール", "トオル", "とおる"),
+            ("ネー", "ネエ", "ねえ"),
+            ("トーキョー", "トウキョウ", "とうきょう"),
+            ("センセー", "センセイ", "せんせい"),
+        ];
+
+        // Act + Assert
+        for (pronunciation, lemma_reading, hiragana) in readings {
+            assert_eq!(kana_reading(pronunciation, lemma_reading), hiragana);
+        }
+    }
+
+    #[test]
+    fn kana_reading_keeps_lemma_spelling_in_conjugated_form() {
+        // Arrange
+        let pronunciation = "トーッ";
+
+        // Act
+        let reading = kana_reading(pronunciation, "トオル");
+
+        // Assert
+        assert_eq!(reading, "とおっ");
+    }
+
+    #[test]
+    fn kana_reading_falls_back_without_lemma_reading() {
+        // Arrange
+        let pronunciation = "オーキー";
+
+        // Act
+        let reading = kana_reading(pronunciation, "");
+
+        // Assert
+        assert_eq!(reading, "おうきい");
+    }
 }
@@ -0,0 +1,292 @@
+use std::collections::{BTreeMap, HashSet};
+
+use rand::seq::IndexedRandom;
+use serde::{Deserialize, Serialize};
+
+use super::{Card, KnowledgeSet};
+use crate::domain::{
+    KeikakuError,
+    grammar::GrammarRule,
+    tokenizer::{PartOfSpeech, Tokenizer},
+    value_objects::NativeLanguage,
+};
+
+/// Части речи, которые спрягаются в упражнениях
+const CONJUGATED_PARTS_OF_SPEECH: [PartOfSpeech; 3] = [
+    PartOfSpeech::Verb,
+    PartOfSpeech::IAdjective,
+    PartOfSpeech::NaAdjective,
+];
+
+/// Ответы пользователя по одному правилу грамматики
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConjugationStats {
+    attempts: u32,
+    correct: u32,
+}
+
+impl ConjugationStats {
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn correct(&self) -> u32 {
+        self.correct
+    }
+
+    /// Доля правильных ответов. `None`, если правило еще не тренировалось
+    pub fn accuracy(&self) -> Option<f64> {
+        (self.attempts > 0).then(|| self.correct as f64 / self.attempts as f64)
+    }
+
+    /// Вес правила при выборе упражнений: сглаженная доля ошибок.
+    /// У нового правила вес 0.5, у правила без ошибок он стремится к нулю
+    pub fn weight(&self) -> f64 {
+        (self.attempts - self.correct + 1) as f64 / (self.attempts + 2) as f64
+    }
+
+    fn record(&mut self, is_correct: bool) {
+        self.attempts += 1;
+        self.correct += u32::from(is_correct);
+    }
+}
+
+/// Упражнение: поставить слово в словарной форме в форму правила
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConjugationExercise {
+    word: String,
+    part_of_speech: PartOfSpeech,
+    rule_id: String,
+    rule_title: String,
+    expected: Vec<String>,
+}
+
+impl ConjugationExercise {
+    /// Слово в словарной форме
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn part_of_speech(&self) -> &PartOfSpeech {
+        &self.part_of_speech
+    }
+
+    pub fn rule_id(&self) -> &str {
+        &self.rule_id
+    }
+
+    pub fn rule_title(&self) -> &str {
+        &self.rule_title
+    }
+
+    /// Правильные ответы: все варианты конструкции из `GrammarRule::variants`
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+}
+
+impl KnowledgeSet {
+    /// Статистика упражнений по идентификаторам правил
+    pub fn conjugation_stats(&self) -> &BTreeMap<String, ConjugationStats> {
+        &self.conjugation_stats
+    }
+
+    /// Упражнения на спряжение слов, изучение которых уже началось.
+    /// Правила со слабой статистикой выбираются чаще
+    pub fn conjugation_exercises(
+        &self,
+        tokenizer: &Tokenizer,
+        rules: &[&dyn GrammarRule],
+        lang: &NativeLanguage,
+        count: usize,
+    ) -> Result<Vec<ConjugationExercise>, KeikakuError> {
+        let words = self.conjugated_words(tokenizer)?;
+        let candidates = rules
+            .iter()
+            .map(|rule| {
+                let apply_to = rule.apply_to();
+                let words = words
+                    .iter()
+                    .filter(|(_, part_of_speech)| apply_to.contains(part_of_speech))
+                    .collect::<Vec<_>>();
+                (*rule, words)
+            })
+            .filter(|(_, words)| !words.is_empty())
+            .collect::<Vec<_>>();
+
+        let mut rng = rand::rng();
+        let mut used = HashSet::new();
+        let mut exercises = Vec::new();
+
+        for _ in 0..count * 3 {
+            if exercises.len() >= count {
+                break;
+            }
+
+            let Ok((rule, words)) = candidates.choose_weighted(&mut rng, |(rule, _)| {
+                self.conjugation_stats
+                    .get(rule.id())
+                    .copied()
+                    .unwrap_or_default()
+                    .weight()
+            }) else {
+                break;
+            };
+            let Some((word, part_of_speech)) = words.choose(&mut rng) else {
+                continue;
+            };
+            if !used.insert((rule.id(), word.as_str())) {
+                continue;
+            }
+
+            let Ok(expected) = rule.variants(word, part_of_speech) else {
+                continue;
+            };
+
+            exercises.push(ConjugationExercise {
+                word: word.clone(),
+                part_of_speech: part_of_speech.clone(),
+                rule_id: rule.id().to_string(),
+                rule_title: rule.title(lang),
+                expected,
+            });
+        }
+
+        Ok(exercises)
+    }
+
+    pub(crate) fn record_conjugation_answer(&mut self, rule_id: &str, is_correct: bool) {
+        self.conjugation_stats
+            .entry(rule_id.to_string())
+            .or_default()
+            .record(is_correct);
+    }
+
+    /// Глаголы и прилагательные из начатых карточек слов в словарной форме
+    fn conjugated_words(
+        &self,
+        tokenizer: &Tokenizer,
+    ) -> Result<Vec<(String, PartOfSpeech)>, KeikakuError> {
+        let mut seen = HashSet::new();
+        let mut words = Vec::new();
+
+        for study_card in self.study_cards.values() {
+            let Card::Vocabulary(vocabulary) = study_card.card() else {
+                continue;
+            };
+            if study_card.memory().is_new() {
+                continue;
+            }
+
+            let tokens = tokenizer.tokenize(vocabulary.word().text().trim())?;
+            let [token] = tokens.as_slice() else {
+                continue;
+            };
+            if !CONJUGATED_PARTS_OF_SPEECH.contains(token.part_of_speech()) {
+                continue;
+            }
+
+            let base_form = token.orthographic_base_form().to_string();
+            if seen.insert(base_form.clone()) {
+                words.push((base_form, token.part_of_speech().clone()));
+            }
+        }
+
+        words.sort_by(|(left, _), (right, _)| left.cmp(right));
+        Ok(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    use crate::domain::grammar::find_grammar_rule;
+    use crate::domain::knowledge::test_support::{studied_memory, vocabulary};
+
+    #[test]
+    fn weak_rules_have_higher_weight() {
+        // Arrange
+        let mut weak = ConjugationStats::default();
+        let mut strong = ConjugationStats::default();
+        for _ in 0..3 {
+            weak.record(false);
+            strong.record(true);
+        }
+
+        // Act
+        let weak_weight = weak.weight();
+        let strong_weight = strong.weight();
+
+        // Assert
+        assert!(weak_weight > ConjugationStats::default().weight());
+        assert!(strong_weight < ConjugationStats::default().weight());
+        assert_eq!(strong.accuracy(), Some(1.0));
+    }
+
+    #[test]
+    fn new_cards_are_not_drilled() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+        let mut knowledge_set = KnowledgeSet::new();
+        knowledge_set.create_card(vocabulary("食べる")).unwrap();
+        let tai = find_grammar_rule("verb_tai").unwrap();
+
+        // Act
+        let exercises = knowledge_set
+            .conjugation_exercises(&tokenizer, &[tai.as_ref()], &NativeLanguage::Russian, 5)
+            .unwrap();
+
+        // Assert
+        assert!(exercises.is_empty());
+    }
+
+    #[test]
+    fn exercise_accepts_every_template_of_rule() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+        let mut knowledge_set = KnowledgeSet::new();
+        let card = knowledge_set.create_card(vocabulary("食べる")).unwrap();
+        knowledge_set
+            .mark_card_as_known(*card.card_id(), studied_memory(), &BTreeSet::new())
+            .unwrap();
+        let te_iru = find_grammar_rule("verb_te_iru").unwrap();
+
+        // Act
+        let exercises = knowledge_set
+            .conjugation_exercises(&tokenizer, &[te_iru.as_ref()], &NativeLanguage::Russian, 5)
+            .unwrap();
+
+        // Assert
+        assert_eq!(exercises.len(), 1);
+        assert_eq!(exercises[0].expected(), ["食べている", "食べています"]);
+    }
+
+    #[test]
+    fn exercise_expects_rule_format() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+        let mut knowledge_set = KnowledgeSet::new();
+        let card = knowledge_set.create_card(vocabulary("食べる")).unwrap();
+        knowledge_set.create_card(vocabulary("本")).unwrap();
+        knowledge_set
+            .mark_card_as_known(*card.card_id(), studied_memory(), &BTreeSet::new())
+            .unwrap();
+        let tai = find_grammar_rule("verb_tai").unwrap();
+
+        // Act
+        let exercises = knowledge_set
+            .conjugation_exercises(&tokenizer, &[tai.as_ref()], &NativeLanguage::Russian, 5)
+            .unwrap();
+
+        // Assert
+        assert_eq!(exercises.len(), 1);
+        assert_eq!(exercises[0].word(), "食べる");
+        assert_eq!(exercises[0].rule_id(), "verb_tai");
+        assert_eq!(
+            exercises[0].expected(),
+            [tai.format("食べる", &PartOfSpeech::Verb).unwrap()]
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+
+use super::{Card, KnowledgeSet, StudyCard};
+use crate::domain::{KeikakuError, japanese::IsJapaneseText, tokenizer::Tokenizer};
+
+/// Нормализованное слово: словарная форма и чтение хираганой.
+/// По нему 食べる и たべる, 一緒 и いっしょ считаются одним словом
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Lexeme {
+    base_form: String,
+    reading: String,
+}
+
+impl Lexeme {
+    /// Лексема по словарным формам токенов. `None`, если в тексте нет словарных слов
+    pub fn parse(tokenizer: &Tokenizer, text: &str) -> Result<Option<Self>, KeikakuError> {
+        let tokens = tokenizer.tokenize(text.trim())?;
+        let words = tokens
+            .iter()
+            .filter(|token| token.part_of_speech().is_vocabulary_word())
+            .collect::<Vec<_>>();
+
+        if words.is_empty() {
+            return Ok(None);
+        }
+
+        let base_form = words
+            .iter()
+            .map(|token| token.orthographic_base_form())
+            .collect::<String>();
+        let reading = words
+            .iter()
+            .map(|token| token.kana_base_form())
+            .collect::<String>();
+
+        Ok(Some(Self { base_form, reading }))
+    }
+
+    pub fn base_form(&self) -> &str {
+        &self.base_form
+    }
+
+    pub fn reading(&self) -> &str {
+        &self.reading
+    }
+}
+
+/// Карточки слов колоды по лексемам. Колода разбирается токенизатором один раз,
+/// поэтому при импорте много слов проверяются без повторного разбора всех карточек
+#[derive(Debug, Clone)]
+pub struct LexemeIndex<'a> {
+    by_lexeme: HashMap<Lexeme, Vec<&'a StudyCard>>,
+    by_question: HashMap<&'a str, Vec<&'a StudyCard>>,
+    by_reading: HashMap<String, Vec<&'a StudyCard>>,
+}
+
+impl<'a> LexemeIndex<'a> {
+    /// Карточки слов, которые совпадают с текстом по написанию или по словарной форме и чтению.
+    /// Текст, записанный только каной, сравнивается с карточками еще и по произношению:
+    /// словарная форма у каны может отличаться от формы, записанной кандзи
+    pub fn find_likely_duplicates(
+        &self,
+        tokenizer: &Tokenizer,
+        text: &str,
+    ) -> Result<Vec<&'a StudyCard>, KeikakuError> {
+        let text = text.trim();
+        let lexeme = Lexeme::parse(tokenizer, text)?;
+        let by_question = self.by_question.get(text).into_iter().flatten();
+        let by_lexeme = lexeme
+            .as_ref()
+            .and_then(|lexeme| self.by_lexeme.get(lexeme))
+            .into_iter()
+            .flatten();
+
+        let mut duplicates = by_question.chain(by_lexeme).copied().collect::<Vec<_>>();
+
+        if let Some(lexeme) = lexeme.filter(|_| !text.contains_kanji()) {
+            for study_card in self.by_reading.get(lexeme.reading()).into_iter().flatten() {
+                if study_card
+                    .card()
+                    .question()
+                    .text()
+                    .equals_by_reading(text)?
+                {
+                    duplicates.push(*study_card);
+                }
+            }
+        }
+
+        duplicates.sort_by_key(|study_card| *study_card.card_id());
+        duplicates.dedup_by_key(|study_card| *study_card.card_id());
+
+        Ok(duplicates)
+    }
+
+    /// Группы карточек с одной лексемой. Внутри группы карточки идут в порядке создания
+    pub fn duplicate_groups(&self) -> Vec<Vec<&'a StudyCard>> {
+        let mut groups = self
+            .by_lexeme
+            .values()
+            .filter(|group| group.len() > 1)
+            .map(|group| {
+                let mut group = group.clone();
+                group.sort_by_key(|study_card| *study_card.card_id());
+                group
+            })
+            .collect::<Vec<_>>();
+        groups.sort_by_key(|group| *group[0].card_id());
+
+        groups
+    }
+}
+
+impl KnowledgeSet {
+    /// Индекс карточек слов по лексемам для многократного поиска дубликатов
+    pub fn lexeme_index(&self, tokenizer: &Tokenizer) -> Result<LexemeIndex<'_>, KeikakuError> {
+        let mut by_lexeme = HashMap::<Lexeme, Vec<&StudyCard>>::new();
+        let mut by_question = HashMap::<&str, Vec<&StudyCard>>::new();
+        let mut by_reading = HashMap::<String, Vec<&StudyCard>>::new();
+
+        for study_card in self.vocabulary_cards() {
+            let question = study_card.card().question().text();
+            by_question.entry(question).or_default().push(study_card);
+            if let Some(lexeme) = Lexeme::parse(tokenizer, question)? {
+                by_reading
+                    .entry(lexeme.reading().to_string())
+                    .or_default()
+                    .push(study_card);
+                by_lexeme.entry(lexeme).or_default().push(study_card);
+            }
+        }
+
+        Ok(LexemeIndex {
+            by_lexeme,
+            by_question,
+            by_reading,
+        })
+    }
+
+    /// Карточки слов, которые совпадают с текстом по словарной форме и чтению.
+    /// Для проверки многих слов подряд лучше один раз построить `lexeme_index`
+    pub fn find_likely_duplicates(
+        &self,
+        tokenizer: &Tokenizer,
+        text: &str,
+    ) -> Result<Vec<&StudyCard>, KeikakuError> {
+        self.lexeme_index(tokenizer)?
+            .find_likely_duplicates(tokenizer, text)
+    }
+
+    /// Группы карточек слов, которые скорее всего записывают одно и то же слово.
+    /// Внутри группы карточки идут в порядке создания
+    pub fn find_duplicate_groups(
+        &self,
+        tokenizer: &Tokenizer,
+    ) -> Result<Vec<Vec<&StudyCard>>, KeikakuError> {
+        Ok(self.lexeme_index(tokenizer)?.duplicate_groups())
+    }
+
+    fn vocabulary_cards(&self) -> impl Iterator<Item = &StudyCard> {
+        self.study_cards
+            .values()
+            .filter(|study_card| matches!(study_card.card(), Card::Vocabulary(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    use crate::domain::knowledge::test_support::vocabulary;
+
+    #[test]
+    fn kana_spelling_has_the_same_lexeme_as_kanji_spelling() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+
+        // Act
+        let kanji = Lexeme::parse(&tokenizer, "食べる").unwrap();
+        let kana = Lexeme::parse(&tokenizer, "たべる").unwrap();
+
+        // Assert
+        assert_eq!(kanji, kana);
+        assert_eq!(kanji.unwrap().reading(), "たべる");
+    }
+
+    #[test]
+    fn duplicate_groups_join_spellings_of_one_word() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+        let mut knowledge_set = KnowledgeSet::new();
+        let kanji = knowledge_set.create_card(vocabulary("一緒")).unwrap();
+        let kana = knowledge_set.create_card(vocabulary("いっしょ")).unwrap();
+        knowledge_set.create_card(vocabulary("飲む")).unwrap();
+
+        // Act
+        let groups = knowledge_set.find_duplicate_groups(&tokenizer).unwrap();
+
+        // Assert
+        assert_eq!(groups.len(), 1);
+        let ids = groups[0]
+            .iter()
+            .map(|study_card| *study_card.card_id())
+            .collect::<HashSet<_>>();
+        assert_eq!(ids, HashSet::from([*kanji.card_id(), *kana.card_id()]));
+    }
+
+    #[test]
+    fn lexeme_index_answers_many_queries() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+        let mut knowledge_set = KnowledgeSet::new();
+        let taberu = knowledge_set.create_card(vocabulary("食べる")).unwrap();
+        let nomu = knowledge_set.create_card(vocabulary("飲む")).unwrap();
+        let index = knowledge_set.lexeme_index(&tokenizer).unwrap();
+
+        // Act
+        let kana = index.find_likely_duplicates(&tokenizer, "たべる").unwrap();
+        let same = index.find_likely_duplicates(&tokenizer, "飲む").unwrap();
+        let new = index.find_likely_duplicates(&tokenizer, "読む").unwrap();
+
+        // Assert
+        assert_eq!(kana.len(), 1);
+        assert_eq!(kana[0].card_id(), taberu.card_id());
+        assert_eq!(same.len(), 1);
+        assert_eq!(same[0].card_id(), nomu.card_id());
+        assert!(new.is_empty());
+    }
+
+    #[test]
+    fn kana_text_is_matched_by_pronunciation() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+        let mut knowledge_set = KnowledgeSet::new();
+        let together = knowledge_set.create_card(vocabulary("一緒")).unwrap();
+        let index = knowledge_set.lexeme_index(&tokenizer).unwrap();
+
+        // Act
+        let kana = index
+            .find_likely_duplicates(&tokenizer, "いっしょ")
+            .unwrap();
+        let other = index
+            .find_likely_duplicates(&tokenizer, "いっしょう")
+            .unwrap();
+
+        // Assert
+        assert_eq!(kana.len(), 1);
+        assert_eq!(kana[0].card_id(), together.card_id());
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn likely_duplicates_are_found_for_new_text() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+        let mut knowledge_set = KnowledgeSet::new();
+        let existing = knowledge_set.create_card(vocabulary("食べる")).unwrap();
+
+        // Act
+        let duplicates = knowledge_set
+            .find_likely_duplicates(&tokenizer, "たべる")
+            .unwrap();
+
+        // Assert
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].card_id(), existing.card_id());
+    }
+}
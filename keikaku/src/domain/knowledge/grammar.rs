@@ -1,3 +1,4 @@
+use super::{Card, KnowledgeSet};
 use crate::domain::{
     KeikakuError,
     grammar::GrammarRule,
@@ -19,6 +20,14 @@ impl GrammarRuleCard {
         })
     }
 
+    /// Копия карты с измененным описанием правила
+    pub fn with_description(&self, description: Answer) -> Self {
+        Self {
+            title: self.title.clone(),
+            description,
+        }
+    }
+
     pub fn title(&self) -> &Question {
         &self.title
     }
@@ -27,3 +36,41 @@ impl GrammarRuleCard {
         &self.description
     }
 }
+
+impl KnowledgeSet {
+    /// Есть ли у пользователя карточка правила. Карточка хранит заголовок на языке пользователя
+    pub fn has_grammar_card(&self, rule: &dyn GrammarRule, lang: &NativeLanguage) -> bool {
+        let title = rule.title(lang);
+
+        self.study_cards
+            .values()
+            .any(|study_card| match study_card.card() {
+                Card::Grammar(grammar) => grammar.title().text() == title,
+                _ => false,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::grammar::find_grammar_rule;
+
+    #[test]
+    fn grammar_card_is_found_by_rule() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let tai = find_grammar_rule("verb_tai").unwrap();
+        let tari = find_grammar_rule("verb_tari").unwrap();
+        let card = GrammarRuleCard::new(tai, &NativeLanguage::Russian).unwrap();
+        knowledge_set.create_card(Card::Grammar(card)).unwrap();
+
+        // Act
+        let has_tai = knowledge_set.has_grammar_card(tai.as_ref(), &NativeLanguage::Russian);
+        let has_tari = knowledge_set.has_grammar_card(tari.as_ref(), &NativeLanguage::Russian);
+
+        // Assert
+        assert!(has_tai);
+        assert!(!has_tari);
+    }
+}
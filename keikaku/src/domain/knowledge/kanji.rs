@@ -48,6 +48,15 @@ impl KanjiCard {
         })
     }
 
+    /// Копия карты с измененным описанием и примерами слов
+    pub fn with_content(&self, description: Answer, example_words: Vec<ExampleKanjiWord>) -> Self {
+        Self {
+            kanji: self.kanji.clone(),
+            description,
+            example_words,
+        }
+    }
+
     pub fn kanji(&self) -> &Question {
         &self.kanji
     }
@@ -80,6 +89,10 @@ impl KanjiCard {
 }
 
 impl ExampleKanjiWord {
+    pub fn new(word: String, meaning: String) -> Self {
+        Self { word, meaning }
+    }
+
     pub fn word(&self) -> &str {
         &self.word
     }
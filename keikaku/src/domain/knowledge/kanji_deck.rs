@@ -0,0 +1,209 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+
+use super::{Card, KnowledgeSet};
+use crate::domain::{japanese::IsJapanese, value_objects::JapaneseLevel};
+
+/// В скольких выученных словах должен встретиться кандзи без своей карточки,
+/// чтобы считаться знакомым
+const KNOWN_KANJI_WORDS_THRESHOLD: usize = 3;
+
+/// Кандзи из слов пользователя, для которого еще нет карточки
+#[derive(Debug, Clone, PartialEq)]
+pub struct KanjiProposal {
+    kanji: char,
+    jlpt: JapaneseLevel,
+    words: Vec<String>,
+    known_words: usize,
+}
+
+impl KanjiProposal {
+    pub fn kanji(&self) -> char {
+        self.kanji
+    }
+
+    pub fn jlpt(&self) -> JapaneseLevel {
+        self.jlpt
+    }
+
+    /// Карточки слов, в которых встречается кандзи
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Сколько из этих слов уже выучено
+    pub fn known_words(&self) -> usize {
+        self.known_words
+    }
+}
+
+impl KnowledgeSet {
+    /// Кандзи из карточек слов не выше уровня пользователя, для которых нет карточек кандзи.
+    /// Первыми идут кандзи из уже выученных слов, затем из самых частых в словаре пользователя
+    pub fn propose_kanji_cards(&self, current_level: &JapaneseLevel) -> Vec<KanjiProposal> {
+        let existing = self
+            .study_cards
+            .values()
+            .filter_map(|study_card| match study_card.card() {
+                Card::Kanji(kanji) => kanji.kanji().text().chars().next(),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        let mut proposals = BTreeMap::<char, KanjiProposal>::new();
+        for study_card in self.study_cards.values() {
+            let Card::Vocabulary(vocabulary) = study_card.card() else {
+                continue;
+            };
+            let is_known = study_card.memory().is_known_card();
+
+            for kanji_info in vocabulary.get_kanji_cards(current_level) {
+                if existing.contains(&kanji_info.kanji()) {
+                    continue;
+                }
+
+                let proposal =
+                    proposals
+                        .entry(kanji_info.kanji())
+                        .or_insert_with(|| KanjiProposal {
+                            kanji: kanji_info.kanji(),
+                            jlpt: *kanji_info.jlpt(),
+                            words: Vec::new(),
+                            known_words: 0,
+                        });
+
+                let word = vocabulary.word().text();
+                if !proposal.words.iter().any(|known| known == word) {
+                    proposal.words.push(word.to_string());
+                    proposal.known_words += usize::from(is_known);
+                }
+            }
+        }
+
+        let mut proposals = proposals.into_values().collect::<Vec<_>>();
+        for proposal in &mut proposals {
+            proposal.words.sort();
+        }
+        proposals.sort_by_key(|proposal| {
+            (
+                Reverse(proposal.known_words),
+                Reverse(proposal.words.len()),
+                proposal.jlpt,
+            )
+        });
+
+        proposals
+    }
+
+    /// Кандзи, которые пользователь уже знает: выучена карточка кандзи или кандзи
+    /// встречается хотя бы в трех выученных словах
+    pub fn known_kanji(&self) -> HashSet<char> {
+        let mut known = HashSet::new();
+        let mut known_words = BTreeMap::<char, HashSet<&str>>::new();
+
+        for study_card in self.study_cards.values() {
+            if !study_card.memory().is_known_card() {
+                continue;
+            }
+
+            match study_card.card() {
+                Card::Kanji(kanji) => known.extend(kanji.kanji().text().chars().next()),
+                Card::Vocabulary(vocabulary) => {
+                    let word = vocabulary.word().text();
+                    for kanji in word.chars().filter(|ch| ch.is_kanji()) {
+                        known_words.entry(kanji).or_default().insert(word);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        known.extend(
+            known_words
+                .into_iter()
+                .filter(|(_, words)| words.len() >= KNOWN_KANJI_WORDS_THRESHOLD)
+                .map(|(kanji, _)| kanji),
+        );
+
+        known
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::knowledge::KanjiCard;
+    use crate::domain::knowledge::test_support::{add_known, vocabulary};
+    use crate::domain::value_objects::NativeLanguage;
+
+    #[test]
+    fn kanji_with_existing_cards_are_not_proposed() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        knowledge_set.create_card(vocabulary("日本")).unwrap();
+        let kanji = KanjiCard::new("日".to_string(), &NativeLanguage::Russian).unwrap();
+        knowledge_set.create_card(Card::Kanji(kanji)).unwrap();
+
+        // Act
+        let proposals = knowledge_set.propose_kanji_cards(&JapaneseLevel::N1);
+
+        // Assert
+        let kanji = proposals
+            .iter()
+            .map(|proposal| proposal.kanji())
+            .collect::<Vec<_>>();
+        assert_eq!(kanji, ['本']);
+        assert_eq!(proposals[0].words(), ["日本"]);
+    }
+
+    #[test]
+    fn kanji_above_user_level_are_skipped() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        knowledge_set.create_card(vocabulary("人")).unwrap();
+        knowledge_set.create_card(vocabulary("憂鬱")).unwrap();
+
+        // Act
+        let proposals = knowledge_set.propose_kanji_cards(&JapaneseLevel::N5);
+
+        // Assert
+        let kanji = proposals
+            .iter()
+            .map(|proposal| proposal.kanji())
+            .collect::<Vec<_>>();
+        assert_eq!(kanji, ['人']);
+    }
+
+    #[test]
+    fn learned_kanji_card_makes_kanji_known() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let kanji = KanjiCard::new("日".to_string(), &NativeLanguage::Russian).unwrap();
+        add_known(&mut knowledge_set, Card::Kanji(kanji));
+        let kanji = KanjiCard::new("本".to_string(), &NativeLanguage::Russian).unwrap();
+        knowledge_set.create_card(Card::Kanji(kanji)).unwrap();
+
+        // Act
+        let known = knowledge_set.known_kanji();
+
+        // Assert
+        assert_eq!(known, HashSet::from(['日']));
+    }
+
+    #[test]
+    fn kanji_from_enough_known_words_is_known() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        for word in ["日本", "毎日", "日曜日", "本屋"] {
+            add_known(&mut knowledge_set, vocabulary(word));
+        }
+        knowledge_set.create_card(vocabulary("本当")).unwrap();
+
+        // Act
+        let known = knowledge_set.known_kanji();
+
+        // Assert
+        assert!(known.contains(&'日'));
+        assert!(!known.contains(&'本'));
+    }
+}
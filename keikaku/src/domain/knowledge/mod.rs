@@ -1,21 +1,34 @@
+mod conjugation;
 mod daily_history;
+mod duplicates;
 mod grammar;
 mod kanji;
+mod kanji_deck;
+mod sentence;
+#[cfg(test)]
+mod test_support;
 mod vocabulary;
+mod vocabulary_deck;
 
+pub use conjugation::{ConjugationExercise, ConjugationStats};
 pub use daily_history::DailyHistoryItem;
+pub use duplicates::{Lexeme, LexemeIndex};
 pub use grammar::GrammarRuleCard;
 pub use kanji::{ExampleKanjiWord, KanjiCard};
+pub use kanji_deck::KanjiProposal;
+pub use sentence::{ClozeCandidate, ClozeDeletion, SentenceCard, propose_clozes};
 pub use vocabulary::VocabularyCard;
+pub use vocabulary_deck::VocabularyProposal;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::LazyLock;
 
 use crate::domain::{
     KeikakuError, Rating, Review,
     review::{MemoryHistory, MemoryState},
-    value_objects::{Answer, Question},
+    value_objects::{Answer, Question, ReviewDirection},
 };
-use chrono::{Duration, Utc};
+use chrono::{Duration, NaiveDate, Utc};
 use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
 use ulid::Ulid;
@@ -23,10 +36,15 @@ use ulid::Ulid;
 const NEW_CARDS_LIMIT: usize = 7;
 const HARD_CARDS_LIMIT: usize = 15;
 
+/// История направления, которое еще ни разу не повторялось
+static NEW_MEMORY: LazyLock<MemoryHistory> = LazyLock::new(MemoryHistory::default);
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KnowledgeSet {
     study_cards: HashMap<Ulid, StudyCard>,
     lesson_history: Vec<DailyHistoryItem>,
+    #[serde(default)]
+    conjugation_stats: BTreeMap<String, ConjugationStats>,
 }
 
 impl Default for KnowledgeSet {
@@ -40,6 +58,7 @@ impl KnowledgeSet {
         Self {
             study_cards: HashMap::new(),
             lesson_history: Vec::new(),
+            conjugation_stats: BTreeMap::new(),
         }
     }
 
@@ -59,6 +78,12 @@ impl KnowledgeSet {
         if self.study_cards.remove(&card_id).is_none() {
             return Err(KeikakuError::CardNotFound { card_id });
         }
+
+        for study_card in self.study_cards.values_mut() {
+            if let Card::Sentence(sentence) = &mut study_card.card {
+                sentence.unlink_vocabulary_card(card_id);
+            }
+        }
         Ok(())
     }
 
@@ -80,10 +105,45 @@ impl KnowledgeSet {
         Ok(study_card)
     }
 
+    /// Заменяет содержимое карты, сохраняя ее историю повторений
+    pub fn edit_card(&mut self, card_id: Ulid, card: Card) -> Result<StudyCard, KeikakuError> {
+        let current = self
+            .study_cards
+            .get(&card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+
+        if std::mem::discriminant(current.card()) != std::mem::discriminant(&card) {
+            return Err(KeikakuError::InvalidValues {
+                reason: "Card type cannot be changed".to_string(),
+            });
+        }
+
+        if current.card().question() != card.question() {
+            self.validate_unique_card_except(&card, Some(card_id))?;
+        }
+
+        let study_card = self
+            .study_cards
+            .get_mut(&card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+        study_card.card = card;
+
+        Ok(study_card.clone())
+    }
+
     fn validate_unique_card(&self, card: &Card) -> Result<(), KeikakuError> {
+        self.validate_unique_card_except(card, None)
+    }
+
+    fn validate_unique_card_except(
+        &self,
+        card: &Card,
+        except_card_id: Option<Ulid>,
+    ) -> Result<(), KeikakuError> {
         if self
             .study_cards
             .values()
+            .filter(|c| Some(*c.card_id()) != except_card_id)
             .any(|c| c.card().question() == card.question())
         {
             return Err(KeikakuError::DuplicateCard {
@@ -94,6 +154,67 @@ impl KnowledgeSet {
         Ok(())
     }
 
+    /// Сливает карту `source_id` в карту `target_id`: истории повторений, теги и заметки
+    /// объединяются, а `source_id` удаляется. Содержимое остается от `target_id`
+    pub(crate) fn merge_cards(
+        &mut self,
+        target_id: Ulid,
+        source_id: Ulid,
+    ) -> Result<StudyCard, KeikakuError> {
+        if target_id == source_id {
+            return Err(KeikakuError::InvalidValues {
+                reason: "Card cannot be merged into itself".to_string(),
+            });
+        }
+
+        let target = self
+            .study_cards
+            .get(&target_id)
+            .ok_or(KeikakuError::CardNotFound { card_id: target_id })?;
+        let source = self
+            .study_cards
+            .get(&source_id)
+            .ok_or(KeikakuError::CardNotFound { card_id: source_id })?;
+
+        if std::mem::discriminant(target.card()) != std::mem::discriminant(source.card()) {
+            return Err(KeikakuError::InvalidValues {
+                reason: "Only cards of the same type can be merged".to_string(),
+            });
+        }
+
+        let source = self
+            .study_cards
+            .remove(&source_id)
+            .ok_or(KeikakuError::CardNotFound { card_id: source_id })?;
+        let target = self
+            .study_cards
+            .get_mut(&target_id)
+            .ok_or(KeikakuError::CardNotFound { card_id: target_id })?;
+
+        target.memory_history.merge(source.memory_history);
+        for (direction, memory) in source.direction_histories {
+            target
+                .direction_histories
+                .entry(direction)
+                .or_default()
+                .merge(memory);
+        }
+        target.tags.extend(source.tags);
+        target.notes = match (target.notes.take(), source.notes) {
+            (Some(own), Some(other)) if own != other => Some(format!("{own}\n\n{other}")),
+            (own, other) => own.or(other),
+        };
+        let merged = target.clone();
+
+        for study_card in self.study_cards.values_mut() {
+            if let Card::Sentence(sentence) = &mut study_card.card {
+                sentence.relink_vocabulary_card(source_id, target_id);
+            }
+        }
+
+        Ok(merged)
+    }
+
     pub fn cards_to_fixation(&self) -> Vec<Card> {
         let mut cards = self
             .study_cards
@@ -111,71 +232,106 @@ impl KnowledgeSet {
         cards.iter().map(|(_, card)| card.card().clone()).collect()
     }
 
-    pub fn cards_to_lesson(&self) -> HashMap<Ulid, Card> {
-        let mut all_cards = self.study_cards.iter().collect::<Vec<_>>();
-        all_cards.sort_by_key(|(_, card)| card.memory().next_review_date());
+    /// Все теги, используемые в наборе
+    pub fn tags(&self) -> BTreeSet<String> {
+        self.study_cards
+            .values()
+            .flat_map(|card| card.tags().iter().cloned())
+            .collect()
+    }
 
-        let mut due_cards: Vec<_> = all_cards
+    /// Карты для урока. Если `tags` не пуст, берутся только карты хотя бы с одним из тегов.
+    ///
+    /// Каждое направление повторения планируется отдельно, но в урок попадает не больше
+    /// одного направления карты, и только если другие ее направления сегодня не повторялись
+    pub fn cards_to_lesson(
+        &self,
+        tags: &BTreeSet<String>,
+        vocabulary_directions: &BTreeSet<ReviewDirection>,
+    ) -> HashMap<Ulid, (ReviewDirection, Card)> {
+        let today = Utc::now().date_naive();
+        let mut all_items = self
+            .study_cards
             .iter()
-            .filter(|(_, card)| {
-                card.memory().is_due()
-                    && (card.memory().is_in_progress() || card.memory().is_known_card())
+            .filter(|(_, card)| tags.is_empty() || !card.tags().is_disjoint(tags))
+            .flat_map(|(card_id, card)| {
+                card.enabled_directions(vocabulary_directions)
+                    .into_iter()
+                    .filter(move |direction| !card.is_sibling_reviewed_on(*direction, today))
+                    .map(move |direction| LessonItem {
+                        card_id,
+                        direction,
+                        card,
+                        memory: card.memory_for(direction),
+                    })
+            })
+            .filter(|item| item.memory.is_new() || item.memory.is_due())
+            .collect::<Vec<_>>();
+
+        // Родственные направления одной карты не показываются в один день: остается
+        // самое просроченное направление, а новые направления вводятся по порядку
+        all_items.sort_by_key(|item| (item.memory.is_new(), item.memory.next_review_date()));
+        let mut seen_cards = HashSet::new();
+        all_items.retain(|item| seen_cards.insert(*item.card_id));
+
+        let mut due_items: Vec<_> = all_items
+            .iter()
+            .filter(|item| {
+                item.memory.is_due()
+                    && (item.memory.is_in_progress() || item.memory.is_known_card())
             })
             .collect();
 
-        let mut priority_cards: Vec<_> = all_cards
+        let mut priority_items: Vec<_> = all_items
             .iter()
-            .filter(|(_, card)| card.memory().is_due() && card.memory().is_low_stability())
+            .filter(|item| item.memory.is_due() && item.memory.is_low_stability())
             .collect();
 
-        if priority_cards.len() < NEW_CARDS_LIMIT {
-            let mut new_cards: Vec<_> = all_cards
+        if priority_items.len() < NEW_CARDS_LIMIT {
+            let mut new_items: Vec<_> = all_items
                 .iter()
-                .filter(|(_, card)| card.memory().is_new())
+                .filter(|item| item.memory.is_new())
                 .collect();
 
-            let available = NEW_CARDS_LIMIT.saturating_sub(priority_cards.len());
-            new_cards.truncate(available);
+            let available = NEW_CARDS_LIMIT.saturating_sub(priority_items.len());
+            new_items.truncate(available);
 
-            priority_cards.extend(new_cards);
+            priority_items.extend(new_items);
         }
 
-        due_cards.sort_by_key(|(_, card)| card.memory().next_review_date());
-        priority_cards.sort_by(|(_, a_card), (_, b_card)| {
-            let reviews_cmp = b_card
-                .memory()
-                .reviews()
-                .len()
-                .cmp(&a_card.memory().reviews().len());
+        due_items.sort_by_key(|item| item.memory.next_review_date());
+        priority_items.sort_by(|a, b| {
+            let reviews_cmp = b.memory.reviews().len().cmp(&a.memory.reviews().len());
             if reviews_cmp != std::cmp::Ordering::Equal {
                 return reviews_cmp;
             }
 
-            a_card
-                .memory()
+            a.memory
                 .next_review_date()
-                .cmp(&b_card.memory().next_review_date())
+                .cmp(&b.memory.next_review_date())
         });
 
-        due_cards.append(&mut priority_cards);
-        due_cards.shuffle(&mut rand::rng());
+        due_items.append(&mut priority_items);
+        due_items.shuffle(&mut rand::rng());
 
-        due_cards
+        due_items
             .iter()
-            .map(|(card_id, card)| (**card_id, card.card().clone()))
+            .map(|item| (*item.card_id, (item.direction, item.card.card().clone())))
             .collect()
     }
 
     pub(crate) fn rate_card(
         &mut self,
         card_id: Ulid,
+        direction: ReviewDirection,
         rating: Rating,
         interval: Duration,
         memory_state: MemoryState,
     ) -> Result<(), KeikakuError> {
         if let Some(card) = self.study_cards.get_mut(&card_id) {
             let review = Review::new(rating, interval);
-            card.memory_history.add_review(memory_state, review);
+            card.memory_for_mut(direction)?
+                .add_review(memory_state, review);
             self.update_history();
             Ok(())
         } else {
@@ -183,6 +339,132 @@ impl KnowledgeSet {
         }
     }
 
+    /// Отмечает карту выученной по всем включенным направлениям, иначе невыученные
+    /// направления попали бы в урок как новые
+    pub(crate) fn mark_card_as_known(
+        &mut self,
+        card_id: Ulid,
+        memory_state: MemoryState,
+        vocabulary_directions: &BTreeSet<ReviewDirection>,
+    ) -> Result<(), KeikakuError> {
+        let card = self
+            .study_cards
+            .get_mut(&card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+
+        for direction in card.enabled_directions(vocabulary_directions) {
+            card.memory_for_mut(direction)?
+                .set_memory_state(memory_state.clone());
+        }
+        Ok(())
+    }
+
+    pub(crate) fn reset_card_progress(
+        &mut self,
+        card_id: Ulid,
+        archive_reviews: bool,
+    ) -> Result<(), KeikakuError> {
+        let card = self
+            .study_cards
+            .get_mut(&card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+
+        card.memory_history.reset(archive_reviews);
+        for memory in card.direction_histories.values_mut() {
+            memory.reset(archive_reviews);
+        }
+        Ok(())
+    }
+
+    /// Задает направления повторения карты. `None` — использовать настройки типа карты
+    pub(crate) fn set_card_review_directions(
+        &mut self,
+        card_id: Ulid,
+        directions: Option<BTreeSet<ReviewDirection>>,
+    ) -> Result<(), KeikakuError> {
+        let card = self
+            .study_cards
+            .get_mut(&card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+
+        if !matches!(card.card, Card::Vocabulary(_)) {
+            return Err(KeikakuError::InvalidValues {
+                reason: "Review directions are supported only for vocabulary cards".to_string(),
+            });
+        }
+
+        if directions.as_ref().is_some_and(BTreeSet::is_empty) {
+            return Err(KeikakuError::InvalidValues {
+                reason: "At least one review direction is required".to_string(),
+            });
+        }
+
+        card.review_directions = directions;
+        Ok(())
+    }
+
+    /// Задает заметки карты. Пустой текст удаляет заметки
+    pub(crate) fn set_card_notes(
+        &mut self,
+        card_id: Ulid,
+        notes: &str,
+    ) -> Result<(), KeikakuError> {
+        let card = self
+            .study_cards
+            .get_mut(&card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+
+        let notes = notes.trim();
+        card.notes = (!notes.is_empty()).then(|| notes.to_string());
+        Ok(())
+    }
+
+    pub(crate) fn add_card_tags(
+        &mut self,
+        card_id: Ulid,
+        tags: &BTreeSet<String>,
+    ) -> Result<(), KeikakuError> {
+        let tags = normalize_tags(tags)?;
+        let card = self
+            .study_cards
+            .get_mut(&card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+
+        card.tags.extend(tags);
+        Ok(())
+    }
+
+    pub(crate) fn remove_card_tags(
+        &mut self,
+        card_id: Ulid,
+        tags: &BTreeSet<String>,
+    ) -> Result<(), KeikakuError> {
+        let tags = normalize_tags(tags)?;
+        let card = self
+            .study_cards
+            .get_mut(&card_id)
+            .ok_or(KeikakuError::CardNotFound { card_id })?;
+
+        card.tags.retain(|tag| !tags.contains(tag));
+        Ok(())
+    }
+
+    /// Переименовывает тег во всех картах. Возвращает количество измененных карт
+    pub(crate) fn rename_tag(&mut self, from: &str, to: &str) -> Result<usize, KeikakuError> {
+        let from = normalize_tag(from)?;
+        let to = normalize_tag(to)?;
+        let mut renamed_count = 0;
+
+        for card in self.study_cards.values_mut() {
+            if card.tags.remove(&from) {
+                card.tags.insert(to.clone());
+                renamed_count += 1;
+            }
+        }
+
+        Ok(renamed_count)
+    }
+
     pub(crate) fn add_lesson_duration(&mut self, lesson_duration: Duration) {
         self.lesson_history
             .last_mut()
@@ -289,11 +571,45 @@ impl KnowledgeSet {
     }
 }
 
+fn normalize_tag(tag: &str) -> Result<String, KeikakuError> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(KeikakuError::InvalidValues {
+            reason: "Tag cannot be empty".to_string(),
+        });
+    }
+
+    Ok(tag.to_string())
+}
+
+fn normalize_tags(tags: &BTreeSet<String>) -> Result<BTreeSet<String>, KeikakuError> {
+    tags.iter().map(|tag| normalize_tag(tag)).collect()
+}
+
+struct LessonItem<'a> {
+    card_id: &'a Ulid,
+    direction: ReviewDirection,
+    card: &'a StudyCard,
+    memory: &'a MemoryHistory,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StudyCard {
     card_id: Ulid,
     card: Card,
+    /// История основного направления — узнавания
     memory_history: MemoryHistory,
+    #[serde(default)]
+    tags: BTreeSet<String>,
+    /// Истории дополнительных направлений повторения
+    #[serde(default)]
+    direction_histories: BTreeMap<ReviewDirection, MemoryHistory>,
+    /// Направления, выбранные для карты. `None` — настройки типа карты
+    #[serde(default)]
+    review_directions: Option<BTreeSet<ReviewDirection>>,
+    /// Заметки пользователя в markdown: мнемоники, контекст, исходное предложение
+    #[serde(default)]
+    notes: Option<String>,
 }
 
 impl StudyCard {
@@ -302,6 +618,10 @@ impl StudyCard {
             card_id: Ulid::new(),
             card,
             memory_history: MemoryHistory::default(),
+            tags: BTreeSet::new(),
+            direction_histories: BTreeMap::new(),
+            review_directions: None,
+            notes: None,
         }
     }
 
@@ -316,6 +636,82 @@ impl StudyCard {
     pub fn memory(&self) -> &MemoryHistory {
         &self.memory_history
     }
+
+    /// Теги карты: источник импорта, колода и пользовательские метки
+    pub fn tags(&self) -> &BTreeSet<String> {
+        &self.tags
+    }
+
+    pub fn notes(&self) -> Option<&str> {
+        self.notes.as_deref()
+    }
+
+    /// История повторений направления. Неповторявшееся направление считается новым
+    pub fn memory_for(&self, direction: ReviewDirection) -> &MemoryHistory {
+        match direction {
+            ReviewDirection::Recognition => &self.memory_history,
+            _ => self
+                .direction_histories
+                .get(&direction)
+                .unwrap_or(&NEW_MEMORY),
+        }
+    }
+
+    pub fn review_directions(&self) -> Option<&BTreeSet<ReviewDirection>> {
+        self.review_directions.as_ref()
+    }
+
+    /// Направления, по которым карта повторяется с учетом настроек типа карты
+    pub fn enabled_directions(
+        &self,
+        vocabulary_directions: &BTreeSet<ReviewDirection>,
+    ) -> BTreeSet<ReviewDirection> {
+        let Card::Vocabulary(vocabulary) = &self.card else {
+            return BTreeSet::from([ReviewDirection::Recognition]);
+        };
+
+        let mut directions = self
+            .review_directions
+            .as_ref()
+            .unwrap_or(vocabulary_directions)
+            .iter()
+            .copied()
+            .filter(|direction| {
+                *direction != ReviewDirection::Reading || vocabulary.supports_reading_review()
+            })
+            .collect::<BTreeSet<_>>();
+
+        if directions.is_empty() {
+            directions.insert(ReviewDirection::Recognition);
+        }
+
+        directions
+    }
+
+    fn memory_for_mut(
+        &mut self,
+        direction: ReviewDirection,
+    ) -> Result<&mut MemoryHistory, KeikakuError> {
+        if direction == ReviewDirection::Recognition {
+            return Ok(&mut self.memory_history);
+        }
+
+        if !matches!(self.card, Card::Vocabulary(_)) {
+            return Err(KeikakuError::InvalidValues {
+                reason: format!("{direction:?} direction is supported only for vocabulary cards"),
+            });
+        }
+
+        Ok(self.direction_histories.entry(direction).or_default())
+    }
+
+    fn is_sibling_reviewed_on(&self, direction: ReviewDirection, day: NaiveDate) -> bool {
+        ReviewDirection::ALL
+            .into_iter()
+            .filter(|sibling| *sibling != direction)
+            .filter_map(|sibling| self.memory_for(sibling).last_review_date())
+            .any(|date| date.date_naive() == day)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -323,6 +719,7 @@ pub enum Card {
     Vocabulary(VocabularyCard),
     Kanji(KanjiCard),
     Grammar(GrammarRuleCard),
+    Sentence(SentenceCard),
 }
 
 impl Card {
@@ -331,6 +728,7 @@ impl Card {
             Card::Vocabulary(card) => card.word(),
             Card::Kanji(card) => card.kanji(),
             Card::Grammar(card) => card.title(),
+            Card::Sentence(card) => card.sentence(),
         }
     }
 
@@ -339,6 +737,407 @@ impl Card {
             Card::Vocabulary(card) => card.meaning(),
             Card::Kanji(card) => card.description(),
             Card::Grammar(card) => card.description(),
+            Card::Sentence(card) => card.translation(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::knowledge::test_support::{known_memory, vocabulary};
+    use crate::domain::value_objects::{Difficulty, NativeLanguage, Stability};
+
+    fn all_directions() -> BTreeSet<ReviewDirection> {
+        BTreeSet::from(ReviewDirection::ALL)
+    }
+
+    fn set_memory(
+        knowledge_set: &mut KnowledgeSet,
+        card_id: Ulid,
+        direction: ReviewDirection,
+        memory_state: MemoryState,
+    ) {
+        knowledge_set
+            .study_cards
+            .get_mut(&card_id)
+            .unwrap()
+            .memory_for_mut(direction)
+            .unwrap()
+            .set_memory_state(memory_state);
+    }
+
+    #[test]
+    fn new_cards_limit_counts_words_not_directions() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        for word in ["猫", "犬", "鳥", "魚", "馬", "牛", "羊"] {
+            knowledge_set.create_card(vocabulary(word)).unwrap();
         }
+
+        // Act
+        let lesson = knowledge_set.cards_to_lesson(&BTreeSet::new(), &all_directions());
+
+        // Assert
+        assert_eq!(lesson.len(), NEW_CARDS_LIMIT);
+    }
+
+    #[test]
+    fn sibling_directions_are_not_shown_together() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card = knowledge_set.create_card(vocabulary("猫")).unwrap();
+
+        // Act
+        let lesson = knowledge_set.cards_to_lesson(&BTreeSet::new(), &all_directions());
+
+        // Assert
+        assert_eq!(lesson.len(), 1);
+        assert_eq!(lesson[card.card_id()].0, ReviewDirection::Recognition);
+    }
+
+    #[test]
+    fn due_direction_is_scheduled_before_new_sibling() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+        set_memory(
+            &mut knowledge_set,
+            card_id,
+            ReviewDirection::Production,
+            known_memory(),
+        );
+
+        // Act
+        let lesson = knowledge_set.cards_to_lesson(&BTreeSet::new(), &all_directions());
+
+        // Assert
+        assert_eq!(lesson[&card_id].0, ReviewDirection::Production);
+    }
+
+    #[test]
+    fn known_card_is_known_in_every_enabled_direction() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+        let directions =
+            BTreeSet::from([ReviewDirection::Recognition, ReviewDirection::Production]);
+
+        // Act
+        knowledge_set
+            .mark_card_as_known(card_id, known_memory(), &directions)
+            .unwrap();
+
+        // Assert
+        let study_card = &knowledge_set.study_cards[&card_id];
+        for direction in directions {
+            assert!(study_card.memory_for(direction).is_known_card());
+        }
+        assert!(study_card.memory_for(ReviewDirection::Reading).is_new());
+    }
+
+    #[test]
+    fn new_direction_is_introduced_when_sibling_is_not_due() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+        let scheduled = MemoryState::new(
+            Stability::new(30.0).unwrap(),
+            Difficulty::new(1.0).unwrap(),
+            Utc::now() + Duration::days(10),
+        );
+        set_memory(
+            &mut knowledge_set,
+            card_id,
+            ReviewDirection::Recognition,
+            scheduled,
+        );
+
+        // Act
+        let lesson = knowledge_set.cards_to_lesson(&BTreeSet::new(), &all_directions());
+
+        // Assert
+        assert_eq!(lesson[&card_id].0, ReviewDirection::Production);
+    }
+
+    #[test]
+    fn reset_card_progress_resets_every_direction() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+        for direction in [ReviewDirection::Recognition, ReviewDirection::Production] {
+            knowledge_set
+                .rate_card(
+                    card_id,
+                    direction,
+                    Rating::Good,
+                    Duration::days(1),
+                    known_memory(),
+                )
+                .unwrap();
+        }
+
+        // Act
+        knowledge_set.reset_card_progress(card_id, true).unwrap();
+
+        // Assert
+        let study_card = &knowledge_set.study_cards[&card_id];
+        for direction in [ReviewDirection::Recognition, ReviewDirection::Production] {
+            let memory = study_card.memory_for(direction);
+            assert!(memory.is_new());
+            assert!(memory.reviews().is_empty());
+            assert_eq!(memory.archived_reviews().len(), 1);
+        }
+    }
+
+    #[test]
+    fn reset_card_progress_without_archive_drops_every_direction_reviews() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+        knowledge_set
+            .rate_card(
+                card_id,
+                ReviewDirection::Production,
+                Rating::Good,
+                Duration::days(1),
+                known_memory(),
+            )
+            .unwrap();
+
+        // Act
+        knowledge_set.reset_card_progress(card_id, false).unwrap();
+
+        // Assert
+        let memory = knowledge_set.study_cards[&card_id].memory_for(ReviewDirection::Production);
+        assert!(memory.is_new());
+        assert!(memory.reviews().is_empty());
+        assert!(memory.archived_reviews().is_empty());
+    }
+
+    #[test]
+    fn reset_card_progress_of_unknown_card_fails() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+
+        // Act
+        let result = knowledge_set.reset_card_progress(Ulid::new(), true);
+
+        // Assert
+        assert!(matches!(result, Err(KeikakuError::CardNotFound { .. })));
+    }
+
+    #[test]
+    fn edit_card_keeps_review_history() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+        knowledge_set
+            .rate_card(
+                card_id,
+                ReviewDirection::Recognition,
+                Rating::Good,
+                Duration::days(1),
+                known_memory(),
+            )
+            .unwrap();
+        let edited = Card::Vocabulary(VocabularyCard::new(
+            Question::new("猫".to_string()).unwrap(),
+            Answer::new("кошка".to_string()).unwrap(),
+            vec![],
+            None,
+        ));
+
+        // Act
+        let study_card = knowledge_set.edit_card(card_id, edited).unwrap();
+
+        // Assert
+        assert_eq!(study_card.card().answer().text(), "кошка");
+        assert_eq!(study_card.memory().reviews().len(), 1);
+        assert!(study_card.memory().memory_state().is_some());
+    }
+
+    #[test]
+    fn edit_card_rejects_type_change() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("日"))
+            .unwrap()
+            .card_id();
+        let kanji = KanjiCard::new("日".to_string(), &NativeLanguage::Russian).unwrap();
+
+        // Act
+        let result = knowledge_set.edit_card(card_id, Card::Kanji(kanji));
+
+        // Assert
+        assert!(matches!(result, Err(KeikakuError::InvalidValues { .. })));
+        assert!(matches!(
+            knowledge_set.study_cards[&card_id].card(),
+            Card::Vocabulary(_)
+        ));
+    }
+
+    #[test]
+    fn edit_card_rejects_question_of_another_card() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        knowledge_set.create_card(vocabulary("猫")).unwrap();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("犬"))
+            .unwrap()
+            .card_id();
+
+        // Act
+        let result = knowledge_set.edit_card(card_id, vocabulary("猫"));
+
+        // Assert
+        assert!(matches!(result, Err(KeikakuError::DuplicateCard { .. })));
+        assert_eq!(
+            knowledge_set.study_cards[&card_id].card().question().text(),
+            "犬"
+        );
+    }
+
+    fn tags(tags: &[&str]) -> BTreeSet<String> {
+        tags.iter().map(|tag| tag.to_string()).collect()
+    }
+
+    #[test]
+    fn card_tags_are_trimmed() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+
+        // Act
+        knowledge_set
+            .add_card_tags(card_id, &tags(&["  n5 ", "animals"]))
+            .unwrap();
+        knowledge_set
+            .remove_card_tags(card_id, &tags(&[" animals"]))
+            .unwrap();
+
+        // Assert
+        assert_eq!(knowledge_set.study_cards[&card_id].tags(), &tags(&["n5"]));
+    }
+
+    #[test]
+    fn empty_tag_is_rejected() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+
+        // Act
+        let added = knowledge_set.add_card_tags(card_id, &tags(&["n5", "  "]));
+        let renamed = knowledge_set.rename_tag("n5", " ");
+
+        // Assert
+        assert!(matches!(added, Err(KeikakuError::InvalidValues { .. })));
+        assert!(matches!(renamed, Err(KeikakuError::InvalidValues { .. })));
+        assert!(knowledge_set.study_cards[&card_id].tags().is_empty());
+    }
+
+    #[test]
+    fn rename_tag_returns_number_of_changed_cards() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        for (word, card_tags) in [("猫", ["anki"]), ("犬", ["anki"]), ("鳥", ["n5"])] {
+            let card_id = *knowledge_set
+                .create_card(vocabulary(word))
+                .unwrap()
+                .card_id();
+            knowledge_set
+                .add_card_tags(card_id, &tags(&card_tags))
+                .unwrap();
+        }
+
+        // Act
+        let renamed_count = knowledge_set.rename_tag(" anki ", "import").unwrap();
+
+        // Assert
+        assert_eq!(renamed_count, 2);
+        assert_eq!(knowledge_set.tags(), tags(&["import", "n5"]));
+    }
+
+    #[test]
+    fn rename_tag_onto_existing_tag_merges_them() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+        knowledge_set
+            .add_card_tags(card_id, &tags(&["anki", "import"]))
+            .unwrap();
+
+        // Act
+        let renamed_count = knowledge_set.rename_tag("anki", "import").unwrap();
+
+        // Assert
+        assert_eq!(renamed_count, 1);
+        assert_eq!(
+            knowledge_set.study_cards[&card_id].tags(),
+            &tags(&["import"])
+        );
+    }
+
+    #[test]
+    fn lesson_is_filtered_by_any_of_tags() {
+        // Arrange
+        let mut knowledge_set = KnowledgeSet::new();
+        let cat_id = *knowledge_set
+            .create_card(vocabulary("猫"))
+            .unwrap()
+            .card_id();
+        let dog_id = *knowledge_set
+            .create_card(vocabulary("犬"))
+            .unwrap()
+            .card_id();
+        knowledge_set.create_card(vocabulary("水")).unwrap();
+        knowledge_set
+            .add_card_tags(cat_id, &tags(&["animals"]))
+            .unwrap();
+        knowledge_set
+            .add_card_tags(dog_id, &tags(&["pets"]))
+            .unwrap();
+        let directions = BTreeSet::from([ReviewDirection::Recognition]);
+
+        // Act
+        let all = knowledge_set.cards_to_lesson(&BTreeSet::new(), &directions);
+        let filtered = knowledge_set.cards_to_lesson(&tags(&["animals", "pets"]), &directions);
+        let single = knowledge_set.cards_to_lesson(&tags(&["animals"]), &directions);
+
+        // Assert
+        assert_eq!(all.len(), 3);
+        assert_eq!(
+            filtered.keys().copied().collect::<HashSet<_>>(),
+            HashSet::from([cat_id, dog_id])
+        );
+        assert_eq!(single.keys().copied().collect::<Vec<_>>(), [cat_id]);
     }
 }
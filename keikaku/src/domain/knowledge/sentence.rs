@@ -0,0 +1,296 @@
+use std::collections::BTreeSet;
+
+use crate::domain::{
+    KeikakuError,
+    tokenizer::Tokenizer,
+    value_objects::{Answer, Question},
+};
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+/// Чем заменяется вырезанный фрагмент в вопросе
+const CLOZE_PLACEHOLDER: &str = "［…］";
+
+/// Пропуск в предложении: диапазон символов `start..end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ClozeDeletion {
+    start: usize,
+    end: usize,
+}
+
+impl ClozeDeletion {
+    pub fn new(start: usize, end: usize) -> Result<Self, KeikakuError> {
+        if start >= end {
+            return Err(KeikakuError::InvalidValues {
+                reason: format!("Cloze range {start}..{end} is empty"),
+            });
+        }
+
+        Ok(Self { start, end })
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    fn overlaps(&self, other: &ClozeDeletion) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+/// Слово предложения, которое можно вырезать в пропуск
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClozeCandidate {
+    deletion: ClozeDeletion,
+    surface: String,
+    base_form: String,
+}
+
+impl ClozeCandidate {
+    pub fn deletion(&self) -> ClozeDeletion {
+        self.deletion
+    }
+
+    /// Слово в том виде, в каком оно стоит в предложении
+    pub fn surface(&self) -> &str {
+        &self.surface
+    }
+
+    /// Словарная форма слова
+    pub fn base_form(&self) -> &str {
+        &self.base_form
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentenceCard {
+    sentence: Question,
+    translation: Answer,
+    #[serde(default)]
+    clozes: Vec<ClozeDeletion>,
+    /// Карточки слов, которые встречаются в предложении
+    #[serde(default)]
+    vocabulary_card_ids: BTreeSet<Ulid>,
+}
+
+impl SentenceCard {
+    pub fn new(
+        sentence: Question,
+        translation: Answer,
+        clozes: Vec<ClozeDeletion>,
+        vocabulary_card_ids: BTreeSet<Ulid>,
+    ) -> Result<Self, KeikakuError> {
+        let clozes = validate_clozes(sentence.text(), clozes)?;
+
+        Ok(Self {
+            sentence,
+            translation,
+            clozes,
+            vocabulary_card_ids,
+        })
+    }
+
+    /// Копия карты с измененным переводом и пропусками
+    pub fn with_content(
+        &self,
+        translation: Answer,
+        clozes: Vec<ClozeDeletion>,
+    ) -> Result<Self, KeikakuError> {
+        Self::new(
+            self.sentence.clone(),
+            translation,
+            clozes,
+            self.vocabulary_card_ids.clone(),
+        )
+    }
+
+    pub fn sentence(&self) -> &Question {
+        &self.sentence
+    }
+
+    pub fn translation(&self) -> &Answer {
+        &self.translation
+    }
+
+    pub fn clozes(&self) -> &[ClozeDeletion] {
+        &self.clozes
+    }
+
+    pub fn vocabulary_card_ids(&self) -> &BTreeSet<Ulid> {
+        &self.vocabulary_card_ids
+    }
+
+    /// Предложение, в котором пропуски заменены заглушкой. Без пропусков — предложение целиком
+    pub fn cloze_text(&self) -> String {
+        let chars = self.sentence.text().chars().collect::<Vec<_>>();
+        let mut result = String::with_capacity(self.sentence.text().len());
+        let mut position = 0;
+
+        for cloze in &self.clozes {
+            result.extend(&chars[position..cloze.start]);
+            result.push_str(CLOZE_PLACEHOLDER);
+            position = cloze.end;
+        }
+        result.extend(&chars[position..]);
+
+        result
+    }
+
+    /// Вырезанные фрагменты в порядке следования
+    pub fn cloze_answers(&self) -> Vec<String> {
+        let chars = self.sentence.text().chars().collect::<Vec<_>>();
+
+        self.clozes
+            .iter()
+            .map(|cloze| chars[cloze.start..cloze.end].iter().collect())
+            .collect()
+    }
+
+    /// Убирает ссылку на удаленную карточку слова
+    pub(crate) fn unlink_vocabulary_card(&mut self, card_id: Ulid) {
+        self.vocabulary_card_ids.remove(&card_id);
+    }
+
+    /// Переносит ссылку со слитой карточки слова на оставшуюся
+    pub(crate) fn relink_vocabulary_card(&mut self, from: Ulid, to: Ulid) {
+        if self.vocabulary_card_ids.remove(&from) {
+            self.vocabulary_card_ids.insert(to);
+        }
+    }
+}
+
+/// Словарные слова предложения, которые можно вырезать в пропуски
+pub fn propose_clozes(
+    tokenizer: &Tokenizer,
+    sentence: &str,
+) -> Result<Vec<ClozeCandidate>, KeikakuError> {
+    let chars = sentence.chars().collect::<Vec<_>>();
+    let mut candidates = Vec::new();
+    let mut position = 0;
+
+    for token in tokenizer.tokenize(sentence)? {
+        let surface = token
+            .orthographic_surface_form()
+            .chars()
+            .collect::<Vec<_>>();
+        let Some(offset) = find_chars(&chars[position..], &surface) else {
+            continue;
+        };
+
+        let start = position + offset;
+        let end = start + surface.len();
+        position = end;
+
+        if token.part_of_speech().is_vocabulary_word() {
+            candidates.push(ClozeCandidate {
+                deletion: ClozeDeletion::new(start, end)?,
+                surface: token.orthographic_surface_form().to_string(),
+                base_form: token.orthographic_base_form().to_string(),
+            });
+        }
+    }
+
+    Ok(candidates)
+}
+
+fn validate_clozes(
+    sentence: &str,
+    mut clozes: Vec<ClozeDeletion>,
+) -> Result<Vec<ClozeDeletion>, KeikakuError> {
+    let length = sentence.chars().count();
+    clozes.sort();
+    clozes.dedup();
+
+    if let Some(cloze) = clozes.iter().find(|cloze| cloze.end > length) {
+        return Err(KeikakuError::InvalidValues {
+            reason: format!(
+                "Cloze range {}..{} is outside of the sentence",
+                cloze.start, cloze.end
+            ),
+        });
+    }
+
+    if clozes.windows(2).any(|pair| pair[0].overlaps(&pair[1])) {
+        return Err(KeikakuError::InvalidValues {
+            reason: "Cloze ranges must not overlap".to_string(),
+        });
+    }
+
+    Ok(clozes)
+}
+
+fn find_chars(haystack: &[char], needle: &[char]) -> Option<usize> {
+    if needle.is_empty() {
+        return None;
+    }
+
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sentence_card(clozes: Vec<ClozeDeletion>) -> Result<SentenceCard, KeikakuError> {
+        SentenceCard::new(
+            Question::new("私は本を読む".to_string()).unwrap(),
+            Answer::new("Я читаю книгу".to_string()).unwrap(),
+            clozes,
+            BTreeSet::new(),
+        )
+    }
+
+    #[test]
+    fn cloze_text_replaces_deleted_spans() {
+        // Arrange
+        let clozes = vec![
+            ClozeDeletion::new(4, 6).unwrap(),
+            ClozeDeletion::new(2, 3).unwrap(),
+        ];
+
+        // Act
+        let card = sentence_card(clozes).unwrap();
+
+        // Assert
+        assert_eq!(card.cloze_text(), "私は［…］を［…］");
+        assert_eq!(card.cloze_answers(), ["本", "読む"]);
+    }
+
+    #[test]
+    fn overlapping_or_out_of_range_clozes_are_rejected() {
+        // Arrange
+        let overlapping = vec![
+            ClozeDeletion::new(0, 3).unwrap(),
+            ClozeDeletion::new(2, 4).unwrap(),
+        ];
+        let out_of_range = vec![ClozeDeletion::new(4, 10).unwrap()];
+
+        // Act + Assert
+        assert!(sentence_card(overlapping).is_err());
+        assert!(sentence_card(out_of_range).is_err());
+    }
+
+    #[test]
+    fn proposed_clozes_cover_vocabulary_words() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+
+        // Act
+        let candidates = propose_clozes(&tokenizer, "本を読む").unwrap();
+
+        // Assert
+        let surfaces = candidates
+            .iter()
+            .map(|candidate| candidate.surface())
+            .collect::<Vec<_>>();
+        assert_eq!(surfaces, ["本", "読む"]);
+        assert_eq!(candidates[1].deletion(), ClozeDeletion::new(2, 4).unwrap());
+        assert_eq!(candidates[1].base_form(), "読む");
+    }
+}
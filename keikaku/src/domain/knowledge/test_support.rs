@@ -0,0 +1,45 @@
+//! Общие заготовки для тестов колоды
+
+use std::collections::BTreeSet;
+
+use chrono::Utc;
+
+use super::{Card, KnowledgeSet, VocabularyCard};
+use crate::domain::review::MemoryState;
+use crate::domain::value_objects::{Answer, Difficulty, Question, Stability};
+
+/// Словарная карта с заглушкой вместо перевода
+pub(super) fn vocabulary(word: &str) -> Card {
+    Card::Vocabulary(VocabularyCard::new(
+        Question::new(word.to_string()).unwrap(),
+        Answer::new("meaning".to_string()).unwrap(),
+        vec![],
+        None,
+    ))
+}
+
+/// Состояние выученной карты
+pub(super) fn known_memory() -> MemoryState {
+    memory_with_stability(30.0)
+}
+
+/// Состояние карты, которая уже изучается, но еще не выучена
+pub(super) fn studied_memory() -> MemoryState {
+    memory_with_stability(10.0)
+}
+
+/// Добавляет карту и сразу отмечает ее выученной
+pub(super) fn add_known(knowledge_set: &mut KnowledgeSet, card: Card) {
+    let study_card = knowledge_set.create_card(card).unwrap();
+    knowledge_set
+        .mark_card_as_known(*study_card.card_id(), known_memory(), &BTreeSet::new())
+        .unwrap();
+}
+
+fn memory_with_stability(stability: f64) -> MemoryState {
+    MemoryState::new(
+        Stability::new(stability).unwrap(),
+        Difficulty::new(1.0).unwrap(),
+        Utc::now(),
+    )
+}
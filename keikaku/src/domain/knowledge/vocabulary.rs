@@ -1,5 +1,5 @@
 use crate::domain::dictionary::{KANJI_DB, KanjiInfo};
-use crate::domain::japanese::IsJapanese;
+use crate::domain::japanese::{IsJapanese, IsJapaneseText};
 use crate::domain::value_objects::{Answer, ExamplePhrase, JapaneseLevel, Question};
 use serde::{Deserialize, Serialize};
 
@@ -8,14 +8,27 @@ pub struct VocabularyCard {
     word: Question,
     meaning: Answer,
     example_phrases: Vec<ExamplePhrase>,
+    /// Чтение слова хираганой. У старых карт отсутствует
+    #[serde(default)]
+    reading: Option<String>,
 }
 
 impl VocabularyCard {
-    pub fn new(word: Question, meaning: Answer, example_phrases: Vec<ExamplePhrase>) -> Self {
+    pub fn new(
+        word: Question,
+        meaning: Answer,
+        example_phrases: Vec<ExamplePhrase>,
+        reading: Option<String>,
+    ) -> Self {
+        let reading = reading
+            .map(|reading| reading.trim().to_hiragana())
+            .filter(|reading| !reading.is_empty());
+
         Self {
             word,
             meaning,
             example_phrases,
+            reading,
         }
     }
 
@@ -31,6 +44,15 @@ impl VocabularyCard {
         &self.example_phrases
     }
 
+    pub fn reading(&self) -> Option<&str> {
+        self.reading.as_deref()
+    }
+
+    /// Чтение имеет смысл спрашивать только у слов с кандзи
+    pub fn supports_reading_review(&self) -> bool {
+        self.reading.is_some() && self.word.text().contains_kanji()
+    }
+
     pub fn get_kanji_cards(&self, current_level: &JapaneseLevel) -> Vec<&KanjiInfo> {
         self.word
             .text()
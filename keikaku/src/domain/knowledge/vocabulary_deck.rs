@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+
+use super::{Card, KnowledgeSet};
+use crate::domain::{
+    KeikakuError,
+    tokenizer::{PartOfSpeech, Tokenizer},
+};
+
+/// Слово из текста пользователя, для которого можно создать карточку
+#[derive(Debug, Clone, PartialEq)]
+pub struct VocabularyProposal {
+    surface: String,
+    base_form: String,
+    reading: String,
+    part_of_speech: PartOfSpeech,
+    existing_cards: Vec<String>,
+}
+
+impl VocabularyProposal {
+    /// Слово в том виде, в каком оно стоит в тексте
+    pub fn surface(&self) -> &str {
+        &self.surface
+    }
+
+    pub fn base_form(&self) -> &str {
+        &self.base_form
+    }
+
+    /// Чтение словарной формы хираганой
+    pub fn reading(&self) -> &str {
+        &self.reading
+    }
+
+    pub fn part_of_speech(&self) -> &PartOfSpeech {
+        &self.part_of_speech
+    }
+
+    /// Карточки, которые уже записывают это слово
+    pub fn existing_cards(&self) -> &[String] {
+        &self.existing_cards
+    }
+}
+
+impl KnowledgeSet {
+    /// Слова текста в порядке появления: без частиц, знаков и вспомогательных глаголов,
+    /// каждая словарная форма один раз
+    pub fn propose_vocabulary_cards(
+        &self,
+        tokenizer: &Tokenizer,
+        text: &str,
+    ) -> Result<Vec<VocabularyProposal>, KeikakuError> {
+        let lexeme_index = self.lexeme_index(tokenizer)?;
+        let mut seen = HashSet::new();
+        let mut proposals = Vec::new();
+
+        for token in tokenizer.tokenize(text)? {
+            let base_form = token.orthographic_base_form();
+            if !token.part_of_speech().is_vocabulary_word() || !seen.insert(base_form.to_string()) {
+                continue;
+            }
+
+            let existing_cards = lexeme_index
+                .find_likely_duplicates(tokenizer, base_form)?
+                .into_iter()
+                .filter_map(|study_card| match study_card.card() {
+                    Card::Vocabulary(vocabulary) => Some(vocabulary.word().text().to_string()),
+                    _ => None,
+                })
+                .collect();
+
+            proposals.push(VocabularyProposal {
+                surface: token.orthographic_surface_form().to_string(),
+                base_form: base_form.to_string(),
+                reading: token.kana_base_form(),
+                part_of_speech: token.part_of_speech().clone(),
+                existing_cards,
+            });
+        }
+
+        Ok(proposals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::knowledge::test_support::vocabulary;
+
+    #[test]
+    fn sentence_is_split_into_new_words() {
+        // Arrange
+        let tokenizer = Tokenizer::new().unwrap();
+        let mut knowledge_set = KnowledgeSet::new();
+        knowledge_set.create_card(vocabulary("食べる")).unwrap();
+
+        // Act
+        let proposals = knowledge_set
+            .propose_vocabulary_cards(&tokenizer, "猫が魚を食べた。猫は魚が好きだ。")
+            .unwrap();
+
+        // Assert
+        let base_forms = proposals
+            .iter()
+            .map(|proposal| proposal.base_form())
+            .collect::<Vec<_>>();
+        assert_eq!(base_forms, ["猫", "魚", "食べる", "好き"]);
+
+        let taberu = &proposals[2];
+        assert_eq!(taberu.surface(), "食べ");
+        assert_eq!(taberu.existing_cards(), ["食べる"]);
+        assert!(proposals[0].existing_cards().is_empty());
+    }
+}
@@ -4,21 +4,25 @@ pub mod furiganizer;
 pub mod grammar;
 pub mod japanese;
 pub mod knowledge;
+pub mod placement;
 pub mod review;
 pub mod settings;
 pub mod tokenizer;
+pub mod transliteration;
+pub mod typed_answer;
 pub mod value_objects;
 
 use crate::domain::{
     knowledge::{Card, KnowledgeSet, StudyCard},
     review::MemoryState,
-    value_objects::{JapaneseLevel, NativeLanguage},
+    value_objects::{JapaneseLevel, NativeLanguage, ReviewDirection},
 };
 use chrono::Duration;
 pub use error::KeikakuError;
 pub use review::Review;
 use serde::{Deserialize, Serialize};
-pub use settings::{LlmSettings, UserSettings};
+pub use settings::{FuriganaMode, LlmSettings, UserSettings};
+use std::collections::{BTreeSet, HashMap};
 use ulid::Ulid;
 pub use value_objects::Rating;
 
@@ -60,6 +64,10 @@ impl User {
         &self.current_japanese_level
     }
 
+    pub fn set_current_japanese_level(&mut self, level: JapaneseLevel) {
+        self.current_japanese_level = level;
+    }
+
     pub fn native_language(&self) -> &NativeLanguage {
         &self.native_language
     }
@@ -79,15 +87,72 @@ impl User {
     pub fn rate_card(
         &mut self,
         card_id: Ulid,
+        direction: ReviewDirection,
         rating: Rating,
         interval: Duration,
         memory_state: MemoryState,
     ) -> Result<(), KeikakuError> {
         self.knowledge_set
-            .rate_card(card_id, rating, interval, memory_state)?;
+            .rate_card(card_id, direction, rating, interval, memory_state)?;
         Ok(())
     }
 
+    /// Карты для урока с учетом направлений повторения из настроек
+    pub fn cards_to_lesson(
+        &self,
+        tags: &BTreeSet<String>,
+    ) -> HashMap<Ulid, (ReviewDirection, Card)> {
+        self.knowledge_set
+            .cards_to_lesson(tags, &self.settings.vocabulary_review_directions())
+    }
+
+    pub fn set_card_review_directions(
+        &mut self,
+        card_id: Ulid,
+        directions: Option<BTreeSet<ReviewDirection>>,
+    ) -> Result<(), KeikakuError> {
+        self.knowledge_set
+            .set_card_review_directions(card_id, directions)
+    }
+
+    pub fn set_card_notes(&mut self, card_id: Ulid, notes: &str) -> Result<(), KeikakuError> {
+        self.knowledge_set.set_card_notes(card_id, notes)
+    }
+
+    pub fn record_conjugation_answer(&mut self, rule_id: &str, is_correct: bool) {
+        self.knowledge_set
+            .record_conjugation_answer(rule_id, is_correct)
+    }
+
+    pub fn merge_cards(
+        &mut self,
+        target_id: Ulid,
+        source_id: Ulid,
+    ) -> Result<StudyCard, KeikakuError> {
+        self.knowledge_set.merge_cards(target_id, source_id)
+    }
+
+    pub fn mark_card_as_known(
+        &mut self,
+        card_id: Ulid,
+        memory_state: MemoryState,
+    ) -> Result<(), KeikakuError> {
+        self.knowledge_set.mark_card_as_known(
+            card_id,
+            memory_state,
+            &self.settings.vocabulary_review_directions(),
+        )
+    }
+
+    pub fn reset_card_progress(
+        &mut self,
+        card_id: Ulid,
+        archive_reviews: bool,
+    ) -> Result<(), KeikakuError> {
+        self.knowledge_set
+            .reset_card_progress(card_id, archive_reviews)
+    }
+
     pub fn add_lesson_duration(&mut self, lesson_duration: Duration) {
         self.knowledge_set.add_lesson_duration(lesson_duration);
     }
@@ -99,4 +164,28 @@ impl User {
     pub fn create_card(&mut self, card: Card) -> Result<StudyCard, KeikakuError> {
         self.knowledge_set.create_card(card)
     }
+
+    pub fn edit_card(&mut self, card_id: Ulid, card: Card) -> Result<StudyCard, KeikakuError> {
+        self.knowledge_set.edit_card(card_id, card)
+    }
+
+    pub fn add_card_tags(
+        &mut self,
+        card_id: Ulid,
+        tags: &BTreeSet<String>,
+    ) -> Result<(), KeikakuError> {
+        self.knowledge_set.add_card_tags(card_id, tags)
+    }
+
+    pub fn remove_card_tags(
+        &mut self,
+        card_id: Ulid,
+        tags: &BTreeSet<String>,
+    ) -> Result<(), KeikakuError> {
+        self.knowledge_set.remove_card_tags(card_id, tags)
+    }
+
+    pub fn rename_tag(&mut self, from: &str, to: &str) -> Result<usize, KeikakuError> {
+        self.knowledge_set.rename_tag(from, to)
+    }
 }
@@ -0,0 +1,353 @@
+use std::collections::{BTreeMap, HashSet};
+
+use rand::seq::{IndexedRandom, SliceRandom};
+
+use crate::domain::{
+    dictionary::{JLPT_DB, VOCABULARY_DB},
+    error::KeikakuError,
+    value_objects::{JapaneseLevel, NativeLanguage},
+};
+
+/// Сколько вопросов задается на каждом уровне
+const QUESTIONS_PER_LEVEL: usize = 10;
+/// Количество вариантов ответа в вопросе
+const OPTIONS_PER_QUESTION: usize = 4;
+/// Доля правильных ответов, при которой уровень считается освоенным
+const PASS_THRESHOLD: f64 = 0.7;
+
+/// Вопрос теста: выбрать перевод слова из нескольких вариантов
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacementQuestion {
+    word: String,
+    level: JapaneseLevel,
+    options: Vec<String>,
+    correct_index: usize,
+}
+
+impl PlacementQuestion {
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn level(&self) -> &JapaneseLevel {
+        &self.level
+    }
+
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PlacementAnswer {
+    word: String,
+    level: JapaneseLevel,
+    is_correct: bool,
+}
+
+/// Итог теста: оценка уровня и покрытие словаря по уровням
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacementResult {
+    estimated_level: JapaneseLevel,
+    coverage: BTreeMap<JapaneseLevel, f64>,
+    known_words: Vec<String>,
+}
+
+impl PlacementResult {
+    /// Уровень, который стоит изучать: первый неосвоенный уровень
+    pub fn estimated_level(&self) -> &JapaneseLevel {
+        &self.estimated_level
+    }
+
+    /// Доля правильных ответов по каждому проверенному уровню
+    pub fn coverage(&self) -> &BTreeMap<JapaneseLevel, f64> {
+        &self.coverage
+    }
+
+    /// Освоенные уровни: все их слова скорее всего известны
+    pub fn mastered_levels(&self) -> Vec<JapaneseLevel> {
+        self.coverage
+            .iter()
+            .filter(|(_, coverage)| **coverage >= PASS_THRESHOLD)
+            .map(|(level, _)| *level)
+            .collect()
+    }
+
+    /// Слова неосвоенных уровней, на которые был дан правильный ответ
+    pub fn known_words(&self) -> &[String] {
+        &self.known_words
+    }
+}
+
+/// Адаптивный тест на определение уровня.
+///
+/// Начинается с самого простого уровня и переходит к следующему,
+/// пока доля правильных ответов не опустится ниже порога.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacementTest {
+    native_language: NativeLanguage,
+    levels: Vec<JapaneseLevel>,
+    level_index: usize,
+    current_question: Option<PlacementQuestion>,
+    asked_words: HashSet<String>,
+    answers: Vec<PlacementAnswer>,
+}
+
+impl PlacementTest {
+    pub fn new(native_language: NativeLanguage) -> Self {
+        let mut test = Self {
+            native_language,
+            levels: JLPT_DB.available_levels(),
+            level_index: 0,
+            current_question: None,
+            asked_words: HashSet::new(),
+            answers: Vec::new(),
+        };
+
+        test.current_question = test.next_question();
+        test
+    }
+
+    pub fn current_question(&self) -> Option<&PlacementQuestion> {
+        self.current_question.as_ref()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current_question.is_none()
+    }
+
+    pub fn answered_count(&self) -> usize {
+        self.answers.len()
+    }
+
+    /// Принимает ответ на текущий вопрос. `None` означает «не знаю»
+    pub fn answer(&mut self, option_index: Option<usize>) -> Result<bool, KeikakuError> {
+        let question = self
+            .current_question
+            .take()
+            .ok_or(KeikakuError::InvalidValues {
+                reason: "Placement test is already finished".to_string(),
+            })?;
+
+        if option_index.is_some_and(|index| index >= question.options.len()) {
+            self.current_question = Some(question);
+            return Err(KeikakuError::InvalidValues {
+                reason: format!("Option index {:?} is out of range", option_index),
+            });
+        }
+
+        let is_correct = option_index == Some(question.correct_index);
+        self.answers.push(PlacementAnswer {
+            word: question.word,
+            level: question.level,
+            is_correct,
+        });
+
+        self.advance_level_if_needed();
+        self.current_question = self.next_question();
+
+        Ok(is_correct)
+    }
+
+    pub fn result(&self) -> PlacementResult {
+        let mut coverage = BTreeMap::new();
+
+        for level in &self.levels {
+            let (asked, correct) = self.level_stats(level);
+            if asked > 0 {
+                coverage.insert(*level, correct as f64 / asked as f64);
+            }
+        }
+
+        let estimated_level = self
+            .levels
+            .iter()
+            .find(|level| {
+                coverage
+                    .get(*level)
+                    .is_none_or(|coverage| *coverage < PASS_THRESHOLD)
+            })
+            .or(self.levels.last())
+            .copied()
+            .unwrap_or(JapaneseLevel::N5);
+
+        let known_words = self
+            .answers
+            .iter()
+            .filter(|answer| answer.is_correct)
+            .filter(|answer| {
+                coverage
+                    .get(&answer.level)
+                    .is_some_and(|coverage| *coverage < PASS_THRESHOLD)
+            })
+            .map(|answer| answer.word.clone())
+            .collect();
+
+        PlacementResult {
+            estimated_level,
+            coverage,
+            known_words,
+        }
+    }
+
+    fn level_stats(&self, level: &JapaneseLevel) -> (usize, usize) {
+        self.answers
+            .iter()
+            .filter(|answer| &answer.level == level)
+            .fold((0, 0), |(asked, correct), answer| {
+                (asked + 1, correct + answer.is_correct as usize)
+            })
+    }
+
+    fn advance_level_if_needed(&mut self) {
+        let Some(level) = self.levels.get(self.level_index).copied() else {
+            return;
+        };
+
+        let (asked, correct) = self.level_stats(&level);
+        let wrong = asked - correct;
+        let max_wrong =
+            QUESTIONS_PER_LEVEL - (QUESTIONS_PER_LEVEL as f64 * PASS_THRESHOLD).ceil() as usize;
+
+        if wrong > max_wrong {
+            // Уровень не освоен, дальше проверять нет смысла
+            self.level_index = self.levels.len();
+        } else if asked >= QUESTIONS_PER_LEVEL {
+            self.level_index += 1;
+        }
+    }
+
+    fn next_question(&mut self) -> Option<PlacementQuestion> {
+        while let Some(level) = self.levels.get(self.level_index).copied() {
+            if let Some(question) = self.build_question(level) {
+                self.asked_words.insert(question.word.clone());
+                return Some(question);
+            }
+
+            // На уровне закончились слова с переводами
+            self.level_index += 1;
+        }
+
+        None
+    }
+
+    fn build_question(&self, level: JapaneseLevel) -> Option<PlacementQuestion> {
+        let mut rng = rand::rng();
+        let candidates = JLPT_DB
+            .get_words_for_level(&level)
+            .into_iter()
+            .filter_map(|word| {
+                VOCABULARY_DB
+                    .get_translation(&word, &self.native_language)
+                    .filter(|translation| !translation.trim().is_empty())
+                    .map(|translation| (word, translation))
+            })
+            .collect::<Vec<_>>();
+
+        let (word, translation) = candidates
+            .iter()
+            .filter(|(word, _)| !self.asked_words.contains(word))
+            .collect::<Vec<_>>()
+            .choose(&mut rng)
+            .map(|(word, translation)| (word.clone(), translation.clone()))?;
+
+        let mut options = candidates
+            .iter()
+            .map(|(_, translation)| translation)
+            .filter(|other| **other != translation)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>()
+            .choose_multiple(&mut rng, OPTIONS_PER_QUESTION - 1)
+            .map(|other| (*other).clone())
+            .collect::<Vec<_>>();
+
+        options.push(translation.clone());
+        options.shuffle(&mut rng);
+
+        let correct_index = options.iter().position(|option| *option == translation)?;
+
+        Some(PlacementQuestion {
+            word,
+            level,
+            options,
+            correct_index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn correct_index(test: &PlacementTest) -> usize {
+        test.current_question.as_ref().unwrap().correct_index
+    }
+
+    #[test]
+    fn new_test_starts_with_easiest_level() {
+        // Arrange & Act
+        let test = PlacementTest::new(NativeLanguage::Russian);
+
+        // Assert
+        let question = test.current_question().unwrap();
+        assert_eq!(question.level(), &JapaneseLevel::N5);
+        assert!(question.options().len() > 1);
+        assert!(
+            question.options().contains(
+                &VOCABULARY_DB
+                    .get_translation(question.word(), &NativeLanguage::Russian)
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn all_unknown_answers_finish_early_on_first_level() {
+        // Arrange
+        let mut test = PlacementTest::new(NativeLanguage::Russian);
+
+        // Act
+        while !test.is_finished() {
+            test.answer(None).unwrap();
+        }
+
+        // Assert
+        let result = test.result();
+        assert!(test.answered_count() < QUESTIONS_PER_LEVEL);
+        assert_eq!(result.estimated_level(), &JapaneseLevel::N5);
+        assert!(result.mastered_levels().is_empty());
+        assert!(result.known_words().is_empty());
+    }
+
+    #[test]
+    fn all_correct_answers_master_every_level() {
+        // Arrange
+        let mut test = PlacementTest::new(NativeLanguage::Russian);
+
+        // Act
+        while !test.is_finished() {
+            let index = correct_index(&test);
+            assert!(test.answer(Some(index)).unwrap());
+        }
+
+        // Assert
+        let result = test.result();
+        assert_eq!(result.mastered_levels(), JLPT_DB.available_levels());
+        assert_eq!(result.estimated_level(), &JapaneseLevel::N1);
+    }
+
+    #[test]
+    fn answer_rejects_out_of_range_option() {
+        // Arrange
+        let mut test = PlacementTest::new(NativeLanguage::Russian);
+        let question = test.current_question().cloned();
+
+        // Act
+        let result = test.answer(Some(OPTIONS_PER_QUESTION + 1));
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(test.current_question().cloned(), question);
+    }
+}
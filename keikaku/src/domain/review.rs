@@ -47,6 +47,8 @@ impl MemoryState {
 pub struct MemoryHistory {
     current_state: Option<MemoryState>,
     reviews: VecDeque<Review>,
+    #[serde(default)]
+    archived_reviews: VecDeque<Review>,
 }
 
 impl Default for MemoryHistory {
@@ -60,6 +62,7 @@ impl MemoryHistory {
         Self {
             current_state: None,
             reviews: VecDeque::new(),
+            archived_reviews: VecDeque::new(),
         }
     }
 
@@ -85,11 +88,50 @@ impl MemoryHistory {
         &self.reviews
     }
 
+    /// Повторения, сохраненные при сбросе прогресса карты
+    pub fn archived_reviews(&self) -> &VecDeque<Review> {
+        &self.archived_reviews
+    }
+
     pub(crate) fn add_review(&mut self, memory_state: MemoryState, review: Review) {
         self.current_state = Some(memory_state);
         self.reviews.push_back(review);
     }
 
+    /// Устанавливает состояние памяти без добавления повторения (например, для уже известных карт)
+    pub(crate) fn set_memory_state(&mut self, memory_state: MemoryState) {
+        self.current_state = Some(memory_state);
+    }
+
+    /// Возвращает карту в состояние новой. Старые повторения либо архивируются, либо удаляются
+    pub(crate) fn reset(&mut self, archive_reviews: bool) {
+        self.current_state = None;
+
+        if archive_reviews {
+            self.archived_reviews.append(&mut self.reviews);
+        } else {
+            self.reviews.clear();
+        }
+    }
+
+    /// Объединяет истории двух карт одного слова. Повторения сливаются в хронологическом
+    /// порядке, а состояние памяти берется у истории с более поздним повторением
+    pub(crate) fn merge(&mut self, other: MemoryHistory) {
+        let other_is_newer = match (self.last_review_date(), other.last_review_date()) {
+            (Some(own), Some(other)) => other > own,
+            (None, Some(_)) => true,
+            (Some(_), None) => false,
+            (None, None) => self.current_state.is_none(),
+        };
+
+        if other_is_newer && other.current_state.is_some() {
+            self.current_state = other.current_state;
+        }
+
+        self.reviews = merge_reviews(&self.reviews, other.reviews);
+        self.archived_reviews = merge_reviews(&self.archived_reviews, other.archived_reviews);
+    }
+
     pub fn last_review_date(&self) -> Option<DateTime<Utc>> {
         self.reviews.back().map(|review| review.timestamp())
     }
@@ -137,6 +179,14 @@ impl MemoryHistory {
     }
 }
 
+fn merge_reviews(own: &VecDeque<Review>, other: VecDeque<Review>) -> VecDeque<Review> {
+    let mut reviews = own.iter().copied().chain(other).collect::<Vec<_>>();
+    reviews.sort_by_key(|review| (review.timestamp, review.id));
+    reviews.dedup_by_key(|review| review.id);
+
+    reviews.into()
+}
+
 impl fmt::Display for MemoryState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -181,3 +231,53 @@ impl Review {
         self.interval
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_state() -> MemoryState {
+        MemoryState::new(
+            Stability::new(5.0).unwrap(),
+            Difficulty::new(1.0).unwrap(),
+            Utc::now() + Duration::days(5),
+        )
+    }
+
+    fn reviewed_history() -> MemoryHistory {
+        let mut history = MemoryHistory::new();
+        history.add_review(memory_state(), Review::new(Rating::Good, Duration::days(1)));
+        history.add_review(memory_state(), Review::new(Rating::Easy, Duration::days(5)));
+        history
+    }
+
+    #[test]
+    fn reset_with_archive_moves_reviews_to_archive() {
+        // Arrange
+        let mut history = reviewed_history();
+        let reviews = history.reviews().clone();
+
+        // Act
+        history.reset(true);
+
+        // Assert
+        assert!(history.is_new());
+        assert!(history.memory_state().is_none());
+        assert!(history.reviews().is_empty());
+        assert_eq!(history.archived_reviews(), &reviews);
+    }
+
+    #[test]
+    fn reset_without_archive_drops_reviews() {
+        // Arrange
+        let mut history = reviewed_history();
+
+        // Act
+        history.reset(false);
+
+        // Assert
+        assert!(history.is_new());
+        assert!(history.reviews().is_empty());
+        assert!(history.archived_reviews().is_empty());
+    }
+}
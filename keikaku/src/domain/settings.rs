@@ -1,9 +1,19 @@
+use std::collections::BTreeSet;
+
 use serde::{Deserialize, Serialize};
 
+use crate::domain::value_objects::ReviewDirection;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct UserSettings {
     llm: LlmSettings,
     duolingo_jwt_token: Option<String>,
+    /// Направления повторения словарных карт по умолчанию
+    #[serde(default)]
+    vocabulary_review_directions: BTreeSet<ReviewDirection>,
+    /// Над какими кандзи показывать фуригану во время урока
+    #[serde(default)]
+    furigana_mode: FuriganaMode,
 }
 
 impl UserSettings {
@@ -11,6 +21,8 @@ impl UserSettings {
         Self {
             llm,
             duolingo_jwt_token,
+            vocabulary_review_directions: BTreeSet::new(),
+            furigana_mode: FuriganaMode::default(),
         }
     }
 
@@ -18,6 +30,8 @@ impl UserSettings {
         Self {
             duolingo_jwt_token: None,
             llm: LlmSettings::None,
+            vocabulary_review_directions: BTreeSet::new(),
+            furigana_mode: FuriganaMode::default(),
         }
     }
 
@@ -36,6 +50,37 @@ impl UserSettings {
     pub fn set_duolingo_jwt_token(&mut self, token: Option<String>) {
         self.duolingo_jwt_token = token;
     }
+
+    /// Направления повторения словарных карт. Если ничего не выбрано — только узнавание
+    pub fn vocabulary_review_directions(&self) -> BTreeSet<ReviewDirection> {
+        if self.vocabulary_review_directions.is_empty() {
+            return BTreeSet::from([ReviewDirection::Recognition]);
+        }
+
+        self.vocabulary_review_directions.clone()
+    }
+
+    pub fn set_vocabulary_review_directions(&mut self, directions: BTreeSet<ReviewDirection>) {
+        self.vocabulary_review_directions = directions;
+    }
+
+    pub fn furigana_mode(&self) -> FuriganaMode {
+        self.furigana_mode
+    }
+
+    pub fn set_furigana_mode(&mut self, mode: FuriganaMode) {
+        self.furigana_mode = mode;
+    }
+}
+
+/// Режим показа фуриганы
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FuriganaMode {
+    /// Над всеми кандзи
+    #[default]
+    All,
+    /// Только над кандзи, которые пользователь еще не выучил
+    UnknownKanji,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
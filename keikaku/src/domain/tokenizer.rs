@@ -1,4 +1,8 @@
-use crate::domain::KeikakuError;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::domain::{KeikakuError, japanese::kana_reading};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct TokenInfo {
@@ -6,7 +10,9 @@ pub struct TokenInfo {
     phonological_base_form: String,
     orthographic_surface_form: String,
     phonological_surface_form: String,
+    lemma_reading: String,
     part_of_speech: PartOfSpeech,
+    conjugation_type: String,
 }
 
 impl TokenInfo {
@@ -26,12 +32,32 @@ impl TokenInfo {
         &self.phonological_surface_form
     }
 
+    /// Чтение лексемы катаканой, как оно пишется: `オオキイ` для 大きい
+    pub fn lemma_reading(&self) -> &str {
+        &self.lemma_reading
+    }
+
+    /// Словарная форма хираганой с долгими гласными, как на письме: おおきい, а не おーきい
+    pub fn kana_base_form(&self) -> String {
+        kana_reading(&self.phonological_base_form, &self.lemma_reading)
+    }
+
+    /// Форма в тексте хираганой с долгими гласными, как на письме
+    pub fn kana_surface_form(&self) -> String {
+        kana_reading(&self.phonological_surface_form, &self.lemma_reading)
+    }
+
     pub fn part_of_speech(&self) -> &PartOfSpeech {
         &self.part_of_speech
     }
+
+    /// Тип спряжения из UniDic, например `五段-ラ行` или `下一段-バ行`. Пустой у неизменяемых слов
+    pub fn conjugation_type(&self) -> &str {
+        &self.conjugation_type
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize)]
 pub enum PartOfSpeech {
     Verb,        // Глагол
     Noun,        // Существительное
@@ -125,7 +151,23 @@ pub struct Tokenizer {
     tokenizer: lindera::tokenizer::Tokenizer,
 }
 
+static SHARED_TOKENIZER: OnceLock<Tokenizer> = OnceLock::new();
+
 impl Tokenizer {
+    /// Общий для процесса токенизатор: словарь UniDic загружается при первом вызове.
+    /// Если первые вызовы идут из нескольких потоков одновременно, лишние копии
+    /// словаря отбрасываются
+    pub fn shared() -> Result<&'static Self, KeikakuError> {
+        if let Some(tokenizer) = SHARED_TOKENIZER.get() {
+            return Ok(tokenizer);
+        }
+
+        let tokenizer = Self::new()?;
+        Ok(SHARED_TOKENIZER.get_or_init(|| tokenizer))
+    }
+
+    /// Новый токенизатор со своей копией словаря. Для повторных вызовов
+    /// лучше использовать `shared`
     pub fn new() -> Result<Self, KeikakuError> {
         let dictionary =
             lindera::dictionary::load_dictionary("embedded://unidic").map_err(|e| {
@@ -165,11 +207,19 @@ impl Tokenizer {
                     .get("phonological_surface_form")
                     .unwrap_or_default()
                     .to_string(),
+                lemma_reading: match token.get("reading").unwrap_or_default() {
+                    "*" => String::new(),
+                    reading => reading.to_string(),
+                },
                 part_of_speech: token
                     .get("part_of_speech")
                     .unwrap_or_default()
                     .parse()
                     .unwrap_or(PartOfSpeech::Unspecified),
+                conjugation_type: match token.get("conjugation_type").unwrap_or_default() {
+                    "*" => String::new(),
+                    conjugation_type => conjugation_type.to_string(),
+                },
             })
             .collect();
 
@@ -252,4 +302,44 @@ mod tests {
         assert_eq!(tokens[0].orthographic_surface_form, "たべ");
         assert_eq!(tokens[0].phonological_surface_form, "タベ");
     }
+
+    #[test]
+    fn should_spell_long_vowels_in_kana_forms() {
+        let tokenizer = Tokenizer::new().unwrap();
+        let tokens = tokenizer.tokenize("大きい").unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].phonological_base_form, "オーキー");
+        assert_eq!(tokens[0].kana_base_form(), "おおきい");
+        assert_eq!(tokens[0].kana_surface_form(), "おおきい");
+    }
+
+    #[test]
+    fn should_return_conjugation_type_for_verb() {
+        let tokenizer = Tokenizer::new().unwrap();
+        let tokens = tokenizer.tokenize("帰る").unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].conjugation_type(), "五段-ラ行");
+    }
+
+    #[test]
+    fn should_return_empty_conjugation_type_for_noun() {
+        let tokenizer = Tokenizer::new().unwrap();
+        let tokens = tokenizer.tokenize("食べ物").unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].conjugation_type(), "");
+    }
+
+    #[test]
+    fn should_reuse_shared_tokenizer() {
+        // Arrange
+        let first = Tokenizer::shared().unwrap();
+
+        // Act
+        let second = Tokenizer::shared().unwrap();
+        let tokens = second.tokenize("食べ物").unwrap();
+
+        // Assert
+        assert!(std::ptr::eq(first, second));
+        assert_eq!(tokens.len(), 1);
+    }
 }
@@ -0,0 +1,598 @@
+/// Знак после гласной с макроном или циркумфлексом (ō, ô)
+const LONG_VOWEL_MARK: char = '\u{0304}';
+
+/// Система латинизации
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RomajiSystem {
+    #[default]
+    Hepburn,
+    Kunrei,
+}
+
+/// Слоги: кана, запись по Хэпберну, запись по Кунрэй.
+/// При неоднозначной записи («ji», «zu», «o») выбирается первый слог в таблице
+const SYLLABLES: &[(&str, &str, &str)] = &[
+    ("あ", "a", "a"),
+    ("い", "i", "i"),
+    ("う", "u", "u"),
+    ("え", "e", "e"),
+    ("お", "o", "o"),
+    ("か", "ka", "ka"),
+    ("き", "ki", "ki"),
+    ("く", "ku", "ku"),
+    ("け", "ke", "ke"),
+    ("こ", "ko", "ko"),
+    ("が", "ga", "ga"),
+    ("ぎ", "gi", "gi"),
+    ("ぐ", "gu", "gu"),
+    ("げ", "ge", "ge"),
+    ("ご", "go", "go"),
+    ("さ", "sa", "sa"),
+    ("し", "shi", "si"),
+    ("す", "su", "su"),
+    ("せ", "se", "se"),
+    ("そ", "so", "so"),
+    ("ざ", "za", "za"),
+    ("じ", "ji", "zi"),
+    ("ず", "zu", "zu"),
+    ("ぜ", "ze", "ze"),
+    ("ぞ", "zo", "zo"),
+    ("た", "ta", "ta"),
+    ("ち", "chi", "ti"),
+    ("つ", "tsu", "tu"),
+    ("て", "te", "te"),
+    ("と", "to", "to"),
+    ("だ", "da", "da"),
+    ("ぢ", "ji", "zi"),
+    ("づ", "zu", "zu"),
+    ("で", "de", "de"),
+    ("ど", "do", "do"),
+    ("な", "na", "na"),
+    ("に", "ni", "ni"),
+    ("ぬ", "nu", "nu"),
+    ("ね", "ne", "ne"),
+    ("の", "no", "no"),
+    ("は", "ha", "ha"),
+    ("ひ", "hi", "hi"),
+    ("ふ", "fu", "hu"),
+    ("へ", "he", "he"),
+    ("ほ", "ho", "ho"),
+    ("ば", "ba", "ba"),
+    ("び", "bi", "bi"),
+    ("ぶ", "bu", "bu"),
+    ("べ", "be", "be"),
+    ("ぼ", "bo", "bo"),
+    ("ぱ", "pa", "pa"),
+    ("ぴ", "pi", "pi"),
+    ("ぷ", "pu", "pu"),
+    ("ぺ", "pe", "pe"),
+    ("ぽ", "po", "po"),
+    ("ま", "ma", "ma"),
+    ("み", "mi", "mi"),
+    ("む", "mu", "mu"),
+    ("め", "me", "me"),
+    ("も", "mo", "mo"),
+    ("や", "ya", "ya"),
+    ("ゆ", "yu", "yu"),
+    ("よ", "yo", "yo"),
+    ("ら", "ra", "ra"),
+    ("り", "ri", "ri"),
+    ("る", "ru", "ru"),
+    ("れ", "re", "re"),
+    ("ろ", "ro", "ro"),
+    ("わ", "wa", "wa"),
+    ("を", "o", "o"),
+    ("ゔ", "vu", "vu"),
+    ("きゃ", "kya", "kya"),
+    ("きゅ", "kyu", "kyu"),
+    ("きょ", "kyo", "kyo"),
+    ("ぎゃ", "gya", "gya"),
+    ("ぎゅ", "gyu", "gyu"),
+    ("ぎょ", "gyo", "gyo"),
+    ("しゃ", "sha", "sya"),
+    ("しゅ", "shu", "syu"),
+    ("しょ", "sho", "syo"),
+    ("じゃ", "ja", "zya"),
+    ("じゅ", "ju", "zyu"),
+    ("じょ", "jo", "zyo"),
+    ("ちゃ", "cha", "tya"),
+    ("ちゅ", "chu", "tyu"),
+    ("ちょ", "cho", "tyo"),
+    ("ぢゃ", "ja", "zya"),
+    ("ぢゅ", "ju", "zyu"),
+    ("ぢょ", "jo", "zyo"),
+    ("にゃ", "nya", "nya"),
+    ("にゅ", "nyu", "nyu"),
+    ("にょ", "nyo", "nyo"),
+    ("ひゃ", "hya", "hya"),
+    ("ひゅ", "hyu", "hyu"),
+    ("ひょ", "hyo", "hyo"),
+    ("びゃ", "bya", "bya"),
+    ("びゅ", "byu", "byu"),
+    ("びょ", "byo", "byo"),
+    ("ぴゃ", "pya", "pya"),
+    ("ぴゅ", "pyu", "pyu"),
+    ("ぴょ", "pyo", "pyo"),
+    ("みゃ", "mya", "mya"),
+    ("みゅ", "myu", "myu"),
+    ("みょ", "myo", "myo"),
+    ("りゃ", "rya", "rya"),
+    ("りゅ", "ryu", "ryu"),
+    ("りょ", "ryo", "ryo"),
+    // Сочетания из заимствованных слов
+    ("しぇ", "she", "she"),
+    ("じぇ", "je", "je"),
+    ("ちぇ", "che", "che"),
+    ("ふぁ", "fa", "fa"),
+    ("ふぃ", "fi", "fi"),
+    ("ふぇ", "fe", "fe"),
+    ("ふぉ", "fo", "fo"),
+    ("うぃ", "wi", "wi"),
+    ("うぇ", "we", "we"),
+    ("ゔぁ", "va", "va"),
+    ("ゔぃ", "vi", "vi"),
+    ("ゔぇ", "ve", "ve"),
+    ("ゔぉ", "vo", "vo"),
+    // Маленькая кана отдельно от слога
+    ("ぁ", "xa", "xa"),
+    ("ぃ", "xi", "xi"),
+    ("ぅ", "xu", "xu"),
+    ("ぇ", "xe", "xe"),
+    ("ぉ", "xo", "xo"),
+    ("ゃ", "xya", "xya"),
+    ("ゅ", "xyu", "xyu"),
+    ("ょ", "xyo", "xyo"),
+];
+
+/// Дополнительные способы набора, которые не используются при выводе латиницы
+const ROMAJI_ALIASES: &[(&str, &str)] = &[
+    ("wo", "を"),
+    ("di", "ぢ"),
+    ("du", "づ"),
+    ("dya", "ぢゃ"),
+    ("dyu", "ぢゅ"),
+    ("dyo", "ぢょ"),
+    ("jya", "じゃ"),
+    ("jyu", "じゅ"),
+    ("jyo", "じょ"),
+    ("cya", "ちゃ"),
+    ("cyu", "ちゅ"),
+    ("cyo", "ちょ"),
+    ("la", "ぁ"),
+    ("li", "ぃ"),
+    ("lu", "ぅ"),
+    ("le", "ぇ"),
+    ("lo", "ぉ"),
+    ("lya", "ゃ"),
+    ("lyu", "ゅ"),
+    ("lyo", "ょ"),
+    ("xtu", "っ"),
+    ("ltu", "っ"),
+    ("xtsu", "っ"),
+    ("ltsu", "っ"),
+];
+
+enum KanaUnit {
+    Syllable(&'static str),
+    Sokuon,
+    MoraicN,
+    LongVowel,
+    Other(char),
+}
+
+/// Переводит латиницу (Хэпберн или Кунрэй) в хирагану.
+///
+/// Удвоенная согласная дает «っ», «nn», «n'» и «n» перед согласной — «ん»,
+/// гласная с макроном или циркумфлексом — долгий слог («ō» — «おう»), «-» — «ー».
+/// Незавершенный слог остается латиницей, чтобы ввод можно было продолжить
+pub fn romaji_to_hiragana(input: &str) -> String {
+    romaji_to_kana(input, false, false)
+}
+
+/// Как `romaji_to_hiragana`, но ввод закончен: последняя «n» становится «ん».
+/// Нужна при отправке ответа, ведь «shashin» уже не продолжится слогом «na»
+pub fn finish_romaji_to_hiragana(input: &str) -> String {
+    romaji_to_kana(input, false, true)
+}
+
+/// Переводит латиницу в катакану. Долгие гласные записываются знаком «ー»
+pub fn romaji_to_katakana(input: &str) -> String {
+    romaji_to_kana(input, true, false)
+}
+
+/// Переводит хирагану и катакану в латиницу. Знак «ー» дает гласную с макроном
+/// по Хэпберну или с циркумфлексом по Кунрэй, остальные символы не меняются
+pub fn kana_to_romaji(input: &str, system: RomajiSystem) -> String {
+    let units = kana_units(&katakana_to_hiragana(input), system);
+    let mut result = String::with_capacity(input.len());
+
+    for (index, unit) in units.iter().enumerate() {
+        let next_romaji = match units.get(index + 1) {
+            Some(KanaUnit::Syllable(romaji)) => Some(*romaji),
+            _ => None,
+        };
+        let next = next_romaji.and_then(|romaji| romaji.chars().next());
+
+        match unit {
+            KanaUnit::Syllable(romaji) => result.push_str(romaji),
+            KanaUnit::Sokuon => {
+                // Перед «ch» по Хэпберну удваивается «t»: «matcha»
+                if let Some(consonant) = next.filter(|c| !is_romaji_vowel(*c)) {
+                    let is_ch = next_romaji.is_some_and(|romaji| romaji.starts_with("ch"));
+                    result.push(if is_ch { 't' } else { consonant });
+                }
+            }
+            KanaUnit::MoraicN => {
+                result.push('n');
+                if next.is_some_and(|c| is_romaji_vowel(c) || c == 'y') {
+                    result.push('\'');
+                }
+            }
+            KanaUnit::LongVowel => {
+                let long = result
+                    .chars()
+                    .last()
+                    .and_then(|vowel| long_romaji_vowel(vowel, system));
+                match long {
+                    Some(long) => {
+                        result.pop();
+                        result.push(long);
+                    }
+                    None => result.push('-'),
+                }
+            }
+            KanaUnit::Other(c) => result.push(*c),
+        }
+    }
+
+    result
+}
+
+/// Переводит хирагану в катакану, остальные символы не меняются
+pub fn hiragana_to_katakana(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{3041}'..='\u{3096}' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Переводит катакану в хирагану, остальные символы (в том числе «ー») не меняются
+pub fn katakana_to_hiragana(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{30A1}'..='\u{30F6}' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+fn romaji_to_kana(input: &str, katakana: bool, finished: bool) -> String {
+    let chars = romaji_chars(input);
+    let mut result = String::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if c == LONG_VOWEL_MARK {
+            let vowel = i.checked_sub(1).map(|prev| chars[prev]);
+            result.push(if katakana {
+                'ー'
+            } else {
+                vowel.map_or('ー', long_vowel_kana)
+            });
+            i += 1;
+            continue;
+        }
+
+        if !c.is_ascii_alphabetic() {
+            result.push(if c == '-' { 'ー' } else { c });
+            i += 1;
+            continue;
+        }
+
+        if c == 'n' {
+            match next {
+                // «nn» — «ん», если дальше не начинается слог на «n»
+                Some('n') => {
+                    result.push('ん');
+                    let starts_syllable = chars
+                        .get(i + 2)
+                        .is_some_and(|c| is_romaji_vowel(*c) || *c == 'y');
+                    i += if starts_syllable { 1 } else { 2 };
+                    continue;
+                }
+                Some(next) if !is_romaji_vowel(next) && next != 'y' => {
+                    result.push('ん');
+                    i += if next == '\'' { 2 } else { 1 };
+                    continue;
+                }
+                None if finished => {
+                    result.push('ん');
+                    i += 1;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        // Удвоенная согласная — «っ», в том числе «tch» по Хэпберну
+        if (next == Some(c) && !is_romaji_vowel(c) && c != 'n') || (c == 't' && next == Some('c')) {
+            result.push('っ');
+            i += 1;
+            continue;
+        }
+
+        let syllable = (1..=4).rev().find_map(|len| {
+            let end = i + len;
+            if end > chars.len() {
+                return None;
+            }
+
+            let romaji = chars[i..end].iter().collect::<String>();
+            romaji_syllable(&romaji).map(|kana| (kana, len))
+        });
+
+        match syllable {
+            Some((kana, len)) => {
+                result.push_str(kana);
+                i += len;
+            }
+            None => {
+                result.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    if katakana {
+        hiragana_to_katakana(&result)
+    } else {
+        result
+    }
+}
+
+/// Символы латиницы в нижнем регистре, гласные с макроном разделены на гласную и знак долготы
+fn romaji_chars(input: &str) -> Vec<char> {
+    let mut chars = Vec::with_capacity(input.len());
+
+    for c in input.to_lowercase().chars() {
+        match short_romaji_vowel(c) {
+            Some(vowel) => chars.extend([vowel, LONG_VOWEL_MARK]),
+            None => chars.push(c),
+        }
+    }
+
+    chars
+}
+
+fn kana_units(hiragana: &str, system: RomajiSystem) -> Vec<KanaUnit> {
+    let chars = hiragana.chars().collect::<Vec<_>>();
+    let mut units = Vec::with_capacity(chars.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let unit = match chars[i] {
+            'っ' => KanaUnit::Sokuon,
+            'ん' => KanaUnit::MoraicN,
+            'ー' => KanaUnit::LongVowel,
+            c => {
+                let syllable = (1..=2).rev().find_map(|len| {
+                    let end = i + len;
+                    if end > chars.len() {
+                        return None;
+                    }
+
+                    let kana = chars[i..end].iter().collect::<String>();
+                    kana_syllable(&kana, system).map(|romaji| (romaji, len))
+                });
+
+                if let Some((romaji, len)) = syllable {
+                    units.push(KanaUnit::Syllable(romaji));
+                    i += len;
+                    continue;
+                }
+
+                KanaUnit::Other(c)
+            }
+        };
+
+        units.push(unit);
+        i += 1;
+    }
+
+    units
+}
+
+fn romaji_syllable(romaji: &str) -> Option<&'static str> {
+    SYLLABLES
+        .iter()
+        .find(|(_, hepburn, kunrei)| *hepburn == romaji || *kunrei == romaji)
+        .map(|(kana, _, _)| *kana)
+        .or_else(|| {
+            ROMAJI_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == romaji)
+                .map(|(_, kana)| *kana)
+        })
+}
+
+fn kana_syllable(kana: &str, system: RomajiSystem) -> Option<&'static str> {
+    SYLLABLES
+        .iter()
+        .find(|(syllable, _, _)| *syllable == kana)
+        .map(|(_, hepburn, kunrei)| match system {
+            RomajiSystem::Hepburn => *hepburn,
+            RomajiSystem::Kunrei => *kunrei,
+        })
+}
+
+fn is_romaji_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+/// Кана, которой продлевается гласная: «ō» — «おう», как в словарных чтениях
+fn long_vowel_kana(vowel: char) -> char {
+    match vowel {
+        'a' => 'あ',
+        'i' => 'い',
+        'e' => 'え',
+        _ => 'う',
+    }
+}
+
+fn short_romaji_vowel(c: char) -> Option<char> {
+    let vowel = match c {
+        'ā' | 'â' => 'a',
+        'ī' | 'î' => 'i',
+        'ū' | 'û' => 'u',
+        'ē' | 'ê' => 'e',
+        'ō' | 'ô' => 'o',
+        _ => return None,
+    };
+
+    Some(vowel)
+}
+
+fn long_romaji_vowel(vowel: char, system: RomajiSystem) -> Option<char> {
+    let long = match (vowel, system) {
+        ('a', RomajiSystem::Hepburn) => 'ā',
+        ('i', RomajiSystem::Hepburn) => 'ī',
+        ('u', RomajiSystem::Hepburn) => 'ū',
+        ('e', RomajiSystem::Hepburn) => 'ē',
+        ('o', RomajiSystem::Hepburn) => 'ō',
+        ('a', RomajiSystem::Kunrei) => 'â',
+        ('i', RomajiSystem::Kunrei) => 'î',
+        ('u', RomajiSystem::Kunrei) => 'û',
+        ('e', RomajiSystem::Kunrei) => 'ê',
+        ('o', RomajiSystem::Kunrei) => 'ô',
+        _ => return None,
+    };
+
+    Some(long)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn romaji_converts_to_hiragana_while_typing() {
+        // Arrange
+        let inputs = [
+            ("taberu", "たべる"),
+            ("gakkou", "がっこう"),
+            ("konnichiha", "こんにちは"),
+            ("onna", "おんな"),
+            ("kin'ei", "きんえい"),
+            ("matcha", "まっちゃ"),
+            ("shashin", "しゃしn"),
+            ("tab", "たb"),
+        ];
+
+        // Act + Assert
+        for (romaji, hiragana) in inputs {
+            assert_eq!(romaji_to_hiragana(romaji), hiragana);
+        }
+    }
+
+    #[test]
+    fn finished_input_ends_with_moraic_n() {
+        // Arrange
+        let inputs = [
+            ("shashin", "しゃしん"),
+            ("しゃしn", "しゃしん"),
+            ("hon", "ほん"),
+            ("kana", "かな"),
+        ];
+
+        // Act + Assert
+        for (romaji, hiragana) in inputs {
+            assert_eq!(finish_romaji_to_hiragana(romaji), hiragana);
+        }
+    }
+
+    #[test]
+    fn kunrei_and_hepburn_romaji_give_same_kana() {
+        // Arrange
+        let pairs = [
+            ("shinbunsha", "sinbunsya"),
+            ("chotto", "tyotto"),
+            ("tsukue", "tukue"),
+            ("fuji", "huzi"),
+            ("jisho", "zisyo"),
+        ];
+
+        // Act + Assert
+        for (hepburn, kunrei) in pairs {
+            assert_eq!(romaji_to_hiragana(hepburn), romaji_to_hiragana(kunrei));
+        }
+    }
+
+    #[test]
+    fn long_vowels_convert_to_kana() {
+        // Arrange
+        let hepburn = "Tōkyō";
+        let kunrei = "tôkyô";
+        let katakana = "kōhī";
+
+        // Act + Assert
+        assert_eq!(romaji_to_hiragana(hepburn), "とうきょう");
+        assert_eq!(romaji_to_hiragana(kunrei), "とうきょう");
+        assert_eq!(romaji_to_katakana(katakana), "コーヒー");
+        assert_eq!(romaji_to_katakana("ko-hi-"), "コーヒー");
+    }
+
+    #[test]
+    fn kana_converts_to_hepburn_romaji() {
+        // Arrange
+        let inputs = [
+            ("たべる", "taberu"),
+            ("しゃしん", "shashin"),
+            ("きんえん", "kin'en"),
+            ("ほんや", "hon'ya"),
+            ("まっちゃ", "matcha"),
+            ("がっこう", "gakkou"),
+            ("ラーメン", "rāmen"),
+            ("ジュース", "jūsu"),
+        ];
+
+        // Act + Assert
+        for (kana, romaji) in inputs {
+            assert_eq!(kana_to_romaji(kana, RomajiSystem::Hepburn), romaji);
+        }
+    }
+
+    #[test]
+    fn kana_converts_to_kunrei_romaji() {
+        // Arrange
+        let inputs = [
+            ("しんぶん", "sinbun"),
+            ("ちょっと", "tyotto"),
+            ("つくえ", "tukue"),
+            ("ふじ", "huzi"),
+            ("ラーメン", "râmen"),
+        ];
+
+        // Act + Assert
+        for (kana, romaji) in inputs {
+            assert_eq!(kana_to_romaji(kana, RomajiSystem::Kunrei), romaji);
+        }
+    }
+
+    #[test]
+    fn hiragana_and_katakana_convert_both_ways() {
+        // Arrange
+        let hiragana = "たべもの";
+        let katakana = "タベモノ";
+
+        // Act + Assert
+        assert_eq!(hiragana_to_katakana(hiragana), katakana);
+        assert_eq!(katakana_to_hiragana(katakana), hiragana);
+        assert_eq!(katakana_to_hiragana("コーヒー"), "こーひー");
+        assert_eq!(hiragana_to_katakana("日本ごabc"), "日本ゴabc");
+    }
+}
@@ -0,0 +1,296 @@
+use chrono::Duration;
+
+use crate::domain::{
+    KeikakuError, Rating,
+    japanese::{IsJapanese, IsJapaneseText},
+    tokenizer::{TokenInfo, Tokenizer},
+};
+
+/// Ответ быстрее этого времени считается легким
+const EASY_ANSWER_SECONDS: i64 = 5;
+/// Ответ дольше этого времени считается трудным
+const HARD_ANSWER_SECONDS: i64 = 15;
+
+/// Результат проверки введенного ответа
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnswerCheck {
+    is_correct: bool,
+    expected: Vec<String>,
+}
+
+impl AnswerCheck {
+    pub fn is_correct(&self) -> bool {
+        self.is_correct
+    }
+
+    /// Принимаемые варианты ответа
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+
+    /// Оценка по правильности и времени ответа
+    pub fn suggested_rating(&self, answer_time: Duration) -> Rating {
+        if !self.is_correct {
+            Rating::Again
+        } else if answer_time < Duration::seconds(EASY_ANSWER_SECONDS) {
+            Rating::Easy
+        } else if answer_time <= Duration::seconds(HARD_ANSWER_SECONDS) {
+            Rating::Good
+        } else {
+            Rating::Hard
+        }
+    }
+}
+
+/// Проверяет чтение слова. Принимается сохраненное чтение и чтение из словаря токенизатора,
+/// хирагана и катакана считаются одинаковыми
+pub fn check_reading(
+    word: &str,
+    reading: Option<&str>,
+    answer: &str,
+) -> Result<AnswerCheck, KeikakuError> {
+    let tokenizer = Tokenizer::shared()?;
+    let mut expected = reading.map(normalize).into_iter().collect::<Vec<_>>();
+
+    let dictionary_reading = text_reading(tokenizer, word)?;
+    if !expected.contains(&dictionary_reading) {
+        expected.push(dictionary_reading);
+    }
+
+    let is_correct = expected.contains(&normalize(answer));
+
+    Ok(AnswerCheck {
+        is_correct,
+        expected,
+    })
+}
+
+/// Проверяет написание слова. Допускается другая окуригана: те же кандзи и то же чтение
+pub fn check_word(word: &str, answer: &str) -> Result<AnswerCheck, KeikakuError> {
+    let expected = vec![word.to_string()];
+    let answer = answer.trim();
+
+    if normalize(answer) == normalize(word) {
+        return Ok(AnswerCheck {
+            is_correct: true,
+            expected,
+        });
+    }
+
+    let tokenizer = Tokenizer::shared()?;
+    let is_correct = !answer.is_empty()
+        && word.contains_kanji()
+        && kanji_of(answer) == kanji_of(word)
+        && surface_reading(tokenizer, answer)? == surface_reading(tokenizer, word)?;
+
+    Ok(AnswerCheck {
+        is_correct,
+        expected,
+    })
+}
+
+/// Проверяет форму слова в упражнении на спряжение. Подходит любой из вариантов,
+/// ответ можно написать каной
+pub fn check_conjugation(expected: &[String], answer: &str) -> Result<AnswerCheck, KeikakuError> {
+    let answer = answer.trim();
+    let is_correct = if expected
+        .iter()
+        .any(|variant| normalize(answer) == normalize(variant))
+    {
+        true
+    } else if answer.is_empty() {
+        false
+    } else {
+        let tokenizer = Tokenizer::shared()?;
+        let answer_reading = text_reading(tokenizer, answer)?;
+        expected
+            .iter()
+            .map(|variant| text_reading(tokenizer, variant))
+            .collect::<Result<Vec<_>, _>>()?
+            .contains(&answer_reading)
+    };
+
+    Ok(AnswerCheck {
+        is_correct,
+        expected: expected.to_vec(),
+    })
+}
+
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_hiragana()
+}
+
+fn kanji_of(text: &str) -> String {
+    text.chars().filter(|c| c.is_kanji()).collect()
+}
+
+/// Чтение текста хираганой. Для одного слова берется чтение словарной формы
+fn text_reading(tokenizer: &Tokenizer, text: &str) -> Result<String, KeikakuError> {
+    let tokens = tokenizer.tokenize(text.trim())?;
+
+    if let [token] = tokens.as_slice() {
+        let reading =
+            reading_or_surface(&token.kana_base_form(), token.orthographic_surface_form());
+        return Ok(normalize(reading));
+    }
+
+    Ok(tokens_reading(&tokens))
+}
+
+/// Чтение текста хираганой в том виде, как он написан: формы слов не приводятся
+/// к словарной, поэтому 食べ и 食べる читаются по-разному
+fn surface_reading(tokenizer: &Tokenizer, text: &str) -> Result<String, KeikakuError> {
+    Ok(tokens_reading(&tokenizer.tokenize(text.trim())?))
+}
+
+fn tokens_reading(tokens: &[TokenInfo]) -> String {
+    let reading = tokens
+        .iter()
+        .map(|token| {
+            reading_or_surface(
+                &token.kana_surface_form(),
+                token.orthographic_surface_form(),
+            )
+            .to_string()
+        })
+        .collect::<String>();
+
+    normalize(&reading)
+}
+
+fn reading_or_surface<'a>(reading: &'a str, surface: &'a str) -> &'a str {
+    if reading.is_empty() || reading == "*" {
+        surface
+    } else {
+        reading
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reading_accepts_katakana_answer() {
+        // Arrange
+        let word = "食べる";
+
+        // Act
+        let check = check_reading(word, Some("たべる"), "タベル").unwrap();
+
+        // Assert
+        assert!(check.is_correct());
+        assert_eq!(check.expected(), ["たべる"]);
+    }
+
+    #[test]
+    fn reading_accepts_long_vowels_spelled_as_written() {
+        // Arrange
+        let words = [
+            ("大きい", "おおきい"),
+            ("遠い", "とおい"),
+            ("通る", "とおる"),
+        ];
+
+        // Act + Assert
+        for (word, answer) in words {
+            let check = check_reading(word, None, answer).unwrap();
+            assert!(check.is_correct(), "{word}: {:?}", check.expected());
+        }
+    }
+
+    #[test]
+    fn reading_rejects_wrong_answer() {
+        // Arrange
+        let word = "食べる";
+
+        // Act
+        let check = check_reading(word, None, "のむ").unwrap();
+
+        // Assert
+        assert!(!check.is_correct());
+    }
+
+    #[test]
+    fn word_accepts_alternative_okurigana() {
+        // Arrange
+        let word = "受付";
+
+        // Act
+        let check = check_word(word, "受け付け").unwrap();
+
+        // Assert
+        assert!(check.is_correct());
+    }
+
+    #[test]
+    fn word_rejects_other_form_of_the_word() {
+        // Arrange
+        let word = "食べる";
+
+        // Act
+        let stem = check_word(word, "食べ").unwrap();
+        let imperative = check_word(word, "食べろ").unwrap();
+
+        // Assert
+        assert!(!stem.is_correct());
+        assert!(!imperative.is_correct());
+    }
+
+    #[test]
+    fn conjugation_accepts_kana_answer() {
+        // Arrange
+        let expected = ["食べたいです".to_string()];
+
+        // Act
+        let kana = check_conjugation(&expected, "たべたいです").unwrap();
+        let wrong = check_conjugation(&expected, "たべます").unwrap();
+
+        // Assert
+        assert!(kana.is_correct());
+        assert!(!wrong.is_correct());
+    }
+
+    #[test]
+    fn conjugation_accepts_any_variant() {
+        // Arrange
+        let expected = ["食べている".to_string(), "食べています".to_string()];
+
+        // Act
+        let plain = check_conjugation(&expected, "食べている").unwrap();
+        let polite = check_conjugation(&expected, "たべています").unwrap();
+
+        // Assert
+        assert!(plain.is_correct());
+        assert!(polite.is_correct());
+        assert_eq!(polite.expected(), expected);
+    }
+
+    #[test]
+    fn suggested_rating_depends_on_correctness_and_time() {
+        // Arrange
+        let correct = AnswerCheck {
+            is_correct: true,
+            expected: vec![],
+        };
+        let wrong = AnswerCheck {
+            is_correct: false,
+            expected: vec![],
+        };
+
+        // Act + Assert
+        assert_eq!(correct.suggested_rating(Duration::seconds(2)), Rating::Easy);
+        assert_eq!(
+            correct.suggested_rating(Duration::seconds(10)),
+            Rating::Good
+        );
+        assert_eq!(
+            correct.suggested_rating(Duration::seconds(30)),
+            Rating::Hard
+        );
+        assert_eq!(wrong.suggested_rating(Duration::seconds(2)), Rating::Again);
+    }
+}
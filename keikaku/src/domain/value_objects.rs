@@ -58,6 +58,28 @@ pub enum Rating {
     Again,
 }
 
+/// Что проверяется при повторении карточки
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum ReviewDirection {
+    /// Видно слово, нужно вспомнить значение
+    #[default]
+    Recognition,
+    /// Видно значение, нужно вспомнить слово
+    Production,
+    /// Видно слово с кандзи, нужно вспомнить чтение каной
+    Reading,
+}
+
+impl ReviewDirection {
+    pub const ALL: [ReviewDirection; 3] = [
+        ReviewDirection::Recognition,
+        ReviewDirection::Production,
+        ReviewDirection::Reading,
+    ];
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Stability {
     value: f64,
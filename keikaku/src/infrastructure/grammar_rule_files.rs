@@ -0,0 +1,49 @@
+use std::fs;
+use std::path::Path;
+
+use crate::domain::KeikakuError;
+use crate::domain::grammar::declarative::DeclarativeRule;
+
+/// Читает пользовательские правила грамматики из `*.toml` файлов каталога.
+///
+/// Отсутствующий каталог означает, что своих правил нет. Файлы с ошибками
+/// и правила, не сходящиеся со своими примерами, пропускаются,
+/// чтобы одно неверное правило не ломало остальные
+pub fn load_grammar_rule_files(dir: &Path) -> Result<Vec<DeclarativeRule>, KeikakuError> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = fs::read_dir(dir)
+        .map_err(|e| KeikakuError::SettingsError {
+            reason: format!(
+                "Failed to read grammar rules directory {}: {}",
+                dir.display(),
+                e
+            ),
+        })?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "toml")
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut rules = Vec::new();
+    for path in paths {
+        let rule = fs::read_to_string(&path)
+            .map_err(|e| KeikakuError::SettingsError {
+                reason: e.to_string(),
+            })
+            .and_then(|text| DeclarativeRule::from_toml(&text))
+            .and_then(|rule| rule.check_examples().map(|_| rule));
+
+        match rule {
+            Ok(rule) => rules.push(rule),
+            Err(e) => tracing::error!("Failed to load grammar rule {}: {}", path.display(), e),
+        }
+    }
+
+    Ok(rules)
+}
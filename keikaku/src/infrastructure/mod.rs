@@ -1,10 +1,12 @@
 pub mod duolingo_client;
+pub mod grammar_rule_files;
 pub mod llm;
 pub mod migii;
 pub mod srs;
 pub mod user_repository;
 
 pub use duolingo_client::HttpDuolingoClient;
+pub use grammar_rule_files::load_grammar_rule_files;
 pub use llm::GeminiLlm;
 pub use llm::LlmServiceInvoker;
 pub use llm::OpenAiLlm;
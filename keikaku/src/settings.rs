@@ -2,10 +2,11 @@ use std::path::PathBuf;
 use std::sync::{Arc, LazyLock};
 
 use crate::application::UserRepository;
+use crate::domain::grammar::register_user_grammar_rules;
 use crate::domain::{KeikakuError, LlmSettings};
 use crate::infrastructure::{
     EmbeddedMigiiClient, FileSystemUserRepository, FsrsSrsService, GeminiLlm, LlmServiceInvoker,
-    OpenAiLlm,
+    OpenAiLlm, load_grammar_rule_files,
 };
 use tokio::sync::OnceCell;
 
@@ -104,6 +105,13 @@ impl ApplicationEnvironment {
             .await
     }
 
+    /// Подключает пользовательские правила грамматики из `~/.keikaku/grammar/*.toml`.
+    /// Вызывается при запуске, до первого обращения к правилам
+    pub fn load_user_grammar_rules(&self) -> Result<usize, KeikakuError> {
+        let rules = load_grammar_rule_files(&expand_tilde().join("grammar"))?;
+        register_user_grammar_rules(rules)
+    }
+
     pub fn get() -> &'static ApplicationEnvironment {
         &SETTINGS
     }
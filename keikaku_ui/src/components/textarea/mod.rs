@@ -1 +1,2 @@
 mod component;
+pub use component::*;
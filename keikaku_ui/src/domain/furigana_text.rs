@@ -1,14 +1,45 @@
+use std::collections::HashSet;
+
 use dioxus::prelude::*;
 use keikaku::domain::japanese::IsJapaneseText;
 
+/// Знакомые пользователю кандзи, над которыми `FuriganaText` не показывает фуригану.
+/// Пока кандзи не загружены, а также в режиме `None`, фуригана над всеми кандзи
+#[derive(Clone, Copy)]
+pub struct KnownKanji(pub Resource<Result<Option<HashSet<char>>, String>>);
+
 #[component]
 pub fn FuriganaText(text: String, show_furigana: bool, class: Option<String>) -> Element {
     let class_str = class.unwrap_or_else(|| "inline-block".to_string());
+    let known_kanji = try_use_context::<KnownKanji>();
+
+    let spans = if !show_furigana {
+        Vec::new()
+    } else if let Some(KnownKanji(known_kanji)) = known_kanji {
+        match &*known_kanji.read() {
+            Some(Ok(Some(kanji))) => text.unknown_furigana_spans(kanji).unwrap_or_default(),
+            _ => text.furigana_spans().unwrap_or_default(),
+        }
+    } else {
+        text.furigana_spans().unwrap_or_default()
+    };
 
-    if show_furigana && text.has_furigana().unwrap_or(false) {
-        let furigana_html = text.as_furigana().unwrap_or_default();
+    if spans.iter().any(|span| span.reading().is_some()) {
         rsx! {
-            span { class: "{class_str}", dangerous_inner_html: "{furigana_html}" }
+            span { class: "{class_str}",
+                for span in spans {
+                    if let Some(reading) = span.reading() {
+                        ruby {
+                            "{span.text()}"
+                            rp { "(" }
+                            rt { "{reading}" }
+                            rp { ")" }
+                        }
+                    } else {
+                        "{span.text()}"
+                    }
+                }
+            }
         }
     } else {
         rsx! {
@@ -3,7 +3,7 @@
 //! They can use UI components but should not depend on external services.
 
 mod furigana_text;
-pub use furigana_text::FuriganaText;
+pub use furigana_text::{FuriganaText, KnownKanji};
 
 mod word_card;
 pub use word_card::WordCard;
@@ -25,3 +25,6 @@ pub use popular_word_card::PopularWordCard;
 
 mod popular_words_grid;
 pub use popular_words_grid::PopularWordsGrid;
+
+mod review_directions;
+pub use review_directions::ReviewDirectionsField;
@@ -0,0 +1,53 @@
+use std::collections::BTreeSet;
+
+use dioxus::prelude::*;
+use keikaku::domain::value_objects::ReviewDirection;
+
+use crate::components::switch::{Switch, SwitchThumb};
+
+fn review_direction_label(direction: ReviewDirection) -> &'static str {
+    match direction {
+        ReviewDirection::Recognition => "Слово → значение",
+        ReviewDirection::Production => "Значение → слово",
+        ReviewDirection::Reading => "Кандзи → чтение",
+    }
+}
+
+/// Переключатели направлений повторения. Последнее направление выключить нельзя
+#[component]
+pub fn ReviewDirectionsField(
+    directions: BTreeSet<ReviewDirection>,
+    on_change: EventHandler<BTreeSet<ReviewDirection>>,
+    #[props(default = false)] disabled: bool,
+) -> Element {
+    rsx! {
+        div { class: "space-y-3",
+            for direction in ReviewDirection::ALL {
+                div {
+                    key: "{direction:?}",
+                    class: "flex items-center justify-between gap-4",
+                    span { class: "text-sm font-medium", "{review_direction_label(direction)}" }
+                    Switch {
+                        aria_label: review_direction_label(direction),
+                        checked: directions.contains(&direction),
+                        disabled: disabled
+                            || (directions.len() == 1 && directions.contains(&direction)),
+                        on_checked_change: {
+                            let directions = directions.clone();
+                            move |v: bool| {
+                                let mut directions = directions.clone();
+                                if v {
+                                    directions.insert(direction);
+                                } else {
+                                    directions.remove(&direction);
+                                }
+                                on_change.call(directions);
+                            }
+                        },
+                        SwitchThumb {}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -11,7 +11,7 @@ use keikaku::{
     settings::ApplicationEnvironment,
 };
 
-use views::{Cards, Import, Kanji, Learn, Navbar, Overview, Profile};
+use views::{Cards, Grammar, Import, Kanji, Learn, Navbar, Overview, Placement, Profile};
 
 pub const DEFAULT_USERNAME: &str = "yurvon_screamo";
 
@@ -50,6 +50,10 @@ const UI_UTILITIES_CSS: Asset = asset!("/assets/styles/utilities.css");
 
 fn main() {
     dioxus_logger::init(Level::INFO).expect("failed to init logger");
+    // Свои правила нужно подключить до первого обращения к списку правил
+    if let Err(e) = ApplicationEnvironment::get().load_user_grammar_rules() {
+        dioxus_logger::tracing::error!("Failed to load user grammar rules: {e}");
+    }
     dioxus::launch(App);
 }
 
@@ -67,8 +71,12 @@ enum Route {
         Cards {},
         #[route("/kanji")]
         Kanji {},
+        #[route("/grammar")]
+        Grammar {},
         #[route("/profile")]
         Profile {},
+        #[route("/placement")]
+        Placement {},
 }
 
 #[component]
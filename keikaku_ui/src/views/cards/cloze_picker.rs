@@ -0,0 +1,118 @@
+use dioxus::prelude::*;
+use keikaku::application::use_cases::create_sentence_card::{
+    CreateSentenceCardUseCase, SentenceClozeCandidate,
+};
+use keikaku::domain::knowledge::ClozeDeletion;
+use keikaku::settings::ApplicationEnvironment;
+
+use crate::components::button::{Button, ButtonVariant};
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+
+/// Выбор пропусков в предложении из слов, найденных токенизатором
+#[component]
+pub fn ClozePicker(sentence: String, clozes: Signal<Vec<ClozeDeletion>>) -> Element {
+    let mut candidates = use_signal(|| None::<(String, Vec<SentenceClozeCandidate>)>);
+    let mut loading = use_signal(|| false);
+
+    let sentence = sentence.trim().to_string();
+    // Разбор устаревает, как только меняется текст предложения
+    let current = candidates
+        .read()
+        .as_ref()
+        .filter(|(parsed, _)| *parsed == sentence)
+        .map(|(_, candidates)| candidates.clone());
+
+    rsx! {
+        div { class: "space-y-2",
+            div { class: "flex items-center justify-between gap-4",
+                span { class: "text-sm font-medium", "Пропуски" }
+                Button {
+                    variant: ButtonVariant::Outline,
+                    disabled: loading() || sentence.is_empty(),
+                    onclick: {
+                        let sentence = sentence.clone();
+                        move |_| {
+                            let sentence = sentence.clone();
+                            spawn(async move {
+                                loading.set(true);
+                                match fetch_candidates(sentence.clone()).await {
+                                    Ok(found) => candidates.set(Some((sentence, found))),
+                                    Err(e) => error!("Failed to propose clozes: {e}"),
+                                }
+                                loading.set(false);
+                            });
+                        }
+                    },
+                    {if loading() { "Разбор..." } else { "Предложить" }}
+                }
+            }
+
+            if let Some(current) = current {
+                if current.is_empty() {
+                    p { class: "text-xs text-muted-foreground", "В предложении не найдено слов" }
+                }
+                div { class: "flex flex-wrap gap-2",
+                    for item in current.iter() {
+                        ClozeCandidateButton {
+                            key: "{item.candidate.deletion().start()}",
+                            item: item.clone(),
+                            clozes,
+                        }
+                    }
+                }
+                p { class: "text-xs text-muted-foreground",
+                    "Отмеченные слова будут скрыты в вопросе. «✓» — слово уже есть в карточках"
+                }
+            } else if !clozes.read().is_empty() {
+                p { class: "text-xs text-muted-foreground",
+                    "Пропусков: {clozes.read().len()}. Нажмите «Предложить», чтобы изменить"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ClozeCandidateButton(
+    item: SentenceClozeCandidate,
+    clozes: Signal<Vec<ClozeDeletion>>,
+) -> Element {
+    let deletion = item.candidate.deletion();
+    let selected = clozes.read().contains(&deletion);
+    let variant = if selected {
+        ButtonVariant::Primary
+    } else {
+        ButtonVariant::Outline
+    };
+    let label = if item.vocabulary_card_id.is_some() {
+        format!("{} ✓", item.candidate.surface())
+    } else {
+        item.candidate.surface().to_string()
+    };
+
+    rsx! {
+        Button {
+            variant,
+            onclick: move |_| {
+                let mut clozes = clozes;
+                if selected {
+                    clozes.write().retain(|cloze| *cloze != deletion);
+                } else {
+                    clozes.write().push(deletion);
+                }
+            },
+            "{label}"
+        }
+    }
+}
+
+async fn fetch_candidates(sentence: String) -> Result<Vec<SentenceClozeCandidate>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    CreateSentenceCardUseCase::new(repo)
+        .propose_clozes(user_id, &sentence)
+        .await
+        .map_err(to_error)
+}
@@ -1,10 +1,17 @@
 use dioxus::prelude::*;
+use keikaku::application::use_cases::preview_vocabulary_cards::VocabularyCandidate;
+use keikaku::domain::knowledge::ClozeDeletion;
+use std::collections::BTreeSet;
 
 use crate::components::button::{Button, ButtonVariant};
 use crate::components::input::Input;
 use crate::components::sheet::{
     Sheet, SheetContent, SheetFooter, SheetHeader, SheetSide, SheetTitle,
 };
+use crate::components::switch::{Switch, SwitchThumb};
+use crate::components::textarea::Textarea;
+use crate::views::cards::cloze_picker::ClozePicker;
+use crate::views::cards::vocabulary_preview::VocabularyPreview;
 use keikaku::settings::ApplicationEnvironment;
 
 #[component]
@@ -15,6 +22,13 @@ pub fn CreateModal(
     loading: bool,
 ) -> Element {
     let question = use_signal(String::new);
+    let mut sentence_mode = use_signal(|| false);
+    let translation = use_signal(String::new);
+    let mut clozes = use_signal(Vec::<ClozeDeletion>::new);
+    // Слова текста: первое нажатие разбирает текст, второе создает отмеченные карточки
+    let mut preview = use_signal(|| None::<Vec<VocabularyCandidate>>);
+    let mut selected = use_signal(BTreeSet::<String>::new);
+    let mut previewing = use_signal(|| false);
 
     rsx! {
         Sheet {
@@ -30,17 +44,60 @@ pub fn CreateModal(
                 }
 
                 div { class: "space-y-4",
+                    div { class: "flex items-center justify-between gap-4",
+                        span { class: "text-sm font-medium", "Карточка предложения" }
+                        Switch {
+                            aria_label: "Карточка предложения",
+                            checked: sentence_mode(),
+                            on_checked_change: move |v| sentence_mode.set(v),
+                            SwitchThumb {}
+                        }
+                    }
+
                     div { class: "space-y-2",
-                        label { class: "text-sm font-medium", "Вопрос" }
+                        label { class: "text-sm font-medium",
+                            if sentence_mode() {
+                                "Предложение"
+                            } else {
+                                "Вопрос"
+                            }
+                        }
                         Input {
                             placeholder: "Введите текст...",
                             value: question(),
                             oninput: {
                                 let mut question = question;
-                                move |e: FormEvent| question.set(e.value())
+                                move |e: FormEvent| {
+                                    question.set(e.value());
+                                    // Пропуски привязаны к позициям в старом тексте
+                                    clozes.set(Vec::new());
+                                    preview.set(None);
+                                }
                             },
                         }
                     }
+
+                    if sentence_mode() {
+                        div { class: "space-y-2",
+                            label { class: "text-sm font-medium", "Перевод" }
+                            Textarea {
+                                rows: 3,
+                                value: translation(),
+                                oninput: {
+                                    let mut translation = translation;
+                                    move |e: FormEvent| translation.set(e.value())
+                                },
+                            }
+                        }
+
+                        ClozePicker { sentence: question(), clozes }
+                    }
+
+                    if !sentence_mode() {
+                        if let Some(candidates) = preview() {
+                            VocabularyPreview { candidates, selected }
+                        }
+                    }
                 }
 
                 SheetFooter {
@@ -51,19 +108,55 @@ pub fn CreateModal(
                     }
                     Button {
                         variant: ButtonVariant::Primary,
-                        disabled: loading,
+                        disabled: loading || previewing() || (preview().is_some() && selected().is_empty()),
                         onclick: move |_| {
                             let q = question();
                             if q.trim().is_empty() {
                                 return;
                             }
 
+                            if !sentence_mode() && preview().is_none() {
+                                spawn(async move {
+                                    previewing.set(true);
+                                    match preview_cards(q).await {
+                                        Ok(candidates) => {
+                                            // Слова, которые уже есть среди карточек, по умолчанию не отмечены
+                                            selected
+                                                .set(
+                                                    candidates
+                                                        .iter()
+                                                        .filter(|candidate| candidate.existing_cards.is_empty())
+                                                        .map(|candidate| candidate.base_form.clone())
+                                                        .collect(),
+                                                );
+                                            preview.set(Some(candidates));
+                                        }
+                                        Err(e) => on_error.call(format!("Ошибка: {}", e)),
+                                    }
+                                    previewing.set(false);
+                                });
+                                return;
+                            }
+
+                            let sentence = sentence_mode().then(|| (translation(), clozes()));
+                            let candidates = preview()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|candidate| selected().contains(&candidate.base_form))
+                                .collect::<Vec<_>>();
                             let on_success = on_success;
                             let on_error = on_error;
                             spawn(async move {
-                                match create_card(q).await {
+                                let result = match sentence {
+                                    Some((translation, clozes)) => {
+                                        create_sentence_card(q, translation, clozes).await
+                                    }
+                                    None => create_cards(candidates).await,
+                                };
+                                match result {
                                     Ok(_) => {
-                                        on_success.call("Карточка создана".to_string());
+                                        preview.set(None);
+                                        on_success.call("Карточки созданы".to_string());
                                     }
                                     Err(e) => {
                                         on_error.call(format!("Ошибка: {}", e));
@@ -71,7 +164,17 @@ pub fn CreateModal(
                                 }
                             });
                         },
-                        {if loading { "Создание..." } else { "Создать" }}
+                        {
+                            if loading {
+                                "Создание..."
+                            } else if previewing() {
+                                "Разбор..."
+                            } else if sentence_mode() || preview().is_some() {
+                                "Создать"
+                            } else {
+                                "Далее"
+                            }
+                        }
                     }
                 }
             }
@@ -79,7 +182,21 @@ pub fn CreateModal(
     }
 }
 
-async fn create_card(question: String) -> Result<(), String> {
+async fn preview_cards(question: String) -> Result<Vec<VocabularyCandidate>, String> {
+    use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+    use keikaku::application::use_cases::preview_vocabulary_cards::PreviewVocabularyCardsUseCase;
+
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    PreviewVocabularyCardsUseCase::new(repo)
+        .execute(user_id, &question)
+        .await
+        .map_err(to_error)
+}
+
+async fn create_cards(candidates: Vec<VocabularyCandidate>) -> Result<(), String> {
     use crate::{DEFAULT_USERNAME, ensure_user, to_error};
     use keikaku::application::use_cases::create_vocabulary_card::CreateVocabularyCardUseCase;
 
@@ -89,7 +206,27 @@ async fn create_card(question: String) -> Result<(), String> {
     let llm_service = env.get_llm_service(user_id).await.map_err(to_error)?;
 
     CreateVocabularyCardUseCase::new(repo, &llm_service)
-        .execute(user_id, question)
+        .execute_candidates(user_id, candidates)
+        .await
+        .map_err(to_error)?;
+
+    Ok(())
+}
+
+async fn create_sentence_card(
+    sentence: String,
+    translation: String,
+    clozes: Vec<ClozeDeletion>,
+) -> Result<(), String> {
+    use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+    use keikaku::application::use_cases::create_sentence_card::CreateSentenceCardUseCase;
+
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    CreateSentenceCardUseCase::new(repo)
+        .execute(user_id, sentence, translation, clozes)
         .await
         .map_err(to_error)?;
 
@@ -0,0 +1,182 @@
+use dioxus::prelude::*;
+use keikaku::application::use_cases::{
+    find_duplicate_cards::FindDuplicateCardsUseCase, merge_cards::MergeCardsUseCase,
+};
+use keikaku::domain::knowledge::{Card, StudyCard};
+use ulid::Ulid;
+
+use crate::components::app_ui::{InfoSection, LoadingState};
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::sheet::{
+    Sheet, SheetContent, SheetFooter, SheetHeader, SheetSide, SheetTitle,
+};
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+use keikaku::settings::ApplicationEnvironment;
+
+#[derive(Clone, PartialEq)]
+struct DuplicateCard {
+    id: String,
+    question: String,
+    answer: String,
+    reviews_count: usize,
+}
+
+/// Отчет о вероятных дубликатах с возможностью слить группу в одну карточку
+#[component]
+pub fn DuplicatesModal(
+    on_close: EventHandler<()>,
+    on_success: EventHandler<String>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let mut groups = use_resource(fetch_duplicate_groups);
+    let mut merging = use_signal(|| false);
+
+    let content = match groups.read().as_ref() {
+        None => rsx! {
+            LoadingState { message: Some("Поиск дубликатов...".to_string()) }
+        },
+        Some(Err(e)) => rsx! {
+            p { class: "text-sm text-muted-foreground", "Ошибка: {e}" }
+        },
+        Some(Ok(found)) if found.is_empty() => rsx! {
+            p { class: "text-sm text-muted-foreground", "Дубликатов не найдено" }
+        },
+        Some(Ok(found)) => rsx! {
+            p { class: "text-xs text-muted-foreground",
+                "Карточки совпадают по словарной форме и чтению. Оставленная карточка заберет историю повторений, теги и заметки остальных"
+            }
+            for group in found.iter() {
+                InfoSection {
+                    key: "{group[0].id}",
+                    title: group[0].question.clone(),
+                    for card in group.iter() {
+                        div {
+                            key: "{card.id}",
+                            class: "flex items-center justify-between gap-4",
+                            div { class: "flex flex-col",
+                                span { class: "text-sm font-medium", "{card.question}" }
+                                span { class: "text-xs text-muted-foreground",
+                                    "{card.answer} · повторений: {card.reviews_count}"
+                                }
+                            }
+                            Button {
+                                variant: ButtonVariant::Outline,
+                                disabled: merging(),
+                                onclick: {
+                                    let target_id = card.id.clone();
+                                    let source_ids = group
+                                        .iter()
+                                        .filter(|other| other.id != card.id)
+                                        .map(|other| other.id.clone())
+                                        .collect::<Vec<_>>();
+                                    move |_| {
+                                        let target_id = target_id.clone();
+                                        let source_ids = source_ids.clone();
+                                        spawn(async move {
+                                            merging.set(true);
+                                            match merge_group(target_id, source_ids).await {
+                                                Ok(count) => {
+                                                    on_success.call(format!("Слито карточек: {}", count));
+                                                    groups.restart();
+                                                }
+                                                Err(e) => on_error.call(format!("Ошибка: {}", e)),
+                                            }
+                                            merging.set(false);
+                                        });
+                                    }
+                                },
+                                "Оставить эту"
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    };
+
+    rsx! {
+        Sheet {
+            open: true,
+            on_open_change: move |v: bool| {
+                if !v {
+                    on_close.call(())
+                }
+            },
+            SheetContent { side: SheetSide::Right,
+                SheetHeader {
+                    SheetTitle { "Вероятные дубликаты" }
+                }
+
+                div { class: "space-y-4 overflow-y-auto", {content} }
+
+                SheetFooter {
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        onclick: move |_| on_close.call(()),
+                        "Закрыть"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn map_duplicate_card(card: &StudyCard) -> DuplicateCard {
+    let (question, answer) = match card.card() {
+        Card::Vocabulary(v) => (v.word().text().to_string(), v.meaning().text().to_string()),
+        Card::Kanji(k) => (
+            k.kanji().text().to_string(),
+            k.description().text().to_string(),
+        ),
+        Card::Grammar(g) => (
+            g.title().text().to_string(),
+            g.description().text().to_string(),
+        ),
+        Card::Sentence(s) => (
+            s.sentence().text().to_string(),
+            s.translation().text().to_string(),
+        ),
+    };
+
+    DuplicateCard {
+        id: card.card_id().to_string(),
+        question,
+        answer,
+        reviews_count: card.memory().reviews().len(),
+    }
+}
+
+async fn fetch_duplicate_groups() -> Result<Vec<Vec<DuplicateCard>>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let groups = FindDuplicateCardsUseCase::new(repo)
+        .execute(user_id)
+        .await
+        .map_err(to_error)?;
+
+    Ok(groups
+        .iter()
+        .map(|group| group.iter().map(map_duplicate_card).collect())
+        .collect())
+}
+
+async fn merge_group(target_id: String, source_ids: Vec<String>) -> Result<usize, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let target_id = target_id.parse::<Ulid>().map_err(|e| e.to_string())?;
+    let use_case = MergeCardsUseCase::new(repo);
+
+    for source_id in &source_ids {
+        let source_id = source_id.parse::<Ulid>().map_err(|e| e.to_string())?;
+        use_case
+            .execute(user_id, target_id, source_id)
+            .await
+            .map_err(to_error)?;
+    }
+
+    Ok(source_ids.len())
+}
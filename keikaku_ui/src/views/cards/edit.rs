@@ -0,0 +1,289 @@
+use std::collections::BTreeSet;
+
+use dioxus::prelude::*;
+use keikaku::application::use_cases::edit_card::{EditCardRequest, EditCardUseCase};
+use keikaku::application::use_cases::set_card_notes::SetCardNotesUseCase;
+use keikaku::application::use_cases::set_review_directions::SetReviewDirectionsUseCase;
+use keikaku::domain::knowledge::{ClozeDeletion, ExampleKanjiWord};
+use keikaku::domain::value_objects::{ExamplePhrase, ReviewDirection};
+use ulid::Ulid;
+
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::input::Input;
+use crate::components::sheet::{
+    Sheet, SheetContent, SheetFooter, SheetHeader, SheetSide, SheetTitle,
+};
+use crate::components::switch::{Switch, SwitchThumb};
+use crate::components::textarea::Textarea;
+use crate::domain::ReviewDirectionsField;
+use crate::views::cards::cloze_picker::ClozePicker;
+use crate::views::cards::{UiCard, UiCardType};
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+use keikaku::settings::ApplicationEnvironment;
+
+const EXAMPLE_SEPARATOR: &str = " — ";
+
+#[component]
+pub fn EditModal(
+    card: UiCard,
+    on_close: EventHandler<()>,
+    on_success: EventHandler<String>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let question = use_signal(|| card.question.clone());
+    let answer = use_signal(|| card.answer.clone());
+    let reading = use_signal(|| card.reading.clone().unwrap_or_default());
+    let examples = use_signal(|| format_examples(&card.examples));
+    let notes = use_signal(|| card.notes.clone().unwrap_or_default());
+    let clozes = use_signal(|| card.clozes.clone());
+    let mut own_directions = use_signal(|| card.review_directions.is_some());
+    let mut directions = use_signal(|| {
+        card.review_directions
+            .clone()
+            .unwrap_or_else(|| BTreeSet::from([ReviewDirection::Recognition]))
+    });
+    let mut saving = use_signal(|| false);
+
+    let card_type = card.card_type;
+    let card_id = card.id.clone();
+
+    rsx! {
+        Sheet {
+            open: true,
+            on_open_change: move |v: bool| {
+                if !v {
+                    on_close.call(())
+                }
+            },
+            SheetContent { side: SheetSide::Right,
+                SheetHeader {
+                    SheetTitle { "Редактировать карточку" }
+                }
+
+                div { class: "space-y-4",
+                    div { class: "space-y-2",
+                        label { class: "text-sm font-medium", "Вопрос" }
+                        Input {
+                            value: question(),
+                            disabled: card_type != UiCardType::Vocabulary,
+                            oninput: {
+                                let mut question = question;
+                                move |e: FormEvent| question.set(e.value())
+                            },
+                        }
+                    }
+
+                    if card_type == UiCardType::Vocabulary {
+                        div { class: "space-y-2",
+                            label { class: "text-sm font-medium", "Чтение" }
+                            Input {
+                                value: reading(),
+                                placeholder: "Хираганой, например たべる",
+                                oninput: {
+                                    let mut reading = reading;
+                                    move |e: FormEvent| reading.set(e.value())
+                                },
+                            }
+                        }
+                    }
+
+                    div { class: "space-y-2",
+                        label { class: "text-sm font-medium", "Ответ" }
+                        Textarea {
+                            rows: 4,
+                            value: answer(),
+                            oninput: {
+                                let mut answer = answer;
+                                move |e: FormEvent| answer.set(e.value())
+                            },
+                        }
+                    }
+
+                    if card_type == UiCardType::Sentence {
+                        ClozePicker { sentence: question(), clozes }
+                    }
+
+                    if card_type == UiCardType::Vocabulary || card_type == UiCardType::Kanji {
+                        div { class: "space-y-2",
+                            label { class: "text-sm font-medium", "Примеры" }
+                            Textarea {
+                                rows: 6,
+                                placeholder: "Одна строка — один пример: текст — перевод",
+                                value: examples(),
+                                oninput: {
+                                    let mut examples = examples;
+                                    move |e: FormEvent| examples.set(e.value())
+                                },
+                            }
+                        }
+                    }
+
+                    div { class: "space-y-2",
+                        label { class: "text-sm font-medium", "Заметки" }
+                        Textarea {
+                            rows: 4,
+                            placeholder: "Мнемоника, контекст, исходное предложение. Поддерживается markdown",
+                            value: notes(),
+                            oninput: {
+                                let mut notes = notes;
+                                move |e: FormEvent| notes.set(e.value())
+                            },
+                        }
+                    }
+
+                    if card_type == UiCardType::Vocabulary {
+                        div { class: "space-y-3",
+                            div { class: "flex items-center justify-between gap-4",
+                                span { class: "text-sm font-medium", "Свои направления повторения" }
+                                Switch {
+                                    aria_label: "Свои направления повторения",
+                                    checked: own_directions(),
+                                    on_checked_change: move |v| own_directions.set(v),
+                                    SwitchThumb {}
+                                }
+                            }
+                            if own_directions() {
+                                ReviewDirectionsField {
+                                    directions: directions(),
+                                    on_change: move |v| directions.set(v),
+                                }
+                            } else {
+                                p { class: "text-xs text-muted-foreground",
+                                    "Используются направления из настроек профиля"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                SheetFooter {
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        onclick: move |_| on_close.call(()),
+                        "Отмена"
+                    }
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        disabled: saving(),
+                        onclick: move |_| {
+                            let request = build_request(
+                                card_type,
+                                question(),
+                                answer(),
+                                reading(),
+                                &examples(),
+                                clozes(),
+                            );
+                            let own = own_directions().then_some(directions());
+                            let review_directions = (card_type == UiCardType::Vocabulary)
+                                .then_some(own);
+                            let card_id = card_id.clone();
+
+                            spawn(async move {
+                                saving.set(true);
+                                match edit_card(card_id, request, notes(), review_directions).await {
+                                    Ok(_) => on_success.call("Карточка сохранена".to_string()),
+                                    Err(e) => on_error.call(format!("Ошибка: {}", e)),
+                                }
+                                saving.set(false);
+                            });
+                        },
+                        {if saving() { "Сохранение..." } else { "Сохранить" }}
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn format_examples(examples: &[(String, String)]) -> String {
+    examples
+        .iter()
+        .map(|(text, translation)| format!("{text}{EXAMPLE_SEPARATOR}{translation}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_examples(examples: &str) -> Vec<(String, String)> {
+    examples
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (text, translation) = line
+                .split_once(EXAMPLE_SEPARATOR.trim())
+                .or_else(|| line.split_once(" - "))
+                .unwrap_or((line, ""));
+            (text.trim().to_string(), translation.trim().to_string())
+        })
+        .collect()
+}
+
+fn build_request(
+    card_type: UiCardType,
+    question: String,
+    answer: String,
+    reading: String,
+    examples: &str,
+    clozes: Vec<ClozeDeletion>,
+) -> EditCardRequest {
+    let examples = parse_examples(examples);
+
+    match card_type {
+        UiCardType::Vocabulary => EditCardRequest::Vocabulary {
+            word: question,
+            meaning: answer,
+            example_phrases: examples
+                .into_iter()
+                .map(|(text, translation)| ExamplePhrase::new(text, translation))
+                .collect(),
+            reading: Some(reading).filter(|reading| !reading.trim().is_empty()),
+        },
+        UiCardType::Kanji => EditCardRequest::Kanji {
+            description: answer,
+            example_words: examples
+                .into_iter()
+                .map(|(word, meaning)| ExampleKanjiWord::new(word, meaning))
+                .collect(),
+        },
+        UiCardType::Grammar => EditCardRequest::Grammar {
+            description: answer,
+        },
+        UiCardType::Sentence => EditCardRequest::Sentence {
+            translation: answer,
+            clozes,
+        },
+    }
+}
+
+async fn edit_card(
+    card_id: String,
+    request: EditCardRequest,
+    notes: String,
+    review_directions: Option<Option<BTreeSet<ReviewDirection>>>,
+) -> Result<(), String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let card_id = card_id.parse::<Ulid>().map_err(|e| e.to_string())?;
+
+    EditCardUseCase::new(repo)
+        .execute(user_id, card_id, request)
+        .await
+        .map_err(to_error)?;
+
+    SetCardNotesUseCase::new(repo)
+        .execute(user_id, card_id, notes)
+        .await
+        .map_err(to_error)?;
+
+    if let Some(directions) = review_directions {
+        SetReviewDirectionsUseCase::new(repo)
+            .execute(user_id, vec![card_id], directions)
+            .await
+            .map_err(to_error)?;
+    }
+
+    Ok(())
+}
@@ -11,6 +11,8 @@ use crate::views::cards::{FilterStatus, SortBy};
 pub fn CardsFilters(
     search: Signal<String>,
     filter_status: Signal<FilterStatus>,
+    tag_filter: Signal<Option<String>>,
+    tags: Vec<String>,
     sort_by: Signal<SortBy>,
 ) -> Element {
     let status_value = match filter_status() {
@@ -32,12 +34,14 @@ pub fn CardsFilters(
     }
     .to_string();
 
+    let tag_value = tag_filter().unwrap_or_default();
+
     rsx! {
         Card { class: Some("space-y-4".to_string()),
             div { class: "space-y-2",
                 label { class: "text-sm font-medium", "ПОИСК" }
                 Input {
-                    placeholder: "Поиск по слову или переводу...",
+                    placeholder: "Поиск по слову, переводу или тегу...",
                     value: search(),
                     oninput: move |e: FormEvent| search.set(e.value()),
                 }
@@ -98,6 +102,34 @@ pub fn CardsFilters(
                         }
                     }
                 }
+                if !tags.is_empty() {
+                    div { class: "flex-1 min-w-[200px]",
+                        label { class: "text-sm font-medium", "ТЕГ" }
+                        Select::<String> {
+                            value: Some(Some(tag_value.clone())),
+                            on_value_change: move |v: Option<String>| {
+                                tag_filter.set(v.filter(|v| !v.is_empty()));
+                            },
+                            placeholder: "Выберите...",
+                            SelectTrigger { aria_label: "Тег", width: "100%", SelectValue {} }
+                            SelectList { aria_label: "Тег",
+                                SelectOption::<String> { index: 0usize, value: String::new(),
+                                    "Все"
+                                    SelectItemIndicator {}
+                                }
+                                for (index , tag) in tags.iter().enumerate() {
+                                    SelectOption::<String> {
+                                        key: "{tag}",
+                                        index: index + 1,
+                                        value: tag.clone(),
+                                        "{tag}"
+                                        SelectItemIndicator {}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
                 div { class: "flex-1 min-w-[200px]",
                     label { class: "text-sm font-medium", "СОРТИРОВКА" }
                     Select::<String> {
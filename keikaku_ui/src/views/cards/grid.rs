@@ -14,6 +14,7 @@ pub fn CardsGrid(
     loading: bool,
     on_edit: EventHandler<UiCard>,
     on_delete: EventHandler<UiCard>,
+    on_reset: EventHandler<UiCard>,
     on_create_click: EventHandler<()>,
     on_card_click: Option<EventHandler<UiCard>>,
 ) -> Element {
@@ -39,6 +40,7 @@ pub fn CardsGrid(
                         card: card.clone(),
                         on_edit,
                         on_delete,
+                        on_reset,
                         on_card_click,
                     }
                 }
@@ -75,6 +77,7 @@ fn CardItem(
     card: UiCard,
     on_edit: EventHandler<UiCard>,
     on_delete: EventHandler<UiCard>,
+    on_reset: EventHandler<UiCard>,
     on_card_click: Option<EventHandler<UiCard>>,
 ) -> Element {
     let card_rc = Rc::new(card);
@@ -107,6 +110,18 @@ fn CardItem(
                         title: "Редактировать",
                         size: 16,
                     }
+                    if !card_rc.is_new {
+                        IconButton {
+                            icon: solid::Shape::ArrowPath,
+                            onclick: {
+                                let card_clone = Rc::clone(&card_rc);
+                                move |_| on_reset.call((*card_clone).clone())
+                            },
+                            class: "w-8 h-8 rounded-xl bg-slate-500 hover:bg-slate-600 text-white flex items-center justify-center shadow-md shadow-slate-500/15 hover:scale-110 hover:shadow-glow active:scale-95 transition-all duration-300 ease-elastic",
+                            title: "Сбросить прогресс",
+                            size: 16,
+                        }
+                    }
                     IconButton {
                         icon: solid::Shape::Trash,
                         onclick: {
@@ -119,7 +134,7 @@ fn CardItem(
                     }
                 }
 
-                div { class: "space-y-3 pr-16",
+                div { class: "space-y-3 pr-24",
                     H3 { class: Some("text-lg font-bold text-slate-800 leading-tight".to_string()),
                         FuriganaText {
                             text: card_rc.question.clone(),
@@ -144,6 +159,18 @@ fn CardItem(
                         }
                     }
 
+                    if !card_rc.tags.is_empty() {
+                        div { class: "flex flex-wrap gap-1",
+                            for tag in card_rc.tags.iter() {
+                                span {
+                                    key: "{tag}",
+                                    class: "px-2 py-0.5 rounded-full text-[10px] bg-slate-100 text-slate-600",
+                                    "#{tag}"
+                                }
+                            }
+                        }
+                    }
+
                     // Теги статуса, сложности и стабильности
                     div { class: "grid grid-cols-2 gap-1 text-xs",
                         Pill {
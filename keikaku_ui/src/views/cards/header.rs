@@ -8,7 +8,13 @@ use crate::components::button::{Button, ButtonVariant};
 pub fn CardsHeader(
     total_count: usize,
     due_count: usize,
+    new_count: usize,
+    started_count: usize,
     on_create_click: EventHandler<()>,
+    on_mark_known_click: EventHandler<()>,
+    on_reset_click: EventHandler<()>,
+    on_tags_click: EventHandler<()>,
+    on_duplicates_click: EventHandler<()>,
 ) -> Element {
     rsx! {
         SectionHeader {
@@ -20,6 +26,32 @@ pub fn CardsHeader(
                 Link { to: Route::Learn {},
                     Button { variant: ButtonVariant::Outline, class: "w-auto px-6", "Учиться" }
                 }
+                Button {
+                    variant: ButtonVariant::Outline,
+                    class: "w-auto px-6",
+                    disabled: new_count == 0,
+                    onclick: move |_| on_mark_known_click.call(()),
+                    "Отметить новые как известные ({new_count})"
+                }
+                Button {
+                    variant: ButtonVariant::Outline,
+                    class: "w-auto px-6",
+                    disabled: started_count == 0,
+                    onclick: move |_| on_reset_click.call(()),
+                    "Сбросить прогресс ({started_count})"
+                }
+                Button {
+                    variant: ButtonVariant::Outline,
+                    class: "w-auto px-6",
+                    onclick: move |_| on_tags_click.call(()),
+                    "Теги"
+                }
+                Button {
+                    variant: ButtonVariant::Outline,
+                    class: "w-auto px-6",
+                    onclick: move |_| on_duplicates_click.call(()),
+                    "Дубликаты"
+                }
                 Button {
                     variant: ButtonVariant::Primary,
                     class: "w-auto px-6",
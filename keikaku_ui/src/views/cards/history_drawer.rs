@@ -12,7 +12,10 @@ pub fn CardHistoryDrawer(
     card: Option<UiCard>,
     open: bool,
     on_open_change: EventHandler<bool>,
+    on_reset: EventHandler<UiCard>,
 ) -> Element {
+    let reset_card = card.clone().filter(|card| !card.is_new);
+
     rsx! {
         Sheet { open, on_open_change,
             SheetContent { side: SheetSide::Right, class: "w-[60%]",
@@ -28,6 +31,11 @@ pub fn CardHistoryDrawer(
                                 {card.question.clone()}
                             }
                             p { class: "text-sm text-slate-600", {card.answer.clone()} }
+                            if card.archived_reviews_count > 0 {
+                                p { class: "text-xs text-slate-500",
+                                    "В архиве повторений: {card.archived_reviews_count}"
+                                }
+                            }
                         }
 
                         // История рейтингов
@@ -102,6 +110,13 @@ pub fn CardHistoryDrawer(
                 }
 
                 SheetFooter {
+                    if let Some(reset_card) = reset_card {
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            onclick: move |_| on_reset.call(reset_card.clone()),
+                            "Сбросить прогресс"
+                        }
+                    }
                     Button {
                         variant: ButtonVariant::Outline,
                         onclick: move |_| on_open_change.call(false),
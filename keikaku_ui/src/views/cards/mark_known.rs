@@ -0,0 +1,112 @@
+use dioxus::prelude::*;
+use dioxus_heroicons::{Icon, solid};
+use dioxus_primitives::toast::{ToastOptions, Toasts};
+use keikaku::application::use_cases::mark_cards_as_known::{
+    MarkAsKnownOptions, MarkCardsAsKnownUseCase,
+};
+use ulid::Ulid;
+
+use crate::components::alert_dialog::{
+    AlertDialogAction, AlertDialogActions, AlertDialogCancel, AlertDialogContent,
+    AlertDialogDescription, AlertDialogRoot, AlertDialogTitle,
+};
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+use keikaku::settings::ApplicationEnvironment;
+
+#[component]
+pub fn MarkKnownConfirmModal(
+    card_ids: Option<Vec<String>>,
+    on_close: EventHandler<()>,
+    on_confirm: EventHandler<Vec<String>>,
+) -> Element {
+    if let Some(card_ids) = card_ids {
+        let count = card_ids.len();
+        let spread_days = MarkAsKnownOptions::default().spread_days;
+
+        rsx! {
+            AlertDialogRoot {
+                open: true,
+                on_open_change: move |v: bool| {
+                    if !v {
+                        on_close.call(())
+                    }
+                },
+                AlertDialogContent {
+                    AlertDialogTitle { "Отметить как известные" }
+                    AlertDialogDescription {
+                        div { class: "flex items-start gap-3",
+                            Icon {
+                                icon: solid::Shape::CheckCircle,
+                                size: 20,
+                                class: Some("text-primary".to_string()),
+                            }
+                            div { class: "space-y-2",
+                                div { class: "text-sm font-semibold",
+                                    "Новых карточек: {count}"
+                                }
+                                div { class: "text-sm text-muted-foreground",
+                                    "Карточки не попадут в уроки как новые. Первые повторения будут распределены на {spread_days} дней."
+                                }
+                            }
+                        }
+                    }
+                    AlertDialogActions {
+                        AlertDialogCancel { "Отмена" }
+                        AlertDialogAction { on_click: move |_| on_confirm.call(card_ids.clone()),
+                            "Отметить"
+                        }
+                    }
+                }
+            }
+        }
+    } else {
+        rsx! {}
+    }
+}
+
+pub fn mark_known_with_handlers(
+    toast: Toasts,
+    mark_known_confirm: Signal<Option<Vec<String>>>,
+    loading: Signal<bool>,
+    on_refresh: EventHandler<()>,
+) -> impl Fn(Vec<String>) {
+    move |card_ids: Vec<String>| {
+        let mut mark_known_confirm = mark_known_confirm;
+        let mut loading = loading;
+        let on_refresh = on_refresh;
+
+        spawn(async move {
+            loading.set(true);
+            match mark_cards_as_known(card_ids).await {
+                Ok(count) => {
+                    mark_known_confirm.set(None);
+                    toast.success(
+                        format!("Отмечено как известные: {}", count),
+                        ToastOptions::new(),
+                    );
+                    on_refresh.call(());
+                }
+                Err(e) => {
+                    toast.error(format!("Ошибка: {}", e), ToastOptions::new());
+                }
+            }
+            loading.set(false);
+        });
+    }
+}
+
+async fn mark_cards_as_known(card_ids: Vec<String>) -> Result<usize, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let card_ids = card_ids
+        .iter()
+        .map(|id| id.parse::<Ulid>().map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    MarkCardsAsKnownUseCase::new(repo)
+        .execute(user_id, card_ids, MarkAsKnownOptions::default())
+        .await
+        .map_err(to_error)
+}
@@ -1,12 +1,19 @@
+mod cloze_picker;
 mod create;
 mod delete;
+mod duplicates;
+mod edit;
 mod filters;
 mod grid;
 mod header;
 mod history_drawer;
+mod mark_known;
+mod reset;
 mod stats;
+mod tags;
 mod types;
 mod view;
+mod vocabulary_preview;
 
-pub use types::{FilterStatus, SortBy, UiCard};
+pub use types::{FilterStatus, SortBy, UiCard, UiCardType};
 pub use view::Cards;
@@ -0,0 +1,120 @@
+use dioxus::prelude::*;
+use dioxus_heroicons::{Icon, solid};
+use dioxus_primitives::toast::{ToastOptions, Toasts};
+use keikaku::application::use_cases::reset_card_progress::ResetCardProgressUseCase;
+use ulid::Ulid;
+
+use crate::components::alert_dialog::{
+    AlertDialogAction, AlertDialogActions, AlertDialogCancel, AlertDialogContent,
+    AlertDialogDescription, AlertDialogRoot, AlertDialogTitle,
+};
+use crate::components::switch::{Switch, SwitchThumb};
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+use keikaku::settings::ApplicationEnvironment;
+
+#[component]
+pub fn ResetProgressConfirmModal(
+    card_ids: Option<Vec<String>>,
+    on_close: EventHandler<()>,
+    on_confirm: EventHandler<(Vec<String>, bool)>,
+) -> Element {
+    let mut archive_reviews = use_signal(|| true);
+
+    if let Some(card_ids) = card_ids {
+        let count = card_ids.len();
+
+        rsx! {
+            AlertDialogRoot {
+                open: true,
+                on_open_change: move |v: bool| {
+                    if !v {
+                        on_close.call(())
+                    }
+                },
+                AlertDialogContent {
+                    AlertDialogTitle { "Сбросить прогресс" }
+                    AlertDialogDescription {
+                        div { class: "flex items-start gap-3",
+                            Icon {
+                                icon: solid::Shape::ArrowPath,
+                                size: 20,
+                                class: Some("text-warning".to_string()),
+                            }
+                            div { class: "space-y-2",
+                                div { class: "text-sm font-semibold", "Карточек: {count}" }
+                                div { class: "text-sm text-muted-foreground",
+                                    "Карточки снова станут новыми. Содержимое и примеры сохранятся."
+                                }
+                            }
+                        }
+                    }
+                    div { class: "flex items-center justify-between gap-4",
+                        span { class: "text-sm font-medium", "Сохранить историю повторений в архиве" }
+                        Switch {
+                            aria_label: "Сохранить историю в архиве",
+                            checked: archive_reviews(),
+                            on_checked_change: move |v| archive_reviews.set(v),
+                            SwitchThumb {}
+                        }
+                    }
+                    AlertDialogActions {
+                        AlertDialogCancel { "Отмена" }
+                        AlertDialogAction {
+                            on_click: move |_| on_confirm.call((card_ids.clone(), archive_reviews())),
+                            "Сбросить"
+                        }
+                    }
+                }
+            }
+        }
+    } else {
+        rsx! {}
+    }
+}
+
+pub fn reset_progress_with_handlers(
+    toast: Toasts,
+    reset_confirm: Signal<Option<Vec<String>>>,
+    loading: Signal<bool>,
+    on_refresh: EventHandler<()>,
+) -> impl Fn((Vec<String>, bool)) {
+    move |(card_ids, archive_reviews): (Vec<String>, bool)| {
+        let mut reset_confirm = reset_confirm;
+        let mut loading = loading;
+        let on_refresh = on_refresh;
+
+        spawn(async move {
+            loading.set(true);
+            match reset_card_progress(card_ids, archive_reviews).await {
+                Ok(count) => {
+                    reset_confirm.set(None);
+                    toast.success(format!("Прогресс сброшен: {}", count), ToastOptions::new());
+                    on_refresh.call(());
+                }
+                Err(e) => {
+                    toast.error(format!("Ошибка: {}", e), ToastOptions::new());
+                }
+            }
+            loading.set(false);
+        });
+    }
+}
+
+async fn reset_card_progress(
+    card_ids: Vec<String>,
+    archive_reviews: bool,
+) -> Result<usize, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let card_ids = card_ids
+        .iter()
+        .map(|id| id.parse::<Ulid>().map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    ResetCardProgressUseCase::new(repo)
+        .execute(user_id, card_ids, archive_reviews)
+        .await
+        .map_err(to_error)
+}
@@ -0,0 +1,189 @@
+use dioxus::prelude::*;
+use keikaku::application::use_cases::{
+    add_card_tags::AddCardTagsUseCase, remove_card_tags::RemoveCardTagsUseCase,
+    rename_tag::RenameTagUseCase,
+};
+use std::collections::BTreeSet;
+use ulid::Ulid;
+
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::input::Input;
+use crate::components::sheet::{
+    Sheet, SheetContent, SheetFooter, SheetHeader, SheetSide, SheetTitle,
+};
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+use keikaku::settings::ApplicationEnvironment;
+
+#[derive(Clone, Copy, PartialEq)]
+enum TagAction {
+    Add,
+    Remove,
+}
+
+#[component]
+pub fn TagsModal(
+    card_ids: Vec<String>,
+    on_close: EventHandler<()>,
+    on_success: EventHandler<String>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let tag = use_signal(String::new);
+    let rename_from = use_signal(String::new);
+    let rename_to = use_signal(String::new);
+    let mut saving = use_signal(|| false);
+
+    let count = card_ids.len();
+    let run_tag_action = {
+        let card_ids = card_ids.clone();
+        move |action: TagAction| {
+            let tag = tag();
+            if tag.trim().is_empty() {
+                return;
+            }
+
+            let card_ids = card_ids.clone();
+            spawn(async move {
+                saving.set(true);
+                match update_card_tags(card_ids, tag, action).await {
+                    Ok(count) => on_success.call(format!("Теги обновлены: {}", count)),
+                    Err(e) => on_error.call(format!("Ошибка: {}", e)),
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        Sheet {
+            open: true,
+            on_open_change: move |v: bool| {
+                if !v {
+                    on_close.call(())
+                }
+            },
+            SheetContent { side: SheetSide::Right,
+                SheetHeader {
+                    SheetTitle { "Теги" }
+                }
+
+                div { class: "space-y-6",
+                    div { class: "space-y-2",
+                        label { class: "text-sm font-medium", "Тег для отфильтрованных карточек ({count})" }
+                        Input {
+                            placeholder: "например, jlpt:N4",
+                            value: tag(),
+                            oninput: {
+                                let mut tag = tag;
+                                move |e: FormEvent| tag.set(e.value())
+                            },
+                        }
+                        div { class: "flex gap-2",
+                            Button {
+                                variant: ButtonVariant::Primary,
+                                disabled: saving() || count == 0,
+                                onclick: {
+                                    let run_tag_action = run_tag_action.clone();
+                                    move |_| run_tag_action(TagAction::Add)
+                                },
+                                "Добавить"
+                            }
+                            Button {
+                                variant: ButtonVariant::Outline,
+                                disabled: saving() || count == 0,
+                                onclick: move |_| run_tag_action(TagAction::Remove),
+                                "Убрать"
+                            }
+                        }
+                    }
+
+                    div { class: "space-y-2",
+                        label { class: "text-sm font-medium", "Переименовать тег во всех карточках" }
+                        Input {
+                            placeholder: "Старое имя",
+                            value: rename_from(),
+                            oninput: {
+                                let mut rename_from = rename_from;
+                                move |e: FormEvent| rename_from.set(e.value())
+                            },
+                        }
+                        Input {
+                            placeholder: "Новое имя",
+                            value: rename_to(),
+                            oninput: {
+                                let mut rename_to = rename_to;
+                                move |e: FormEvent| rename_to.set(e.value())
+                            },
+                        }
+                        Button {
+                            variant: ButtonVariant::Outline,
+                            disabled: saving(),
+                            onclick: move |_| {
+                                let from = rename_from();
+                                let to = rename_to();
+                                if from.trim().is_empty() || to.trim().is_empty() {
+                                    return;
+                                }
+
+                                spawn(async move {
+                                    saving.set(true);
+                                    match rename_tag(from, to).await {
+                                        Ok(count) => on_success.call(format!("Тег переименован в {} карточках", count)),
+                                        Err(e) => on_error.call(format!("Ошибка: {}", e)),
+                                    }
+                                    saving.set(false);
+                                });
+                            },
+                            "Переименовать"
+                        }
+                    }
+                }
+
+                SheetFooter {
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        onclick: move |_| on_close.call(()),
+                        "Закрыть"
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn update_card_tags(
+    card_ids: Vec<String>,
+    tag: String,
+    action: TagAction,
+) -> Result<usize, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let card_ids = card_ids
+        .iter()
+        .map(|id| id.parse::<Ulid>().map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let tags = BTreeSet::from([tag]);
+
+    match action {
+        TagAction::Add => AddCardTagsUseCase::new(repo)
+            .execute(user_id, card_ids, tags)
+            .await
+            .map_err(to_error),
+        TagAction::Remove => RemoveCardTagsUseCase::new(repo)
+            .execute(user_id, card_ids, tags)
+            .await
+            .map_err(to_error),
+    }
+}
+
+async fn rename_tag(from: String, to: String) -> Result<usize, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    RenameTagUseCase::new(repo)
+        .execute(user_id, from, to)
+        .await
+        .map_err(to_error)
+}
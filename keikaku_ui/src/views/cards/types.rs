@@ -1,5 +1,7 @@
 use chrono::{DateTime, Duration, Utc};
-use keikaku::domain::value_objects::Rating;
+use keikaku::domain::knowledge::ClozeDeletion;
+use keikaku::domain::value_objects::{Rating, ReviewDirection};
+use std::collections::BTreeSet;
 
 #[derive(Clone, PartialEq)]
 pub struct ReviewInfo {
@@ -27,11 +29,21 @@ pub enum SortBy {
     Stability,
 }
 
+#[derive(Clone, Copy, PartialEq)]
+pub enum UiCardType {
+    Vocabulary,
+    Kanji,
+    Grammar,
+    Sentence,
+}
+
 #[derive(Clone, PartialEq)]
 pub struct UiCard {
     pub id: String,
+    pub card_type: UiCardType,
     pub question: String,
     pub answer: String,
+    pub reading: Option<String>,
     pub examples: Vec<(String, String)>, // (text, translation)
     pub difficulty: Option<f64>,
     pub stability: Option<f64>,
@@ -43,4 +55,12 @@ pub struct UiCard {
     pub is_low_stability: bool,
     pub is_high_difficulty: bool,
     pub reviews: Vec<ReviewInfo>,
+    pub archived_reviews_count: usize,
+    pub tags: Vec<String>,
+    /// Направления повторения карты. `None` — из настроек профиля
+    pub review_directions: Option<BTreeSet<ReviewDirection>>,
+    /// Заметки пользователя в markdown
+    pub notes: Option<String>,
+    /// Пропуски в карточке предложения
+    pub clozes: Vec<ClozeDeletion>,
 }
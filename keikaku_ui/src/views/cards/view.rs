@@ -3,15 +3,22 @@ use keikaku::application::use_cases::knowledge_set_cards::KnowledgeSetCardsUseCa
 use keikaku::domain::knowledge::StudyCard;
 use keikaku::domain::value_objects::{Difficulty, Stability};
 use keikaku::settings::ApplicationEnvironment;
+use std::collections::BTreeSet;
 
 use crate::components::app_ui::ErrorCard;
 use crate::views::cards::create::CreateModal;
 use crate::views::cards::delete::{DeleteConfirmModal, delete_card_with_handlers};
+use crate::views::cards::duplicates::DuplicatesModal;
+use crate::views::cards::edit::EditModal;
+use crate::views::cards::mark_known::{MarkKnownConfirmModal, mark_known_with_handlers};
+use crate::views::cards::reset::{ResetProgressConfirmModal, reset_progress_with_handlers};
+use crate::views::cards::tags::TagsModal;
 use crate::{
     DEFAULT_USERNAME, ensure_user, to_error,
     views::cards::{
-        FilterStatus, SortBy, UiCard, filters::CardsFilters, grid::CardsGrid, header::CardsHeader,
-        history_drawer::CardHistoryDrawer, stats::CardsStats, types::ReviewInfo,
+        FilterStatus, SortBy, UiCard, UiCardType, filters::CardsFilters, grid::CardsGrid,
+        header::CardsHeader, history_drawer::CardHistoryDrawer, stats::CardsStats,
+        types::ReviewInfo,
     },
 };
 use dioxus_primitives::toast::{ToastOptions, use_toast};
@@ -20,6 +27,9 @@ use dioxus_primitives::toast::{ToastOptions, use_toast};
 pub enum ModalState {
     None,
     Create,
+    Edit(UiCard),
+    Tags,
+    Duplicates,
 }
 
 #[component]
@@ -70,28 +80,59 @@ struct CardsStatsData {
 fn CardsContent(cards_data: ProcessedCardsData, on_refresh: EventHandler<()>) -> Element {
     let search = use_signal(String::new);
     let filter_status = use_signal(|| FilterStatus::All);
+    let tag_filter = use_signal(|| None::<String>);
     let sort_by = use_signal(|| SortBy::Date);
     let mut modal_state = use_signal(|| ModalState::None);
     let mut delete_confirm = use_signal(|| None::<String>);
+    let mut mark_known_confirm = use_signal(|| None::<Vec<String>>);
+    let mut reset_confirm = use_signal(|| None::<Vec<String>>);
     let mut selected_card_for_history = use_signal(|| None::<UiCard>);
     let loading = use_signal(|| false);
     let toast = use_toast();
 
+    let all_tags = cards_data
+        .cards
+        .iter()
+        .flat_map(|c| c.tags.iter().cloned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
     let filtered_and_sorted = move || {
         filter_and_sort_cards(
             cards_data.cards.clone(),
             search(),
             filter_status(),
+            tag_filter(),
             sort_by(),
         )
     };
 
+    let new_card_ids = filtered_and_sorted()
+        .into_iter()
+        .filter(|c| c.is_new)
+        .map(|c| c.id)
+        .collect::<Vec<_>>();
+    let new_count = new_card_ids.len();
+    let started_card_ids = filtered_and_sorted()
+        .into_iter()
+        .filter(|c| !c.is_new)
+        .map(|c| c.id)
+        .collect::<Vec<_>>();
+    let started_count = started_card_ids.len();
+
     rsx! {
         div { class: "bg-bg min-h-screen text-text-main px-6 py-8 space-y-6",
             CardsHeader {
                 total_count: cards_data.stats.total_count,
                 due_count: cards_data.stats.due_count,
+                new_count,
+                started_count,
                 on_create_click: move |_| modal_state.set(ModalState::Create),
+                on_mark_known_click: move |_| mark_known_confirm.set(Some(new_card_ids.clone())),
+                on_reset_click: move |_| reset_confirm.set(Some(started_card_ids.clone())),
+                on_tags_click: move |_| modal_state.set(ModalState::Tags),
+                on_duplicates_click: move |_| modal_state.set(ModalState::Duplicates),
             }
 
             CardsStats {
@@ -100,13 +141,20 @@ fn CardsContent(cards_data: ProcessedCardsData, on_refresh: EventHandler<()>) ->
                 filtered_count: filtered_and_sorted().len(),
             }
 
-            CardsFilters { search, filter_status, sort_by }
+            CardsFilters {
+                search,
+                filter_status,
+                tag_filter,
+                tags: all_tags,
+                sort_by,
+            }
 
             CardsGrid {
                 cards: filtered_and_sorted(),
                 loading: loading(),
-                on_edit: move |_card: UiCard| {},
+                on_edit: move |card: UiCard| modal_state.set(ModalState::Edit(card)),
                 on_delete: move |card: UiCard| delete_confirm.set(Some(card.id)),
+                on_reset: move |card: UiCard| reset_confirm.set(Some(vec![card.id])),
                 on_create_click: move |_| modal_state.set(ModalState::Create),
                 on_card_click: move |card: UiCard| selected_card_for_history.set(Some(card)),
             }
@@ -123,6 +171,39 @@ fn CardsContent(cards_data: ProcessedCardsData, on_refresh: EventHandler<()>) ->
                         loading: loading(),
                     }
                 },
+                ModalState::Edit(card) => rsx! {
+                    EditModal {
+                        card,
+                        on_close: move |_| modal_state.set(ModalState::None),
+                        on_success: move |msg| {
+                            modal_state.set(ModalState::None);
+                            toast.success(msg, ToastOptions::new());
+                            on_refresh.call(());
+                        },
+                        on_error: move |msg| toast.error(msg, ToastOptions::new()),
+                    }
+                },
+                ModalState::Tags => rsx! {
+                    TagsModal {
+                        card_ids: filtered_and_sorted().into_iter().map(|c| c.id).collect::<Vec<_>>(),
+                        on_close: move |_| modal_state.set(ModalState::None),
+                        on_success: move |msg| {
+                            toast.success(msg, ToastOptions::new());
+                            on_refresh.call(());
+                        },
+                        on_error: move |msg| toast.error(msg, ToastOptions::new()),
+                    }
+                },
+                ModalState::Duplicates => rsx! {
+                    DuplicatesModal {
+                        on_close: move |_| modal_state.set(ModalState::None),
+                        on_success: move |msg| {
+                            toast.success(msg, ToastOptions::new());
+                            on_refresh.call(());
+                        },
+                        on_error: move |msg| toast.error(msg, ToastOptions::new()),
+                    }
+                },
                 ModalState::None => rsx! {},
             }
 
@@ -132,6 +213,18 @@ fn CardsContent(cards_data: ProcessedCardsData, on_refresh: EventHandler<()>) ->
                 on_confirm: delete_card_with_handlers(toast, delete_confirm, loading, on_refresh),
             }
 
+            MarkKnownConfirmModal {
+                card_ids: mark_known_confirm(),
+                on_close: move || mark_known_confirm.set(None),
+                on_confirm: mark_known_with_handlers(toast, mark_known_confirm, loading, on_refresh),
+            }
+
+            ResetProgressConfirmModal {
+                card_ids: reset_confirm(),
+                on_close: move || reset_confirm.set(None),
+                on_confirm: reset_progress_with_handlers(toast, reset_confirm, loading, on_refresh),
+            }
+
             CardHistoryDrawer {
                 card: selected_card_for_history(),
                 open: selected_card_for_history().is_some(),
@@ -140,6 +233,10 @@ fn CardsContent(cards_data: ProcessedCardsData, on_refresh: EventHandler<()>) ->
                         selected_card_for_history.set(None);
                     }
                 },
+                on_reset: move |card: UiCard| {
+                    selected_card_for_history.set(None);
+                    reset_confirm.set(Some(vec![card.id]));
+                },
             }
         }
     }
@@ -168,8 +265,9 @@ fn map_card(card: &StudyCard) -> UiCard {
         .unwrap_or_else(|| "—".to_string());
 
     // Extract data based on card type
-    let (question, answer, examples) = match card.card() {
+    let (card_type, question, answer, examples) = match card.card() {
         keikaku::domain::knowledge::Card::Vocabulary(v) => (
+            UiCardType::Vocabulary,
             v.word().text().to_string(),
             v.meaning().text().to_string(),
             v.example_phrases()
@@ -180,15 +278,26 @@ fn map_card(card: &StudyCard) -> UiCard {
                 .collect(),
         ),
         keikaku::domain::knowledge::Card::Kanji(k) => (
+            UiCardType::Kanji,
             k.kanji().text().to_string(),
             k.description().text().to_string(),
-            Vec::new(), // Kanji cards don't have examples in the same format
+            k.example_words()
+                .iter()
+                .map(|ex| (ex.word().to_string(), ex.meaning().to_string()))
+                .collect(),
         ),
         keikaku::domain::knowledge::Card::Grammar(g) => (
+            UiCardType::Grammar,
             g.title().text().to_string(),
             g.description().text().to_string(),
             Vec::new(), // Grammar cards don't have examples in the same format
         ),
+        keikaku::domain::knowledge::Card::Sentence(sentence) => (
+            UiCardType::Sentence,
+            sentence.sentence().text().to_string(),
+            sentence.translation().text().to_string(),
+            Vec::new(),
+        ),
     };
 
     let reviews = card
@@ -204,8 +313,13 @@ fn map_card(card: &StudyCard) -> UiCard {
 
     UiCard {
         id: card.card_id().to_string(),
+        card_type,
         question,
         answer,
+        reading: match card.card() {
+            keikaku::domain::knowledge::Card::Vocabulary(v) => v.reading().map(str::to_string),
+            _ => None,
+        },
         examples,
         difficulty: card.memory().difficulty().map(|d: &Difficulty| d.value()),
         stability: card.memory().stability().map(|s: &Stability| s.value()),
@@ -217,6 +331,14 @@ fn map_card(card: &StudyCard) -> UiCard {
         is_low_stability: card.memory().is_low_stability(),
         is_high_difficulty: card.memory().is_high_difficulty(),
         reviews,
+        archived_reviews_count: card.memory().archived_reviews().len(),
+        tags: card.tags().iter().cloned().collect(),
+        review_directions: card.review_directions().cloned(),
+        notes: card.notes().map(str::to_string),
+        clozes: match card.card() {
+            keikaku::domain::knowledge::Card::Sentence(sentence) => sentence.clozes().to_vec(),
+            _ => Vec::new(),
+        },
     }
 }
 
@@ -238,6 +360,7 @@ fn filter_and_sort_cards(
     cards: Vec<UiCard>,
     search: String,
     filter_status: FilterStatus,
+    tag_filter: Option<String>,
     sort_by: SortBy,
 ) -> Vec<UiCard> {
     let q = search.to_lowercase();
@@ -246,7 +369,13 @@ fn filter_and_sort_cards(
         .filter(|c| {
             let matches_search = q.is_empty()
                 || c.question.to_lowercase().contains(&q)
-                || c.answer.to_lowercase().contains(&q);
+                || c.answer.to_lowercase().contains(&q)
+                || c.notes
+                    .as_ref()
+                    .is_some_and(|notes| notes.to_lowercase().contains(&q))
+                || c.tags.iter().any(|tag| tag.to_lowercase().contains(&q));
+
+            let matches_tag = tag_filter.as_ref().is_none_or(|tag| c.tags.contains(tag));
 
             let matches_status = match filter_status {
                 FilterStatus::All => true,
@@ -257,7 +386,7 @@ fn filter_and_sort_cards(
                 FilterStatus::Learned => c.is_learned,
             };
 
-            matches_search && matches_status
+            matches_search && matches_status && matches_tag
         })
         .collect::<Vec<_>>();
 
@@ -0,0 +1,99 @@
+use dioxus::prelude::*;
+use dioxus_primitives::checkbox::CheckboxState;
+use keikaku::application::use_cases::preview_vocabulary_cards::VocabularyCandidate;
+use keikaku::domain::tokenizer::PartOfSpeech;
+use std::collections::BTreeSet;
+
+use crate::components::app_ui::{Pill, StateTone};
+use crate::components::checkbox::Checkbox;
+
+/// Слова, найденные в тексте. Пользователь отмечает, для каких создать карточки
+#[component]
+pub fn VocabularyPreview(
+    candidates: Vec<VocabularyCandidate>,
+    selected: Signal<BTreeSet<String>>,
+) -> Element {
+    rsx! {
+        div { class: "space-y-2",
+            span { class: "text-sm font-medium", "Слова для карточек" }
+            if candidates.is_empty() {
+                p { class: "text-xs text-muted-foreground", "В тексте не найдено слов" }
+            }
+            for candidate in candidates.iter() {
+                VocabularyCandidateRow {
+                    key: "{candidate.base_form}",
+                    candidate: candidate.clone(),
+                    selected,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn VocabularyCandidateRow(
+    candidate: VocabularyCandidate,
+    selected: Signal<BTreeSet<String>>,
+) -> Element {
+    let checked = selected.read().contains(&candidate.base_form);
+    let level = candidate
+        .jlpt_level
+        .map(|level| level.code().to_string())
+        .unwrap_or_else(|| "вне JLPT".to_string());
+    let source = if candidate.has_dictionary_content {
+        "Словарь"
+    } else {
+        "LLM"
+    };
+    let existing = (!candidate.existing_cards.is_empty())
+        .then(|| format!("Уже есть: {}", candidate.existing_cards.join(", ")));
+
+    rsx! {
+        label { class: "flex items-start gap-3 cursor-pointer",
+            Checkbox {
+                checked: if checked { CheckboxState::Checked } else { CheckboxState::Unchecked },
+                on_checked_change: {
+                    let base_form = candidate.base_form.clone();
+                    move |state: CheckboxState| {
+                        let mut selected = selected;
+                        let checked: bool = state.into();
+                        if checked {
+                            selected.write().insert(base_form.clone());
+                        } else {
+                            selected.write().remove(&base_form);
+                        }
+                    }
+                },
+            }
+            div { class: "flex flex-col gap-1",
+                span { class: "text-sm font-medium",
+                    "{candidate.base_form} ({candidate.reading})"
+                }
+                div { class: "flex flex-wrap gap-1",
+                    Pill { text: part_of_speech_label(&candidate.part_of_speech).to_string() }
+                    Pill { text: level, tone: Some(StateTone::Info) }
+                    Pill { text: source.to_string() }
+                    if let Some(existing) = existing {
+                        Pill { text: existing, tone: Some(StateTone::Warning) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn part_of_speech_label(part_of_speech: &PartOfSpeech) -> &'static str {
+    match part_of_speech {
+        PartOfSpeech::Verb => "Глагол",
+        PartOfSpeech::Noun => "Существительное",
+        PartOfSpeech::IAdjective => "И-прилагательное",
+        PartOfSpeech::NaAdjective => "На-прилагательное",
+        PartOfSpeech::Adverb => "Наречие",
+        PartOfSpeech::PreNounAdjectival => "Предикатив",
+        PartOfSpeech::Conjunction => "Союз",
+        PartOfSpeech::Pronoun => "Местоимение",
+        PartOfSpeech::Numeral => "Числительное",
+        PartOfSpeech::Determiner => "Определитель",
+        _ => "Другое",
+    }
+}
@@ -0,0 +1,229 @@
+use dioxus::prelude::*;
+use keikaku::application::use_cases::check_conjugation_answer::{
+    CheckConjugationAnswerUseCase, ConjugationAnswerResult,
+};
+use keikaku::application::use_cases::start_conjugation_drill::{
+    ConjugationRuleStats, StartConjugationDrillUseCase,
+};
+use keikaku::domain::knowledge::ConjugationExercise;
+use keikaku::domain::transliteration::{finish_romaji_to_hiragana, romaji_to_hiragana};
+use keikaku::settings::ApplicationEnvironment;
+
+use crate::components::app_ui::{Card, H3, LoadingState, Pill, StateTone};
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::input::Input;
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+
+/// Количество упражнений в одной тренировке
+const DRILL_SIZE: usize = 10;
+
+/// Тренировка спряжения: словарная форма и правило, ответ вводится с клавиатуры
+#[component]
+pub fn ConjugationDrill() -> Element {
+    let mut exercises = use_resource(fetch_exercises);
+    let mut rule_stats = use_resource(fetch_rule_stats);
+    let mut index = use_signal(|| 0usize);
+    let mut correct_count = use_signal(|| 0usize);
+
+    let restart = move |_| {
+        index.set(0);
+        correct_count.set(0);
+        exercises.restart();
+        rule_stats.restart();
+    };
+
+    let content = match exercises.read().as_ref() {
+        None => rsx! {
+            LoadingState { message: Some("Подбор упражнений...".to_string()) }
+        },
+        Some(Err(e)) => rsx! {
+            p { class: "text-sm text-muted-foreground", "Ошибка: {e}" }
+        },
+        Some(Ok(items)) if items.is_empty() => rsx! {
+            p { class: "text-sm text-muted-foreground",
+                "Начните учить глаголы и прилагательные, чтобы тренировать их формы"
+            }
+        },
+        Some(Ok(items)) => match items.get(index()) {
+            Some(exercise) => rsx! {
+                p { class: "text-xs text-muted-foreground", "Упражнение {index() + 1} из {items.len()}" }
+                ConjugationExerciseView {
+                    key: "{index()}",
+                    exercise: exercise.clone(),
+                    on_next: move |is_correct: bool| {
+                        if is_correct {
+                            *correct_count.write() += 1;
+                        }
+                        *index.write() += 1;
+                    },
+                }
+            },
+            None => rsx! {
+                div { class: "flex items-center justify-between gap-4",
+                    p { class: "text-sm", "Правильных ответов: {correct_count()} из {items.len()}" }
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        class: "w-auto px-4",
+                        onclick: restart,
+                        "Еще раз"
+                    }
+                }
+            },
+        },
+    };
+
+    rsx! {
+        Card {
+            div { class: "space-y-4",
+                div { class: "flex items-center justify-between gap-4",
+                    H3 { "Тренировка спряжения" }
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        class: "w-auto px-4",
+                        onclick: restart,
+                        "Новые упражнения"
+                    }
+                }
+                {content}
+                if let Some(Ok(stats)) = rule_stats.read().as_ref() {
+                    RuleStatsList { stats: stats.clone() }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ConjugationExerciseView(exercise: ConjugationExercise, on_next: EventHandler<bool>) -> Element {
+    let mut answer = use_signal(String::new);
+    let mut result = use_signal(|| None::<ConjugationAnswerResult>);
+    let mut checking = use_signal(|| false);
+
+    let submit = use_callback({
+        let exercise = exercise.clone();
+        move |_: ()| {
+            if checking() {
+                return;
+            }
+            if let Some(result) = result() {
+                on_next.call(result.is_correct);
+                return;
+            }
+
+            let exercise = exercise.clone();
+            let typed = finish_romaji_to_hiragana(&answer());
+            spawn(async move {
+                checking.set(true);
+                match check_answer(&exercise, &typed).await {
+                    Ok(checked) => result.set(Some(checked)),
+                    Err(e) => error!("Failed to check conjugation: {}", e),
+                }
+                checking.set(false);
+            });
+        }
+    });
+
+    let expected = result()
+        .map(|checked| checked.expected.join(", "))
+        .unwrap_or_default();
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex flex-wrap items-center gap-2",
+                span { class: "text-2xl font-bold", "{exercise.word()}" }
+                Pill { text: exercise.rule_title().to_string(), tone: Some(StateTone::Info) }
+            }
+            Input {
+                value: answer(),
+                placeholder: "Форма слова: romaji или кана",
+                autofocus: true,
+                oninput: move |e: FormEvent| answer.set(romaji_to_hiragana(&e.value())),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Enter {
+                        e.prevent_default();
+                        submit.call(());
+                    }
+                },
+            }
+            if let Some(checked) = result() {
+                div { class: if checked.is_correct { "rounded-lg border border-emerald-200 bg-emerald-50 p-3 text-sm" } else { "rounded-lg border border-red-200 bg-red-50 p-3 text-sm" },
+                    if checked.is_correct {
+                        "Верно"
+                    } else {
+                        "Неверно. Ожидалось: {expected}"
+                    }
+                }
+            }
+            Button {
+                variant: ButtonVariant::Primary,
+                disabled: checking(),
+                onclick: move |_| submit.call(()),
+                if result().is_some() {
+                    "Дальше"
+                } else {
+                    "Проверить"
+                }
+            }
+        }
+    }
+}
+
+/// Точность по правилам, самые слабые сверху
+#[component]
+fn RuleStatsList(stats: Vec<ConjugationRuleStats>) -> Element {
+    if stats.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { class: "space-y-2",
+            span { class: "text-sm font-medium", "Точность по правилам" }
+            for rule in stats.iter() {
+                div {
+                    key: "{rule.rule_id}",
+                    class: "flex items-center justify-between gap-4 text-sm",
+                    span { "{rule.title}" }
+                    span { class: "text-muted-foreground",
+                        "{rule.stats.correct()} / {rule.stats.attempts()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn fetch_exercises() -> Result<Vec<ConjugationExercise>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    StartConjugationDrillUseCase::new(repo)
+        .execute(user_id, DRILL_SIZE)
+        .await
+        .map_err(to_error)
+}
+
+async fn fetch_rule_stats() -> Result<Vec<ConjugationRuleStats>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    StartConjugationDrillUseCase::new(repo)
+        .rule_stats(user_id)
+        .await
+        .map_err(to_error)
+}
+
+async fn check_answer(
+    exercise: &ConjugationExercise,
+    answer: &str,
+) -> Result<ConjugationAnswerResult, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    CheckConjugationAnswerUseCase::new(repo)
+        .execute(user_id, exercise, answer)
+        .await
+        .map_err(to_error)
+}
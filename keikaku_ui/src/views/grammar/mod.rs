@@ -0,0 +1,4 @@
+mod drill;
+mod view;
+
+pub use view::Grammar;
@@ -0,0 +1,209 @@
+use dioxus::prelude::*;
+use dioxus_primitives::checkbox::CheckboxState;
+use dioxus_primitives::toast::{ToastOptions, use_toast};
+use keikaku::application::use_cases::add_grammar_cards::{AddGrammarCardsUseCase, GrammarRuleItem};
+use keikaku::domain::value_objects::JapaneseLevel;
+use keikaku::settings::ApplicationEnvironment;
+use std::collections::BTreeSet;
+
+use crate::components::app_ui::{Card, ErrorCard, LoadingState, Pill, SectionHeader, StateTone};
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::checkbox::Checkbox;
+use crate::views::grammar::drill::ConjugationDrill;
+use crate::views::learn::GrammarCardView;
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+
+const LEVELS: [JapaneseLevel; 5] = [
+    JapaneseLevel::N5,
+    JapaneseLevel::N4,
+    JapaneseLevel::N3,
+    JapaneseLevel::N2,
+    JapaneseLevel::N1,
+];
+
+/// Встроенные правила грамматики по уровням с добавлением в колоду
+#[component]
+pub fn Grammar() -> Element {
+    let mut rules = use_resource(fetch_grammar_rules);
+    let mut level_filter = use_signal(|| None::<JapaneseLevel>);
+    let mut selected = use_signal(BTreeSet::<String>::new);
+    let mut adding = use_signal(|| false);
+    let toast = use_toast();
+
+    let add_cards = move |rule_ids: Option<Vec<String>>| {
+        spawn(async move {
+            adding.set(true);
+            match add_grammar_cards(rule_ids).await {
+                Ok(0) => toast.info(
+                    "Все выбранные правила уже изучаются".to_string(),
+                    ToastOptions::new(),
+                ),
+                Ok(count) => {
+                    toast.success(format!("Добавлено правил: {}", count), ToastOptions::new());
+                    selected.write().clear();
+                    rules.restart();
+                }
+                Err(e) => toast.error(format!("Ошибка: {}", e), ToastOptions::new()),
+            }
+            adding.set(false);
+        });
+    };
+
+    let content = match rules.read().as_ref() {
+        None => rsx! {
+            LoadingState { message: Some("Загрузка правил...".to_string()) }
+        },
+        Some(Err(e)) => rsx! {
+            ErrorCard { message: format!("Ошибка загрузки правил: {}", e) }
+        },
+        Some(Ok(items)) => {
+            let visible = items
+                .iter()
+                .filter(|rule| level_filter().is_none_or(|level| rule.level == level))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            rsx! {
+                if visible.is_empty() {
+                    p { class: "text-sm text-muted-foreground", "Для этого уровня пока нет правил" }
+                }
+                for rule in visible {
+                    GrammarRuleRow { key: "{rule.id}", rule: rule.clone(), selected }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "bg-bg min-h-screen text-text-main px-6 py-8 space-y-6",
+            SectionHeader {
+                title: "Грамматика".to_string(),
+                subtitle: Some("Отметьте правила, которые хотите изучать".to_string()),
+                actions: Some(rsx! {
+                    div { class: "flex gap-2",
+                        Button {
+                            variant: ButtonVariant::Outline,
+                            class: "w-auto px-4",
+                            disabled: adding(),
+                            onclick: move |_| add_cards(None),
+                            "Все до моего уровня"
+                        }
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            class: "w-auto px-4",
+                            disabled: adding() || selected.read().is_empty(),
+                            onclick: move |_| {
+                                let rule_ids = selected.read().iter().cloned().collect();
+                                add_cards(Some(rule_ids));
+                            },
+                            "Добавить выбранные"
+                        }
+                    }
+                }),
+            }
+
+            ConjugationDrill {}
+
+            div { class: "flex flex-wrap gap-2",
+                Button {
+                    variant: if level_filter().is_none() { ButtonVariant::Primary } else { ButtonVariant::Outline },
+                    class: "w-auto px-4",
+                    onclick: move |_| level_filter.set(None),
+                    "Все"
+                }
+                for level in LEVELS {
+                    Button {
+                        key: "{level.code()}",
+                        variant: if level_filter() == Some(level) { ButtonVariant::Primary } else { ButtonVariant::Outline },
+                        class: "w-auto px-4",
+                        onclick: move |_| level_filter.set(Some(level)),
+                        {level.code()}
+                    }
+                }
+            }
+
+            div { class: "space-y-3", {content} }
+        }
+    }
+}
+
+#[component]
+fn GrammarRuleRow(rule: GrammarRuleItem, selected: Signal<BTreeSet<String>>) -> Element {
+    let mut expanded = use_signal(|| false);
+    let checked = selected.read().contains(&rule.id);
+
+    rsx! {
+        Card {
+            div { class: "space-y-3",
+                div { class: "flex items-center justify-between gap-4",
+                    label { class: "flex items-center gap-3 cursor-pointer",
+                        if !rule.is_studying {
+                            Checkbox {
+                                checked: if checked { CheckboxState::Checked } else { CheckboxState::Unchecked },
+                                on_checked_change: {
+                                    let rule_id = rule.id.clone();
+                                    move |state: CheckboxState| {
+                                        let mut selected = selected;
+                                        let checked: bool = state.into();
+                                        if checked {
+                                            selected.write().insert(rule_id.clone());
+                                        } else {
+                                            selected.write().remove(&rule_id);
+                                        }
+                                    }
+                                },
+                            }
+                        }
+                        span { class: "text-sm font-medium", "{rule.title}" }
+                    }
+                    div { class: "flex items-center gap-2",
+                        Pill { text: rule.level.code().to_string(), tone: Some(StateTone::Info) }
+                        if rule.is_studying {
+                            Pill { text: "Изучается".to_string(), tone: Some(StateTone::Success) }
+                        }
+                        Button {
+                            variant: ButtonVariant::Ghost,
+                            class: "w-auto px-3 text-xs",
+                            onclick: move |_| expanded.toggle(),
+                            if expanded() {
+                                "Скрыть"
+                            } else {
+                                "Подробнее"
+                            }
+                        }
+                    }
+                }
+                if expanded() {
+                    GrammarCardView { markdown_content: rule.md_description.clone(), show_furigana: false }
+                }
+            }
+        }
+    }
+}
+
+async fn fetch_grammar_rules() -> Result<Vec<GrammarRuleItem>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    AddGrammarCardsUseCase::new(repo)
+        .rules(user_id)
+        .await
+        .map_err(to_error)
+}
+
+/// Добавляет выбранные правила, а без выбора все правила до уровня пользователя
+async fn add_grammar_cards(rule_ids: Option<Vec<String>>) -> Result<usize, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+    let use_case = AddGrammarCardsUseCase::new(repo);
+
+    let cards = match rule_ids {
+        Some(rule_ids) => use_case.execute(user_id, rule_ids).await,
+        None => use_case.execute_up_to_level(user_id).await,
+    }
+    .map_err(to_error)?;
+
+    Ok(cards.len())
+}
@@ -10,7 +10,8 @@ use crate::{DEFAULT_USERNAME, ensure_user, to_error};
 use dioxus_primitives::checkbox::CheckboxState;
 use keikaku::application::use_cases::{
     import_anki_pack::ExportAnkiPackUseCase, import_jlpt_recommended::ExportJlptRecommendedUseCase,
-    import_migii_pack::ExportMigiiPackUseCase, sync_duolingo_words::SyncDuolingoWordsUseCase,
+    import_migii_pack::ExportMigiiPackUseCase, mark_cards_as_known::MarkAsKnownOptions,
+    sync_duolingo_words::SyncDuolingoWordsUseCase,
 };
 use keikaku::domain::value_objects::JapaneseLevel;
 use keikaku::infrastructure::HttpDuolingoClient;
@@ -166,6 +167,8 @@ fn ImportDuolingoTool() -> Element {
 fn ImportJlptTool() -> Element {
     let levels = ["N5", "N4", "N3", "N2", "N1"];
     let selected = use_signal(|| vec!["N5".to_string(), "N4".to_string()]);
+    let mut mark_as_known = use_signal(|| false);
+    let spread_days = use_signal(|| MarkAsKnownOptions::default().spread_days.to_string());
     let log = use_signal(Vec::<String>::new);
     let status = use_signal(|| OperationStatus::Idle);
 
@@ -220,6 +223,34 @@ fn ImportJlptTool() -> Element {
                 }
             }
 
+            div { class: "flex items-center justify-between gap-4",
+                span { class: "text-sm font-medium",
+                    "Отметить как известные (не попадут в новые карточки)"
+                }
+                Switch {
+                    aria_label: "Отметить как известные",
+                    checked: mark_as_known(),
+                    on_checked_change: move |v| mark_as_known.set(v),
+                    disabled: matches!(status(), OperationStatus::Loading),
+                    SwitchThumb {}
+                }
+            }
+
+            if mark_as_known() {
+                div { class: "space-y-2",
+                    label { class: "text-sm font-medium", "РАСПРЕДЕЛИТЬ ПОВТОРЕНИЯ НА (ДНЕЙ)" }
+                    Input {
+                        placeholder: "28",
+                        value: spread_days(),
+                        oninput: {
+                            let mut spread_days = spread_days;
+                            move |e: FormEvent| spread_days.set(e.value())
+                        },
+                        disabled: matches!(status(), OperationStatus::Loading),
+                    }
+                }
+            }
+
             if matches!(status(), OperationStatus::Loading) {
                 LoadingState { message: Some("Создание пачки JLPT...".to_string()) }
             } else {
@@ -232,11 +263,13 @@ fn ImportJlptTool() -> Element {
                             return;
                         }
                         let levels = selected();
+                        let known_options = mark_as_known()
+                            .then(|| known_options_from_input(&spread_days()));
                         let mut log = log;
                         let mut status = status;
                         status.set(OperationStatus::Loading);
                         spawn(async move {
-                            match run_jlpt(levels).await {
+                            match run_jlpt(levels, known_options).await {
                                 Ok(msg) => {
                                     status.set(OperationStatus::Success(msg.clone()));
                                     log.write().push(msg);
@@ -468,7 +501,21 @@ async fn run_duolingo() -> Result<String, String> {
     ))
 }
 
-async fn run_jlpt(levels: Vec<String>) -> Result<String, String> {
+fn known_options_from_input(spread_days: &str) -> MarkAsKnownOptions {
+    let defaults = MarkAsKnownOptions::default();
+    MarkAsKnownOptions {
+        spread_days: spread_days
+            .trim()
+            .parse::<u32>()
+            .unwrap_or(defaults.spread_days),
+        ..defaults
+    }
+}
+
+async fn run_jlpt(
+    levels: Vec<String>,
+    known_options: Option<MarkAsKnownOptions>,
+) -> Result<String, String> {
     let env = ApplicationEnvironment::get();
     let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
     let repo = env.get_repository().await.map_err(to_error)?;
@@ -480,13 +527,14 @@ async fn run_jlpt(levels: Vec<String>) -> Result<String, String> {
         .collect::<Result<Vec<_>, _>>()?;
 
     let res = ExportJlptRecommendedUseCase::new(repo, &llm)
-        .execute(user_id, parsed_levels)
+        .execute(user_id, parsed_levels, known_options)
         .await
         .map_err(to_error)?;
 
     Ok(format!(
-        "JLPT: создано {}, пропущено {}",
+        "JLPT: создано {}, отмечено известными {}, пропущено {}",
         res.total_created_count,
+        res.known_count,
         res.skipped_words.len()
     ))
 }
@@ -502,11 +550,36 @@ async fn run_anki_dry(
     let repo = env.get_repository().await.map_err(to_error)?;
     let llm = env.get_llm_service(user_id).await.map_err(to_error)?;
     let use_case = ExportAnkiPackUseCase::new(repo, &llm);
-    let cards = use_case
-        .extract_cards(&file_path, &word_tag, Some(translation_tag.as_str()))
+    let result = use_case
+        .dry_run(
+            user_id,
+            &file_path,
+            &word_tag,
+            Some(translation_tag.as_str()),
+        )
         .await
         .map_err(to_error)?;
-    Ok(format!("Dry-run: найдено {} карточек", cards.len()))
+
+    let total =
+        result.new_words.len() + result.duplicate_words.len() + result.likely_duplicates.len();
+    let mut message = format!(
+        "Dry-run: найдено {}, новых {}, уже есть {}, вероятных дубликатов {}",
+        total,
+        result.new_words.len(),
+        result.duplicate_words.len(),
+        result.likely_duplicates.len()
+    );
+    if !result.likely_duplicates.is_empty() {
+        let duplicates = result
+            .likely_duplicates
+            .iter()
+            .map(|(word, existing)| format!("{} → {}", word, existing.join(", ")))
+            .collect::<Vec<_>>()
+            .join("; ");
+        message.push_str(&format!(" ({})", duplicates));
+    }
+
+    Ok(message)
 }
 
 async fn run_anki(
@@ -1,3 +1,4 @@
+mod sync;
 mod view;
 
 pub use view::Kanji;
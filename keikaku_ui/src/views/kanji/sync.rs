@@ -0,0 +1,164 @@
+use dioxus::prelude::*;
+use dioxus_primitives::checkbox::CheckboxState;
+use keikaku::application::use_cases::sync_kanji_cards::SyncKanjiCardsUseCase;
+use keikaku::settings::ApplicationEnvironment;
+use std::collections::BTreeSet;
+
+use crate::components::app_ui::{Card, H3, LoadingState, Pill, StateTone};
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::checkbox::Checkbox;
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+
+#[derive(Clone, PartialEq)]
+struct KanjiProposalItem {
+    kanji: char,
+    jlpt: String,
+    words: String,
+    known_words: usize,
+}
+
+/// Колода кандзи из слов пользователя. Повторная синхронизация предлагает только новые кандзи
+#[component]
+pub fn KanjiDeckSync() -> Element {
+    let mut proposals = use_resource(fetch_kanji_proposals);
+    let mut selected = use_signal(BTreeSet::<char>::new);
+    let mut creating = use_signal(|| false);
+    let mut message = use_signal(|| None::<String>);
+
+    use_effect(move || {
+        if let Some(Ok(items)) = proposals.read().as_ref() {
+            selected.set(items.iter().map(|item| item.kanji).collect());
+        }
+    });
+
+    let content = match proposals.read().as_ref() {
+        None => rsx! {
+            LoadingState { message: Some("Поиск кандзи в словах...".to_string()) }
+        },
+        Some(Err(e)) => rsx! {
+            p { class: "text-sm text-muted-foreground", "Ошибка: {e}" }
+        },
+        Some(Ok(items)) if items.is_empty() => rsx! {
+            p { class: "text-sm text-muted-foreground",
+                "Для всех кандзи из ваших слов уже есть карточки"
+            }
+        },
+        Some(Ok(items)) => rsx! {
+            div { class: "space-y-2 max-h-96 overflow-y-auto",
+                for item in items.iter() {
+                    KanjiProposalRow { key: "{item.kanji}", item: item.clone(), selected }
+                }
+            }
+        },
+    };
+
+    rsx! {
+        Card {
+            div { class: "space-y-4",
+                div { class: "flex items-center justify-between gap-4",
+                    H3 { "Колода кандзи" }
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        disabled: creating() || selected.read().is_empty(),
+                        onclick: move |_| {
+                            let kanji = selected.read().iter().copied().collect::<Vec<_>>();
+                            spawn(async move {
+                                creating.set(true);
+                                match create_kanji_cards(kanji).await {
+                                    Ok(count) => {
+                                        message.set(Some(format!("Создано карточек: {}", count)));
+                                        proposals.restart();
+                                    }
+                                    Err(e) => message.set(Some(format!("Ошибка: {}", e))),
+                                }
+                                creating.set(false);
+                            });
+                        },
+                        if creating() {
+                            "Создание..."
+                        } else {
+                            "Создать карточки"
+                        }
+                    }
+                }
+                p { class: "text-xs text-muted-foreground",
+                    "Кандзи из ваших слов не выше текущего уровня JLPT. Первыми идут кандзи из выученных слов"
+                }
+                if let Some(message) = message() {
+                    p { class: "text-sm", "{message}" }
+                }
+                {content}
+            }
+        }
+    }
+}
+
+#[component]
+fn KanjiProposalRow(item: KanjiProposalItem, selected: Signal<BTreeSet<char>>) -> Element {
+    let checked = selected.read().contains(&item.kanji);
+    let kanji = item.kanji;
+
+    rsx! {
+        label { class: "flex items-start gap-3 cursor-pointer",
+            Checkbox {
+                checked: if checked { CheckboxState::Checked } else { CheckboxState::Unchecked },
+                on_checked_change: move |state: CheckboxState| {
+                    let mut selected = selected;
+                    let checked: bool = state.into();
+                    if checked {
+                        selected.write().insert(kanji);
+                    } else {
+                        selected.write().remove(&kanji);
+                    }
+                },
+            }
+            span { class: "text-2xl font-bold", "{item.kanji}" }
+            div { class: "flex flex-col gap-1",
+                span { class: "text-sm", "{item.words}" }
+                div { class: "flex flex-wrap gap-1",
+                    Pill { text: item.jlpt.clone(), tone: Some(StateTone::Info) }
+                    if item.known_words > 0 {
+                        Pill {
+                            text: format!("выучено слов: {}", item.known_words),
+                            tone: Some(StateTone::Success),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn fetch_kanji_proposals() -> Result<Vec<KanjiProposalItem>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let proposals = SyncKanjiCardsUseCase::new(repo)
+        .propose(user_id)
+        .await
+        .map_err(to_error)?;
+
+    Ok(proposals
+        .iter()
+        .map(|proposal| KanjiProposalItem {
+            kanji: proposal.kanji(),
+            jlpt: proposal.jlpt().code().to_string(),
+            words: proposal.words().join(", "),
+            known_words: proposal.known_words(),
+        })
+        .collect())
+}
+
+async fn create_kanji_cards(kanji: Vec<char>) -> Result<usize, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let cards = SyncKanjiCardsUseCase::new(repo)
+        .execute(user_id, kanji)
+        .await
+        .map_err(to_error)?;
+
+    Ok(cards.len())
+}
@@ -2,6 +2,7 @@ use crate::components::app_ui::{Card, ErrorCard, H2};
 use crate::components::button::{Button, ButtonVariant};
 use crate::components::input::Input;
 use crate::domain::KanjiCard;
+use crate::views::kanji::sync::KanjiDeckSync;
 use dioxus::prelude::*;
 use keikaku::application::use_cases::get_kanji_info::GetKanjiInfoUseCase;
 use keikaku::domain::{dictionary::KanjiInfo, value_objects::NativeLanguage};
@@ -94,6 +95,8 @@ fn KanjiContent(
                     class: None,
                 }
             }
+
+            KanjiDeckSync {}
         }
     }
 }
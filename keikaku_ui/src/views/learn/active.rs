@@ -9,6 +9,8 @@ pub fn LearnActive(
     current_index: usize,
     current_step: super::LearnStep,
     show_furigana: bool,
+    typed_answers: bool,
+    answer_check: Signal<Option<super::CheckedAnswer>>,
     native_language: keikaku::domain::value_objects::NativeLanguage,
     on_next: EventHandler<()>,
     on_show_answer: EventHandler<()>,
@@ -33,6 +35,8 @@ pub fn LearnActive(
                 card: current_card,
                 current_step: current_step.clone(),
                 show_furigana,
+                typed_answers,
+                answer_check,
                 native_language,
                 on_show_answer,
                 on_next,
@@ -10,6 +10,8 @@ pub fn LearnCardDisplay(
     card: Option<super::LearnCard>,
     current_step: super::LearnStep,
     show_furigana: bool,
+    typed_answers: bool,
+    answer_check: Signal<Option<super::CheckedAnswer>>,
     native_language: NativeLanguage,
     on_show_answer: EventHandler<()>,
     on_next: EventHandler<()>,
@@ -25,6 +27,8 @@ pub fn LearnCardDisplay(
                             card: card.clone(),
                             current_step,
                             show_furigana,
+                            typed_answers,
+                            answer_check,
                             native_language: native_language.clone(),
                             on_show_answer: move |_| on_show_answer.call(()),
                             on_rate: move |rating| on_rate.call(rating),
@@ -50,6 +54,15 @@ pub fn LearnCardDisplay(
                             on_rate: move |rating| on_rate.call(rating),
                         }
                     },
+                    CardType::Sentence => rsx! {
+                        super::sentence_card::SentenceCardView {
+                            card: card.clone(),
+                            current_step,
+                            show_furigana,
+                            on_show_answer: move |_| on_show_answer.call(()),
+                            on_rate: move |rating| on_rate.call(rating),
+                        }
+                    },
                 }
             }
         }
@@ -0,0 +1,124 @@
+use dioxus::prelude::*;
+use keikaku::application::use_cases::knowledge_set_cards::KnowledgeSetCardsUseCase;
+use keikaku::application::use_cases::set_card_notes::SetCardNotesUseCase;
+use keikaku::settings::ApplicationEnvironment;
+use ulid::Ulid;
+
+use crate::components::app_ui::InfoSection;
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::textarea::Textarea;
+use crate::views::learn::card_display::GrammarCardView;
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+
+/// Заметки карточки на стороне ответа: просмотр и редактирование
+#[component]
+pub fn CardNotesSection(card_id: String, show_furigana: bool) -> Element {
+    let mut notes = use_resource({
+        let card_id = card_id.clone();
+        move || fetch_notes(card_id.clone())
+    });
+    let mut draft = use_signal(String::new);
+    let mut editing = use_signal(|| false);
+    let mut saving = use_signal(|| false);
+
+    // Карточки закрепления не связаны с сохраненными карточками
+    let Some(Ok(current)) = notes.read().clone() else {
+        return rsx! {};
+    };
+    let has_notes = current.is_some();
+
+    rsx! {
+        InfoSection { title: "Заметки".to_string(),
+            if editing() {
+                Textarea {
+                    rows: 5,
+                    placeholder: "Мнемоника, контекст, исходное предложение. Поддерживается markdown",
+                    value: draft(),
+                    oninput: move |e: FormEvent| draft.set(e.value()),
+                    onkeydown: move |e: KeyboardEvent| {
+                        // Клавиши урока не должны срабатывать во время ввода
+                        e.stop_propagation();
+                    },
+                }
+                div { class: "flex gap-2",
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        onclick: move |_| editing.set(false),
+                        "Отмена"
+                    }
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        disabled: saving(),
+                        onclick: {
+                            let card_id = card_id.clone();
+                            move |_| {
+                                let card_id = card_id.clone();
+                                spawn(async move {
+                                    saving.set(true);
+                                    match save_notes(card_id, draft()).await {
+                                        Ok(()) => {
+                                            editing.set(false);
+                                            notes.restart();
+                                        }
+                                        Err(e) => error!("Failed to save card notes: {e}"),
+                                    }
+                                    saving.set(false);
+                                });
+                            }
+                        },
+                        {if saving() { "Сохранение..." } else { "Сохранить" }}
+                    }
+                }
+            } else {
+                if let Some(text) = current.clone() {
+                    GrammarCardView { markdown_content: text, show_furigana }
+                }
+                Button {
+                    variant: ButtonVariant::Ghost,
+                    onclick: {
+                        let current = current.clone();
+                        move |_| {
+                            draft.set(current.clone().unwrap_or_default());
+                            editing.set(true);
+                        }
+                    },
+                    if has_notes {
+                        "Изменить заметки"
+                    } else {
+                        "Добавить заметку"
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn fetch_notes(card_id: String) -> Result<Option<String>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let card_id = card_id.parse::<Ulid>().map_err(|e| e.to_string())?;
+    let cards = KnowledgeSetCardsUseCase::new(repo)
+        .execute(user_id)
+        .await
+        .map_err(to_error)?;
+
+    cards
+        .iter()
+        .find(|card| *card.card_id() == card_id)
+        .map(|card| card.notes().map(str::to_string))
+        .ok_or_else(|| format!("Card {card_id} not found"))
+}
+
+async fn save_notes(card_id: String, notes: String) -> Result<(), String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let card_id = card_id.parse::<Ulid>().map_err(|e| e.to_string())?;
+    SetCardNotesUseCase::new(repo)
+        .execute(user_id, card_id, notes)
+        .await
+        .map_err(to_error)
+}
@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
 
+use super::CardNotesSection;
 use crate::domain::{AnswerActionButtons, Rating, WordCard};
 use crate::views::learn::learn_session::{LearnCard, LearnStep};
 use keikaku::domain::value_objects::NativeLanguage;
@@ -123,6 +124,11 @@ fn GrammarAnswerView(
                 // Right column: Action buttons
                 div { class: "space-y-2",
                     AnswerActionButtons { on_rate }
+                    CardNotesSection {
+                        key: "{card.id}",
+                        card_id: card.id.clone(),
+                        show_furigana,
+                    }
                 }
             }
         }
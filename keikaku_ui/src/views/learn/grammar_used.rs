@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+use keikaku::application::use_cases::{DetectGrammarUseCase, DetectedGrammar};
+use keikaku::settings::ApplicationEnvironment;
+
+use crate::components::app_ui::{InfoSection, InfoSectionTone, Pill, StateTone};
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+
+/// Правила грамматики, использованные в тексте, на стороне ответа
+#[component]
+pub fn GrammarUsedSection(text: String) -> Element {
+    let detected = use_resource({
+        let text = text.clone();
+        move || fetch_detected_grammar(text.clone())
+    });
+
+    let Some(Ok(detected)) = detected.read().clone() else {
+        return rsx! {};
+    };
+    if detected.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        InfoSection {
+            title: "Грамматика в тексте".to_string(),
+            tone: InfoSectionTone::Purple,
+            div { class: "space-y-2",
+                for item in detected.iter() {
+                    div {
+                        key: "{item.rule_id}",
+                        class: "flex flex-wrap items-center gap-2",
+                        span { class: "text-lg", "{item.fragment}" }
+                        span { class: "text-sm text-slate-600", "{item.title}" }
+                        Pill { text: item.level.code().to_string(), tone: Some(StateTone::Info) }
+                        if item.is_studying {
+                            Pill {
+                                text: "Изучается".to_string(),
+                                tone: Some(StateTone::Success),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn fetch_detected_grammar(text: String) -> Result<Vec<DetectedGrammar>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    DetectGrammarUseCase::new(repo)
+        .execute(user_id, &text)
+        .await
+        .map_err(to_error)
+}
@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
 
+use super::CardNotesSection;
 use crate::components::app_ui::{InfoSection, InfoSectionTone};
 use crate::domain::{AnswerActionButtons, FuriganaText, RadicalGrid, Rating, WordCard};
 use crate::views::learn::learn_session::{LearnCard, LearnStep};
@@ -117,6 +118,11 @@ fn KanjiAnswerView(
                 // Right column: Action buttons
                 div { class: "space-y-2",
                     AnswerActionButtons { on_rate }
+                    CardNotesSection {
+                        key: "{card.id}",
+                        card_id: card.id.clone(),
+                        show_furigana,
+                    }
                 }
             }
 
@@ -1,7 +1,8 @@
+use keikaku::application::use_cases::check_typed_answer::TypedAnswerResult;
 use keikaku::domain::{
     dictionary::{KanjiInfo, RadicalInfo},
     knowledge::ExampleKanjiWord,
-    value_objects::{ExamplePhrase, JapaneseLevel},
+    value_objects::{ExamplePhrase, JapaneseLevel, ReviewDirection},
 };
 
 #[derive(Clone, PartialEq)]
@@ -31,6 +32,7 @@ pub enum CardType {
     Vocabulary,
     Kanji,
     Grammar,
+    Sentence,
 }
 
 #[derive(Clone, PartialEq)]
@@ -39,7 +41,9 @@ pub struct LearnCard {
     pub card_type: CardType,
     pub question: String,
     pub answer: String,
+    pub direction: ReviewDirection,
     // Для vocabulary:
+    pub reading: Option<String>,
     pub example_phrases: Vec<ExamplePhrase>,
     pub kanji_info: Vec<KanjiInfo>,
     // Для kanji:
@@ -48,4 +52,15 @@ pub struct LearnCard {
     pub jlpt_level: JapaneseLevel,
     // Для grammar:
     pub markdown_description: Option<String>,
+    // Для sentence:
+    pub sentence: Option<String>,
+    pub cloze_answers: Vec<String>,
+}
+
+/// Проверенный введенный ответ на карточку
+#[derive(Clone, PartialEq)]
+pub struct CheckedAnswer {
+    pub card_id: String,
+    pub answer: String,
+    pub result: TypedAnswerResult,
 }
@@ -8,14 +8,28 @@ mod progress;
 use progress::LearnProgress;
 
 mod card_display;
+pub(crate) use card_display::GrammarCardView;
 use card_display::LearnCardDisplay;
 
+mod card_notes;
+use card_notes::CardNotesSection;
+
+mod grammar_used;
+use grammar_used::GrammarUsedSection;
+
 mod grammar_card;
 mod kanji_card;
+mod sentence_card;
 mod vocabulary_card;
 
+mod tag_filter;
+use tag_filter::LessonTagFilter;
+
+mod typed_answer;
+use typed_answer::TypedAnswerToggle;
+
 mod session_manager;
 use session_manager::use_learn_session;
 
 mod learn_session;
-use learn_session::{LearnCard, LearnStep, SessionState, StartFeedback};
+use learn_session::{CheckedAnswer, LearnCard, LearnStep, SessionState, StartFeedback};
@@ -0,0 +1,145 @@
+use dioxus::prelude::*;
+
+use super::{CardNotesSection, GrammarUsedSection};
+use crate::components::app_ui::{Pill, StateTone};
+use crate::domain::{AnswerActionButtons, Rating, WordCard};
+use crate::views::learn::learn_session::{LearnCard, LearnStep};
+
+#[component]
+pub fn SentenceCardView(
+    card: LearnCard,
+    current_step: LearnStep,
+    show_furigana: bool,
+    on_show_answer: EventHandler<()>,
+    on_rate: EventHandler<Rating>,
+) -> Element {
+    match current_step {
+        LearnStep::Question => rsx! {
+            SentenceQuestionView {
+                question: card.question,
+                show_furigana,
+                on_show_answer,
+            }
+        },
+        LearnStep::Answer => rsx! {
+            SentenceAnswerView { card, show_furigana, on_rate }
+        },
+        LearnStep::Completed => rsx! {
+            SentenceCompletedView { card, show_furigana }
+        },
+    }
+}
+
+#[component]
+fn SentenceQuestionView(
+    question: String,
+    show_furigana: bool,
+    on_show_answer: EventHandler<()>,
+) -> Element {
+    rsx! {
+        div { class: "space-y-6",
+            div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6",
+                // Left column: Sentence with clozes
+                div { class: "lg:col-span-2 space-y-2",
+                    div { class: "text-xs text-slate-500 uppercase tracking-wide font-semibold",
+                        "Предложение"
+                    }
+                    WordCard { text: question, show_furigana, class: None }
+                }
+
+                div { class: "space-y-2 flex flex-col h-full",
+                    super::card_display::QuestionActionButtons { on_show_answer }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SentenceAnswerView(
+    card: LearnCard,
+    show_furigana: bool,
+    on_rate: EventHandler<Rating>,
+) -> Element {
+    let sentence = card
+        .sentence
+        .clone()
+        .unwrap_or_else(|| card.question.clone());
+
+    rsx! {
+        div { class: "space-y-6",
+            div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6",
+                // Left column: Sentence + Translation
+                div { class: "lg:col-span-2 space-y-4",
+                    div { class: "space-y-2",
+                        div { class: "text-xs text-slate-500 uppercase tracking-wide font-semibold",
+                            "Предложение"
+                        }
+                        WordCard { text: sentence.clone(), show_furigana, class: None }
+                    }
+
+                    if !card.cloze_answers.is_empty() {
+                        div { class: "flex flex-wrap gap-2",
+                            for (index , answer) in card.cloze_answers.iter().enumerate() {
+                                Pill {
+                                    key: "{index}",
+                                    text: answer.clone(),
+                                    tone: Some(StateTone::Info),
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "space-y-2",
+                        div { class: "text-xs text-slate-500 uppercase tracking-wide font-semibold",
+                            "Перевод"
+                        }
+                        WordCard {
+                            text: card.answer.clone(),
+                            show_furigana,
+                            class: Some("text-lg md:text-xl".to_string()),
+                        }
+                    }
+
+                    GrammarUsedSection { key: "{card.id}", text: sentence.clone() }
+                }
+
+                // Right column: Action buttons
+                div { class: "space-y-2",
+                    AnswerActionButtons { on_rate }
+                    CardNotesSection {
+                        key: "{card.id}",
+                        card_id: card.id.clone(),
+                        show_furigana,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SentenceCompletedView(card: LearnCard, show_furigana: bool) -> Element {
+    let sentence = card
+        .sentence
+        .clone()
+        .unwrap_or_else(|| card.question.clone());
+
+    rsx! {
+        div { class: "space-y-6",
+            div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6",
+                div { class: "lg:col-span-2 space-y-2",
+                    WordCard { text: sentence, show_furigana, class: None }
+                    WordCard {
+                        text: card.answer.clone(),
+                        show_furigana,
+                        class: Some("text-lg md:text-xl".to_string()),
+                    }
+                }
+
+                // Right column: Empty for consistency
+                div { class: "space-y-2" }
+            }
+        }
+    }
+}
@@ -7,15 +7,16 @@ use keikaku::application::use_cases::{
     select_cards_to_fixation::SelectCardsToFixationUseCase,
     select_cards_to_lesson::SelectCardsToLessonUseCase,
 };
-use keikaku::domain::knowledge::Card;
+use keikaku::domain::knowledge::{Card, SentenceCard};
+use keikaku::domain::value_objects::ReviewDirection;
 use keikaku::settings::ApplicationEnvironment;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::rc::Rc;
 use ulid::Ulid;
 
 use crate::{DEFAULT_USERNAME, ensure_user, to_error};
 
-use super::{LearnCard, LearnStep, SessionState, StartFeedback};
+use super::{CheckedAnswer, LearnCard, LearnStep, SessionState, StartFeedback};
 
 #[derive(Clone, PartialEq)]
 pub struct LearnSessionData {
@@ -45,19 +46,26 @@ impl Default for LearnSessionData {
 pub fn use_learn_session() -> LearnSessionSignals {
     let state = use_signal(|| SessionState::Start);
     let session_data = use_signal(LearnSessionData::default);
+    let lesson_tag = use_signal(|| None::<String>);
+    let typed_answers = use_signal(|| false);
+    let answer_check = use_signal(|| None::<CheckedAnswer>);
 
     LearnSessionSignals {
         state,
         session_data,
+        lesson_tag,
+        typed_answers,
+        answer_check,
         start_session: Rc::new(move || {
             let mut state = state;
             let mut session_data = session_data;
+            let tags = lesson_tag().into_iter().collect::<BTreeSet<_>>();
 
             spawn(async move {
                 state.set(SessionState::Loading);
                 session_data.write().start_feedback = StartFeedback::None;
 
-                match fetch_cards_to_learn().await {
+                match fetch_cards_to_learn(tags).await {
                     Ok(items) => {
                         if items.is_empty() {
                             state.set(SessionState::Start);
@@ -182,13 +190,16 @@ pub fn use_learn_session() -> LearnSessionSignals {
                 let data = session_data.read();
                 let current_index = data.current_index;
                 let cards_len = data.cards.len();
-                let card_id = data.cards.get(current_index).map(|c| c.id.clone());
+                let card = data
+                    .cards
+                    .get(current_index)
+                    .map(|c| (c.id.clone(), c.direction));
 
-                if let Some(card_id_str) = card_id
+                if let Some((card_id_str, direction)) = card
                     && let Ok(card_ulid) = ulid::Ulid::from_string(&card_id_str)
                 {
                     // Rate the card
-                    if let Err(e) = rate_card_impl(card_ulid, rating).await {
+                    if let Err(e) = rate_card_impl(card_ulid, direction, rating).await {
                         error!("Failed to rate card: {:?}", e);
                     }
 
@@ -226,6 +237,12 @@ pub fn use_learn_session() -> LearnSessionSignals {
 pub struct LearnSessionSignals {
     pub state: Signal<SessionState>,
     pub session_data: Signal<LearnSessionData>,
+    /// Тег, которым ограничен урок. `None` — все карточки
+    pub lesson_tag: Signal<Option<String>>,
+    /// Режим ввода ответа для чтения и воспроизведения слов
+    pub typed_answers: Signal<bool>,
+    /// Результат проверки введенного ответа на текущую карточку
+    pub answer_check: Signal<Option<CheckedAnswer>>,
     pub start_session: Rc<dyn Fn()>,
     pub next_card: Rc<dyn Fn()>,
     pub restart_session: Rc<dyn Fn()>,
@@ -235,12 +252,14 @@ pub struct LearnSessionSignals {
     pub start_high_difficulty_session: Rc<dyn Fn()>,
 }
 
-async fn fetch_cards_to_learn() -> Result<HashMap<Ulid, Card>, String> {
+async fn fetch_cards_to_learn(
+    tags: BTreeSet<String>,
+) -> Result<HashMap<Ulid, (ReviewDirection, Card)>, String> {
     let env = ApplicationEnvironment::get();
     let repo = env.get_repository().await.map_err(to_error)?;
     let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
     SelectCardsToLessonUseCase::new(repo)
-        .execute(user_id)
+        .execute(user_id, tags)
         .await
         .map_err(to_error)
 }
@@ -262,6 +281,8 @@ fn map_card_to_learn_card(card: Card) -> LearnCard {
             card_type: CardType::Vocabulary,
             question: v.word().text().to_string(),
             answer: v.meaning().text().to_string(),
+            direction: ReviewDirection::Recognition,
+            reading: v.reading().map(str::to_string),
             example_phrases: v.example_phrases().to_vec(),
             kanji_info: v
                 .get_kanji_cards(&keikaku::domain::value_objects::JapaneseLevel::N5)
@@ -272,12 +293,16 @@ fn map_card_to_learn_card(card: Card) -> LearnCard {
             radicals: vec![],
             jlpt_level: keikaku::domain::value_objects::JapaneseLevel::N5, // TODO: Add proper level
             markdown_description: None,
+            sentence: None,
+            cloze_answers: vec![],
         },
         Card::Kanji(k) => LearnCard {
             id: ulid::Ulid::new().to_string(), // Generate a temporary ID
             card_type: CardType::Kanji,
             question: k.kanji().text().to_string(),
             answer: k.description().text().to_string(),
+            direction: ReviewDirection::Recognition,
+            reading: None,
             example_phrases: vec![],
             kanji_info: vec![],
             example_words: k.example_words().to_vec(),
@@ -289,45 +314,68 @@ fn map_card_to_learn_card(card: Card) -> LearnCard {
                 .collect(),
             jlpt_level: k.jlpt(),
             markdown_description: None,
+            sentence: None,
+            cloze_answers: vec![],
         },
         Card::Grammar(g) => LearnCard {
             id: ulid::Ulid::new().to_string(), // Generate a temporary ID
             card_type: CardType::Grammar,
             question: g.title().text().to_string(),
             answer: g.description().text().to_string(),
+            direction: ReviewDirection::Recognition,
+            reading: None,
             example_phrases: vec![],
             kanji_info: vec![],
             example_words: vec![],
             radicals: vec![],
             jlpt_level: keikaku::domain::value_objects::JapaneseLevel::N5, // TODO: Grammar rules don't have JLPT level
             markdown_description: Some(g.description().text().to_string()),
+            sentence: None,
+            cloze_answers: vec![],
         },
+        Card::Sentence(s) => map_sentence_to_learn_card(ulid::Ulid::new().to_string(), s),
     }
 }
 
-fn map_study_item_to_learn_card((card_id, card): (Ulid, Card)) -> LearnCard {
+fn map_study_item_to_learn_card(
+    (card_id, (direction, card)): (Ulid, (ReviewDirection, Card)),
+) -> LearnCard {
     match card {
-        Card::Vocabulary(v) => LearnCard {
-            id: card_id.to_string(),
-            card_type: CardType::Vocabulary,
-            question: v.word().text().to_string(),
-            answer: v.meaning().text().to_string(),
-            example_phrases: v.example_phrases().to_vec(),
-            kanji_info: v
-                .get_kanji_cards(&keikaku::domain::value_objects::JapaneseLevel::N5)
-                .into_iter()
-                .cloned()
-                .collect(), // TODO: Use proper level
-            example_words: vec![],
-            radicals: vec![],
-            jlpt_level: keikaku::domain::value_objects::JapaneseLevel::N5, // TODO: Add proper level
-            markdown_description: None,
-        },
+        Card::Vocabulary(v) => {
+            let (question, answer) = match direction {
+                // При воспроизведении по значению нужно вспомнить слово
+                ReviewDirection::Production => (v.meaning().text(), v.word().text()),
+                _ => (v.word().text(), v.meaning().text()),
+            };
+
+            LearnCard {
+                id: card_id.to_string(),
+                card_type: CardType::Vocabulary,
+                question: question.to_string(),
+                answer: answer.to_string(),
+                direction,
+                reading: v.reading().map(str::to_string),
+                example_phrases: v.example_phrases().to_vec(),
+                kanji_info: v
+                    .get_kanji_cards(&keikaku::domain::value_objects::JapaneseLevel::N5)
+                    .into_iter()
+                    .cloned()
+                    .collect(), // TODO: Use proper level
+                example_words: vec![],
+                radicals: vec![],
+                jlpt_level: keikaku::domain::value_objects::JapaneseLevel::N5, // TODO: Add proper level
+                markdown_description: None,
+                sentence: None,
+                cloze_answers: vec![],
+            }
+        }
         Card::Kanji(k) => LearnCard {
             id: card_id.to_string(),
             card_type: CardType::Kanji,
             question: k.kanji().text().to_string(),
             answer: k.description().text().to_string(),
+            direction: ReviewDirection::Recognition,
+            reading: None,
             example_phrases: vec![],
             kanji_info: vec![],
             example_words: k.example_words().to_vec(),
@@ -339,23 +387,54 @@ fn map_study_item_to_learn_card((card_id, card): (Ulid, Card)) -> LearnCard {
                 .collect(),
             jlpt_level: k.jlpt(),
             markdown_description: None,
+            sentence: None,
+            cloze_answers: vec![],
         },
         Card::Grammar(g) => LearnCard {
             id: card_id.to_string(),
             card_type: CardType::Grammar,
             question: g.title().text().to_string(),
             answer: g.description().text().to_string(),
+            direction: ReviewDirection::Recognition,
+            reading: None,
             example_phrases: vec![],
             kanji_info: vec![],
             example_words: vec![],
             radicals: vec![],
             jlpt_level: keikaku::domain::value_objects::JapaneseLevel::N5, // TODO: Grammar rules don't have JLPT level
             markdown_description: Some(g.description().text().to_string()),
+            sentence: None,
+            cloze_answers: vec![],
         },
+        Card::Sentence(s) => map_sentence_to_learn_card(card_id.to_string(), s),
+    }
+}
+
+/// Вопрос — предложение с пропусками, ответ — перевод и вырезанные слова
+fn map_sentence_to_learn_card(id: String, card: SentenceCard) -> LearnCard {
+    LearnCard {
+        id,
+        card_type: CardType::Sentence,
+        question: card.cloze_text(),
+        answer: card.translation().text().to_string(),
+        direction: ReviewDirection::Recognition,
+        reading: None,
+        example_phrases: vec![],
+        kanji_info: vec![],
+        example_words: vec![],
+        radicals: vec![],
+        jlpt_level: keikaku::domain::value_objects::JapaneseLevel::N5,
+        markdown_description: None,
+        sentence: Some(card.sentence().text().to_string()),
+        cloze_answers: card.cloze_answers(),
     }
 }
 
-async fn rate_card_impl(card_id: Ulid, rating: crate::domain::Rating) -> Result<(), String> {
+async fn rate_card_impl(
+    card_id: Ulid,
+    direction: ReviewDirection,
+    rating: crate::domain::Rating,
+) -> Result<(), String> {
     let env = ApplicationEnvironment::get();
     let repo = env.get_repository().await.map_err(to_error)?;
     let srs_service = env.get_srs_service().await.map_err(to_error)?;
@@ -369,9 +448,10 @@ async fn rate_card_impl(card_id: Ulid, rating: crate::domain::Rating) -> Result<
         crate::domain::Rating::Again => keikaku::domain::Rating::Again,
     };
     rate_usecase
-        .execute(
+        .execute_direction(
             user_id,
             card_id,
+            direction,
             keikaku::application::srs_service::RateMode::Standard,
             domain_rating,
         )
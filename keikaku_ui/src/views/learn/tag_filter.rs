@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+use keikaku::application::use_cases::knowledge_set_cards::KnowledgeSetCardsUseCase;
+use keikaku::settings::ApplicationEnvironment;
+use std::collections::BTreeSet;
+
+use crate::components::select::{
+    Select, SelectItemIndicator, SelectList, SelectOption, SelectTrigger, SelectValue,
+};
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+
+const ALL_TAGS_VALUE: &str = "";
+
+#[component]
+pub fn LessonTagFilter(lesson_tag: Signal<Option<String>>) -> Element {
+    let tags_resource = use_resource(fetch_tags);
+    let tags = tags_resource
+        .read()
+        .as_ref()
+        .and_then(|tags| tags.as_ref().ok())
+        .cloned()
+        .unwrap_or_default();
+
+    if tags.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { class: "min-w-[200px]",
+            Select::<String> {
+                value: Some(Some(lesson_tag().unwrap_or_else(|| ALL_TAGS_VALUE.to_string()))),
+                on_value_change: move |v: Option<String>| {
+                    lesson_tag.set(v.filter(|v| v != ALL_TAGS_VALUE));
+                },
+                placeholder: "Все карточки",
+                SelectTrigger { aria_label: "Тег урока", width: "100%", SelectValue {} }
+                SelectList { aria_label: "Тег урока",
+                    SelectOption::<String> { index: 0usize, value: ALL_TAGS_VALUE.to_string(),
+                        "Все карточки"
+                        SelectItemIndicator {}
+                    }
+                    for (index , tag) in tags.into_iter().enumerate() {
+                        SelectOption::<String> {
+                            key: "{tag}",
+                            index: index + 1,
+                            value: tag.clone(),
+                            "{tag}"
+                            SelectItemIndicator {}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn fetch_tags() -> Result<BTreeSet<String>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    let cards = KnowledgeSetCardsUseCase::new(repo)
+        .execute(user_id)
+        .await
+        .map_err(to_error)?;
+
+    Ok(cards
+        .iter()
+        .flat_map(|card| card.tags().iter().cloned())
+        .collect())
+}
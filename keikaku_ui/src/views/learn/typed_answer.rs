@@ -0,0 +1,153 @@
+use chrono::Utc;
+use dioxus::prelude::*;
+use keikaku::application::use_cases::check_typed_answer::{
+    CheckTypedAnswerUseCase, TypedAnswerResult,
+};
+use keikaku::domain::Rating;
+use keikaku::domain::transliteration::{finish_romaji_to_hiragana, romaji_to_hiragana};
+use keikaku::domain::value_objects::ReviewDirection;
+use keikaku::settings::ApplicationEnvironment;
+use ulid::Ulid;
+
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::input::Input;
+use crate::components::switch::{Switch, SwitchThumb};
+use crate::views::learn::learn_session::CheckedAnswer;
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+
+#[component]
+pub fn TypedAnswerToggle(typed_answers: Signal<bool>) -> Element {
+    rsx! {
+        div { class: "flex items-center gap-2",
+            span { class: "text-sm font-medium", "Ввод ответа" }
+            Switch {
+                aria_label: "Вводить ответ",
+                checked: typed_answers(),
+                on_checked_change: move |v: bool| typed_answers.set(v),
+                SwitchThumb {}
+            }
+        }
+    }
+}
+
+/// Поле ответа. Латиница сразу переводится в кану
+#[component]
+pub fn TypedAnswerInput(
+    card_id: String,
+    direction: ReviewDirection,
+    answer_check: Signal<Option<CheckedAnswer>>,
+    on_show_answer: EventHandler<()>,
+) -> Element {
+    let mut answer = use_signal(String::new);
+    let mut checking = use_signal(|| false);
+    let started_at = use_hook(Utc::now);
+
+    let submit = use_callback(move |_: ()| {
+        if checking() {
+            return;
+        }
+
+        let mut answer_check = answer_check;
+        let card_id = card_id.clone();
+        let typed = finish_romaji_to_hiragana(&answer());
+        let answer_time = Utc::now().signed_duration_since(started_at);
+
+        spawn(async move {
+            checking.set(true);
+            match check_answer(&card_id, direction, typed.clone(), answer_time).await {
+                Ok(result) => answer_check.set(Some(CheckedAnswer {
+                    card_id,
+                    answer: typed,
+                    result,
+                })),
+                Err(e) => {
+                    answer_check.set(None);
+                    error!("Failed to check answer: {}", e);
+                }
+            }
+            checking.set(false);
+            on_show_answer.call(());
+        });
+    });
+
+    let placeholder = match direction {
+        ReviewDirection::Reading => "Чтение: romaji или кана",
+        _ => "Слово на японском",
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-3 justify-center h-full",
+            Input {
+                value: answer(),
+                placeholder,
+                autofocus: true,
+                oninput: move |e: FormEvent| answer.set(romaji_to_hiragana(&e.value())),
+                onkeydown: move |e: KeyboardEvent| {
+                    // Клавиши урока не должны срабатывать во время ввода
+                    e.stop_propagation();
+                    if e.key() == Key::Enter {
+                        e.prevent_default();
+                        submit.call(());
+                    }
+                },
+            }
+            Button {
+                variant: ButtonVariant::Primary,
+                disabled: checking(),
+                onclick: move |_| submit.call(()),
+                "Проверить"
+            }
+        }
+    }
+}
+
+#[component]
+pub fn AnswerCheckResult(checked: CheckedAnswer) -> Element {
+    let result = &checked.result;
+    let expected = result.expected.join(", ");
+    let rating = match result.suggested_rating {
+        Rating::Easy => "Легко (1)",
+        Rating::Good => "Хорошо (2)",
+        Rating::Hard => "Сложно (3)",
+        Rating::Again => "Снова (4)",
+    };
+    let class = if result.is_correct {
+        "rounded-lg border border-emerald-200 bg-emerald-50 p-3 space-y-1 text-sm"
+    } else {
+        "rounded-lg border border-red-200 bg-red-50 p-3 space-y-1 text-sm"
+    };
+
+    rsx! {
+        div { class,
+            div { class: "font-semibold",
+                if result.is_correct {
+                    "Верно"
+                } else {
+                    "Неверно"
+                }
+            }
+            div { "Ваш ответ: {checked.answer}" }
+            if !result.is_correct {
+                div { "Ожидалось: {expected}" }
+            }
+            div { class: "text-slate-600", "Рекомендуемая оценка: {rating}" }
+        }
+    }
+}
+
+async fn check_answer(
+    card_id: &str,
+    direction: ReviewDirection,
+    answer: String,
+    answer_time: chrono::Duration,
+) -> Result<TypedAnswerResult, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+    let card_id = card_id.parse::<Ulid>().map_err(|e| e.to_string())?;
+
+    CheckTypedAnswerUseCase::new(repo)
+        .execute(user_id, card_id, direction, answer, answer_time)
+        .await
+        .map_err(to_error)
+}
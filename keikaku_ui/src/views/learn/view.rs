@@ -1,15 +1,25 @@
+use std::collections::HashSet;
+
 use chrono::Utc;
 use dioxus::{document::eval, prelude::*};
+use keikaku::application::use_cases::GetKnownKanjiUseCase;
+use keikaku::settings::ApplicationEnvironment;
 
-use super::{LearnActive, SessionState, StartFeedback, use_learn_session};
+use super::{
+    LearnActive, LessonTagFilter, SessionState, StartFeedback, TypedAnswerToggle, use_learn_session,
+};
 use crate::components::app_ui::{Card, LoadingState, Paragraph, SectionHeader};
 use crate::components::button::{Button, ButtonVariant};
+use crate::domain::KnownKanji;
 use crate::views::Overview;
 use crate::views::learn::session_manager::complete_lesson_impl;
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
 
 #[component]
 pub fn Learn() -> Element {
     let session = use_learn_session();
+    let known_kanji = use_resource(fetch_known_kanji);
+    use_context_provider(|| KnownKanji(known_kanji));
 
     let keyboard_handler = {
         let session = session.clone();
@@ -117,6 +127,8 @@ pub fn Learn() -> Element {
                         subtitle: Some("Нажми «Учиться», чтобы начать урок".to_string()),
                         actions: Some(rsx! {
                             div { class: "flex gap-3",
+                                LessonTagFilter { lesson_tag: session.lesson_tag }
+                                TypedAnswerToggle { typed_answers: session.typed_answers }
                                 Button {
                                     variant: ButtonVariant::Primary,
                                     class: "w-auto px-6",
@@ -185,6 +197,8 @@ pub fn Learn() -> Element {
                                 current_index: session_data.current_index,
                                 current_step: session_data.current_step.clone(),
                                 show_furigana: session_data.show_furigana,
+                                typed_answers: (session.typed_answers)(),
+                                answer_check: session.answer_check,
                                 native_language: keikaku::domain::value_objects::NativeLanguage::Russian,
                                 on_next: EventHandler::new({
                                     let next_card = session.next_card.clone();
@@ -222,3 +236,14 @@ pub fn Learn() -> Element {
         }
     }
 }
+
+async fn fetch_known_kanji() -> Result<Option<HashSet<char>>, String> {
+    let env = ApplicationEnvironment::get();
+    let repo = env.get_repository().await.map_err(to_error)?;
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+
+    GetKnownKanjiUseCase::new(repo)
+        .execute(user_id)
+        .await
+        .map_err(to_error)
+}
@@ -1,32 +1,58 @@
 use dioxus::prelude::*;
 
+use super::{CardNotesSection, GrammarUsedSection};
 use crate::components::app_ui::{InfoSection, InfoSectionTone};
 use crate::domain::{
     AnswerActionButtons, FuriganaText, KanjiCard as DomainKanjiCard, Rating, WordCard,
 };
-use crate::views::learn::learn_session::{LearnCard, LearnStep};
-use keikaku::domain::value_objects::NativeLanguage;
+use crate::views::learn::learn_session::{CheckedAnswer, LearnCard, LearnStep};
+use crate::views::learn::typed_answer::{AnswerCheckResult, TypedAnswerInput};
+use keikaku::application::use_cases::GetConjugationTableUseCase;
+use keikaku::domain::transliteration::{RomajiSystem, kana_to_romaji};
+use keikaku::domain::value_objects::{NativeLanguage, ReviewDirection};
 
 #[component]
 pub fn VocabularyCardView(
     card: LearnCard,
     current_step: LearnStep,
     show_furigana: bool,
+    typed_answers: bool,
+    answer_check: Signal<Option<CheckedAnswer>>,
     native_language: NativeLanguage,
     on_show_answer: EventHandler<()>,
     on_rate: EventHandler<Rating>,
 ) -> Element {
+    // Ввод ответа возможен, когда нужно вспомнить японское слово или чтение
+    let typed_answer = typed_answers && card.direction != ReviewDirection::Recognition;
+    let checked = answer_check
+        .read()
+        .clone()
+        .filter(|checked| checked.card_id == card.id);
+    // При проверке чтения фуригана в вопросе выдала бы ответ
+    let question_furigana = show_furigana && card.direction != ReviewDirection::Reading;
+    let question_title = match card.direction {
+        ReviewDirection::Recognition => "Вопрос",
+        ReviewDirection::Production => "Вспомните слово",
+        ReviewDirection::Reading => "Вспомните чтение",
+    };
+
     match current_step {
         LearnStep::Question => rsx! {
             VocabularyQuestionView {
                 question: card.question,
-                show_furigana,
+                title: question_title.to_string(),
+                show_furigana: question_furigana,
+                typed_answer,
+                card_id: card.id.clone(),
+                direction: card.direction,
+                answer_check,
                 on_show_answer,
             }
         },
         LearnStep::Answer => rsx! {
             VocabularyAnswerView {
                 card,
+                checked,
                 show_furigana,
                 native_language,
                 on_rate,
@@ -41,7 +67,12 @@ pub fn VocabularyCardView(
 #[component]
 fn VocabularyQuestionView(
     question: String,
+    title: String,
     show_furigana: bool,
+    typed_answer: bool,
+    card_id: String,
+    direction: ReviewDirection,
+    answer_check: Signal<Option<CheckedAnswer>>,
     on_show_answer: EventHandler<()>,
 ) -> Element {
     rsx! {
@@ -53,14 +84,24 @@ fn VocabularyQuestionView(
                     // Question
                     div { class: "space-y-2",
                         div { class: "text-xs text-slate-500 uppercase tracking-wide font-semibold",
-                            "Вопрос"
+                            "{title}"
                         }
                         WordCard { text: question, show_furigana, class: None }
                     }
                 }
 
                 div { class: "space-y-2 flex flex-col h-full",
-                    super::card_display::QuestionActionButtons { on_show_answer }
+                    if typed_answer {
+                        TypedAnswerInput {
+                            key: "{card_id}",
+                            card_id: card_id.clone(),
+                            direction,
+                            answer_check,
+                            on_show_answer,
+                        }
+                    } else {
+                        super::card_display::QuestionActionButtons { on_show_answer }
+                    }
                 }
             }
         }
@@ -70,10 +111,30 @@ fn VocabularyQuestionView(
 #[component]
 fn VocabularyAnswerView(
     card: LearnCard,
+    checked: Option<CheckedAnswer>,
     show_furigana: bool,
     native_language: NativeLanguage,
     on_rate: EventHandler<Rating>,
 ) -> Element {
+    let reading = card
+        .reading
+        .clone()
+        .filter(|_| card.direction == ReviewDirection::Reading);
+    let romaji = reading
+        .as_deref()
+        .map(|reading| kana_to_romaji(reading, RomajiSystem::Hepburn));
+    let examples_text = card
+        .example_phrases
+        .iter()
+        .map(|example| example.text())
+        .collect::<Vec<_>>()
+        .join("\n");
+    // При воспроизведении японское слово находится в ответе
+    let japanese_word = match card.direction {
+        ReviewDirection::Production => card.answer.clone(),
+        _ => card.question.clone(),
+    };
+
     rsx! {
         div { class: "space-y-6",
             div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6",
@@ -100,6 +161,12 @@ fn VocabularyAnswerView(
                                 div { class: "text-xs text-slate-500 uppercase tracking-wide font-semibold",
                                     "Ответ"
                                 }
+                                if let Some(reading) = reading {
+                                    div { class: "text-3xl font-bold", "{reading}" }
+                                }
+                                if let Some(romaji) = romaji {
+                                    div { class: "text-sm text-muted-foreground", "{romaji}" }
+                                }
                                 div { class: "relative",
                                     WordCard {
                                         text: card.answer.clone(),
@@ -113,16 +180,36 @@ fn VocabularyAnswerView(
                         // Middle column: Examples
                         VocabularyExamplesSection { card: card.clone(), show_furigana }
                     }
+
+                    if !card.example_phrases.is_empty() {
+                        GrammarUsedSection {
+                            key: "{card.id}",
+                            text: examples_text,
+                        }
+                    }
                 
                 }
 
                 // Right column: Action buttons
                 div { class: "space-y-2",
+                    if let Some(checked) = checked {
+                        AnswerCheckResult { checked }
+                    }
                     AnswerActionButtons { on_rate }
+                    CardNotesSection {
+                        key: "{card.id}",
+                        card_id: card.id.clone(),
+                        show_furigana,
+                    }
                 }
             }
 
             div { class: "space-y-2",
+                VocabularyConjugationSection {
+                    key: "{card.id}",
+                    word: japanese_word,
+                    native_language: native_language.clone(),
+                }
                 // Kanji info for vocabulary cards
                 if !card.kanji_info.is_empty() {
                     VocabularyKanjiInfoSection {
@@ -210,6 +297,49 @@ fn VocabularyExamplesSection(card: LearnCard, show_furigana: bool) -> Element {
     }
 }
 
+/// Таблица спряжения для глаголов и прилагательных
+#[component]
+fn VocabularyConjugationSection(word: String, native_language: NativeLanguage) -> Element {
+    let mut expanded = use_signal(|| false);
+    // Для остальных частей речи таблица не строится
+    let Ok(conjugation) = GetConjugationTableUseCase::new().execute(&word) else {
+        return rsx! {};
+    };
+
+    rsx! {
+        InfoSection {
+            title: "Спряжение".to_string(),
+            tone: InfoSectionTone::Blue,
+            button {
+                class: "text-sm text-blue-700 hover:underline",
+                onclick: move |_| expanded.toggle(),
+                if expanded() {
+                    "Скрыть"
+                } else {
+                    "Показать все формы"
+                }
+            }
+            if expanded() {
+                table { class: "w-full text-sm",
+                    tbody {
+                        for row in conjugation.rows().iter() {
+                            tr { class: "border-t border-blue-100",
+                                td { class: "py-1 pr-4 text-slate-600",
+                                    "{row.form().title(&native_language)}"
+                                }
+                                td { class: "py-1 pr-4 text-lg", "{row.plain()}" }
+                                td { class: "py-1 text-lg",
+                                    "{row.polite().unwrap_or_default()}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[component]
 fn VocabularyKanjiInfoSection(
     kanji_info: Vec<keikaku::domain::dictionary::KanjiInfo>,
@@ -16,5 +16,11 @@ pub use import::Import;
 mod kanji;
 pub use kanji::Kanji;
 
+mod grammar;
+pub use grammar::Grammar;
+
 mod profile;
 pub use profile::Profile;
+
+mod placement;
+pub use placement::Placement;
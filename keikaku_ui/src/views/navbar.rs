@@ -57,6 +57,14 @@ pub fn Navbar() -> Element {
                                 active_class: Some("bg-muted".to_string()),
                                 "Импорт"
                             }
+                            NavbarItem {
+                                index: 3usize,
+                                value: "grammar".to_string(),
+                                to: Route::Grammar {},
+                                class: Some("px-3 py-2 rounded-md".to_string()),
+                                active_class: Some("bg-muted".to_string()),
+                                "Грамматика"
+                            }
                             NavbarItem {
                                 index: 4usize,
                                 value: "kanji".to_string(),
@@ -0,0 +1,240 @@
+use dioxus::prelude::*;
+use dioxus_primitives::toast::{ToastOptions, use_toast};
+use keikaku::application::use_cases::{
+    apply_placement_result::{ApplyPlacementResultOptions, ApplyPlacementResultUseCase},
+    get_user_info::GetUserInfoUseCase,
+    mark_cards_as_known::MarkAsKnownOptions,
+};
+use keikaku::domain::placement::{PlacementResult, PlacementTest};
+use keikaku::domain::value_objects::NativeLanguage;
+use keikaku::settings::ApplicationEnvironment;
+
+use crate::components::app_ui::{
+    Card, ErrorCard, LoadingState, Paragraph, Pill, SectionHeader, StateTone,
+};
+use crate::components::button::{Button, ButtonVariant};
+use crate::components::switch::{Switch, SwitchThumb};
+use crate::{DEFAULT_USERNAME, ensure_user, to_error};
+
+#[component]
+pub fn Placement() -> Element {
+    let language_resource = use_resource(fetch_native_language);
+    let language_read = language_resource.read();
+
+    match language_read.as_ref() {
+        Some(Ok(native_language)) => rsx! {
+            PlacementContent { native_language: native_language.clone() }
+        },
+        Some(Err(err)) => rsx! {
+            ErrorCard { message: format!("Ошибка загрузки профиля: {}", err) }
+        },
+        None => rsx! {
+            div { class: "bg-bg min-h-screen text-text-main px-6 py-8", "Загрузка..." }
+        },
+    }
+}
+
+#[component]
+fn PlacementContent(native_language: NativeLanguage) -> Element {
+    let mut test = use_signal(|| PlacementTest::new(native_language.clone()));
+
+    rsx! {
+        div { class: "bg-bg min-h-screen text-text-main px-6 py-8 space-y-6",
+            SectionHeader {
+                title: "Тест на уровень".to_string(),
+                subtitle: Some(
+                    "Выберите перевод слова. Если не знаете, нажмите «Не знаю»".to_string(),
+                ),
+                actions: Some(rsx! {
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        class: "w-auto px-6",
+                        onclick: move |_| test.set(PlacementTest::new(native_language.clone())),
+                        "Начать заново"
+                    }
+                }),
+            }
+
+            if test.read().is_finished() {
+                PlacementResultCard { result: test.read().result() }
+            } else {
+                PlacementQuestionCard { test }
+            }
+        }
+    }
+}
+
+#[component]
+fn PlacementQuestionCard(test: Signal<PlacementTest>) -> Element {
+    let Some(question) = test.read().current_question().cloned() else {
+        return rsx! {};
+    };
+    let answered = test.read().answered_count();
+
+    rsx! {
+        Card { class: Some("space-y-6".to_string()),
+            div { class: "flex items-center justify-between",
+                Pill {
+                    text: format!("JLPT {}", question.level().code()),
+                    tone: Some(StateTone::Info),
+                }
+                span { class: "text-sm text-muted-foreground", "Отвечено: {answered}" }
+            }
+
+            div { class: "text-5xl font-bold text-center py-6", "{question.word()}" }
+
+            div { class: "grid grid-cols-1 md:grid-cols-2 gap-3",
+                for (index , option) in question.options().iter().cloned().enumerate() {
+                    Button {
+                        key: "{index}",
+                        variant: ButtonVariant::Outline,
+                        class: "w-full",
+                        onclick: move |_| {
+                            let _ = test.write().answer(Some(index));
+                        },
+                        "{option}"
+                    }
+                }
+            }
+
+            Button {
+                variant: ButtonVariant::Ghost,
+                class: "w-full",
+                onclick: move |_| {
+                    let _ = test.write().answer(None);
+                },
+                "Не знаю"
+            }
+        }
+    }
+}
+
+#[component]
+fn PlacementResultCard(result: PlacementResult) -> Element {
+    let mut set_level = use_signal(|| true);
+    let mut mark_as_known = use_signal(|| true);
+    let mut loading = use_signal(|| false);
+    let toast = use_toast();
+
+    let estimated_level = result.estimated_level().code();
+    let known_levels = result
+        .mastered_levels()
+        .iter()
+        .map(|level| level.code())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let coverage_rows = result
+        .coverage()
+        .iter()
+        .map(|(level, coverage)| (level.code(), format!("{:.0}%", coverage * 100.0)))
+        .collect::<Vec<_>>();
+
+    rsx! {
+        Card { class: Some("space-y-6".to_string()),
+            div { class: "space-y-2",
+                div { class: "text-sm text-muted-foreground", "Рекомендуемый уровень" }
+                div { class: "text-4xl font-bold", "JLPT {estimated_level}" }
+            }
+
+            div { class: "space-y-2",
+                for (level , coverage) in coverage_rows {
+                    div { class: "flex items-center justify-between text-sm",
+                        span { "Уровень {level}" }
+                        span { class: "font-semibold", "{coverage}" }
+                    }
+                }
+            }
+
+            Paragraph { class: Some("text-sm text-slate-600".to_string()),
+                if known_levels.is_empty() {
+                    "Освоенных уровней не найдено."
+                } else {
+                    "Освоенные уровни: {known_levels}. Их слова можно сразу отметить как известные."
+                }
+            }
+
+            div { class: "flex items-center justify-between gap-4",
+                span { class: "text-sm font-medium", "Установить уровень {estimated_level}" }
+                Switch {
+                    aria_label: "Установить уровень",
+                    checked: set_level(),
+                    on_checked_change: move |v| set_level.set(v),
+                    disabled: loading(),
+                    SwitchThumb {}
+                }
+            }
+
+            div { class: "flex items-center justify-between gap-4",
+                span { class: "text-sm font-medium", "Отметить вероятно известные слова" }
+                Switch {
+                    aria_label: "Отметить вероятно известные слова",
+                    checked: mark_as_known(),
+                    on_checked_change: move |v| mark_as_known.set(v),
+                    disabled: loading(),
+                    SwitchThumb {}
+                }
+            }
+
+            if loading() {
+                LoadingState { message: Some("Применение результатов...".to_string()) }
+            } else {
+                Button {
+                    variant: ButtonVariant::Primary,
+                    class: "w-full",
+                    disabled: !set_level() && !mark_as_known(),
+                    onclick: move |_| {
+                        let result = result.clone();
+                        let options = ApplyPlacementResultOptions {
+                            set_level: set_level(),
+                            mark_as_known: mark_as_known().then(MarkAsKnownOptions::default),
+                        };
+                        spawn(async move {
+                            loading.set(true);
+                            match apply_placement(result, options).await {
+                                Ok(msg) => toast.success(msg, ToastOptions::new()),
+                                Err(e) => toast.error(format!("Ошибка: {}", e), ToastOptions::new()),
+                            }
+                            loading.set(false);
+                        });
+                    },
+                    "Применить"
+                }
+            }
+        }
+    }
+}
+
+async fn fetch_native_language() -> Result<NativeLanguage, String> {
+    let env = ApplicationEnvironment::get();
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+    let repo = env.get_repository().await.map_err(to_error)?;
+
+    let profile = GetUserInfoUseCase::new(repo)
+        .execute(user_id)
+        .await
+        .map_err(to_error)?;
+
+    Ok(profile.native_language)
+}
+
+async fn apply_placement(
+    result: PlacementResult,
+    options: ApplyPlacementResultOptions,
+) -> Result<String, String> {
+    let env = ApplicationEnvironment::get();
+    let user_id = ensure_user(env, DEFAULT_USERNAME).await?;
+    let repo = env.get_repository().await.map_err(to_error)?;
+
+    let res = ApplyPlacementResultUseCase::new(repo)
+        .execute(user_id, &result, options)
+        .await
+        .map_err(to_error)?;
+
+    Ok(format!(
+        "Уровень {}: создано {}, отмечено известными {}, пропущено {}",
+        res.current_japanese_level.code(),
+        res.created_count,
+        res.known_count,
+        res.skipped_words.len()
+    ))
+}
@@ -1,9 +1,11 @@
 use dioxus::prelude::*;
-use keikaku::domain::UserSettings;
+use keikaku::domain::{FuriganaMode, UserSettings};
 
 use crate::components::app_ui::{Card, SectionHeader};
 use crate::components::button::{Button, ButtonVariant};
 use crate::components::input::Input;
+use crate::components::switch::{Switch, SwitchThumb};
+use crate::domain::ReviewDirectionsField;
 use crate::views::profile::forms::LlmSettingsForm;
 
 #[component]
@@ -19,6 +21,8 @@ pub fn SettingsForm(
             .unwrap_or_default()
             .to_string()
     });
+    let mut review_directions = use_signal(|| settings.vocabulary_review_directions());
+    let mut furigana_mode = use_signal(|| settings.furigana_mode());
 
     rsx! {
         div { class: "space-y-6",
@@ -59,15 +63,57 @@ pub fn SettingsForm(
                 }
             }
 
+            Card { class: Some("space-y-4".to_string()),
+                SectionHeader {
+                    title: "Повторение слов".to_string(),
+                    subtitle: Some(
+                        "Каждое направление планируется отдельно. Для отдельной карточки направления можно изменить при редактировании"
+                            .to_string(),
+                    ),
+                    actions: None,
+                }
+
+                ReviewDirectionsField {
+                    directions: review_directions(),
+                    on_change: move |directions| review_directions.set(directions),
+                }
+            }
+
+            Card { class: Some("space-y-4".to_string()),
+                SectionHeader {
+                    title: "Фуригана".to_string(),
+                    subtitle: Some(
+                        "Кандзи считается знакомым, если выучена его карточка или он встречается в трех выученных словах"
+                            .to_string(),
+                    ),
+                    actions: None,
+                }
+
+                div { class: "flex items-center justify-between gap-4",
+                    span { class: "text-sm font-medium", "Только над незнакомыми кандзи" }
+                    Switch {
+                        aria_label: "Только над незнакомыми кандзи",
+                        checked: furigana_mode() == FuriganaMode::UnknownKanji,
+                        on_checked_change: move |v: bool| {
+                            furigana_mode
+                                .set(if v { FuriganaMode::UnknownKanji } else { FuriganaMode::All });
+                        },
+                        SwitchThumb {}
+                    }
+                }
+            }
+
             div { class: "flex justify-end",
                 Button {
                     variant: ButtonVariant::Primary,
                     disabled: loading,
                     onclick: move |_| {
-                        let new_settings = UserSettings::new(
+                        let mut new_settings = UserSettings::new(
                             llm_settings(),
                             Some(duolingo_token()).filter(|s| !s.trim().is_empty()),
                         );
+                        new_settings.set_vocabulary_review_directions(review_directions());
+                        new_settings.set_furigana_mode(furigana_mode());
                         on_save.call(new_settings);
                     },
                     if loading {
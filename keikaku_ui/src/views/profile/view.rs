@@ -3,7 +3,9 @@ use dioxus_primitives::toast::{ToastOptions, use_toast};
 use keikaku::application::UserRepository;
 use keikaku::domain::UserSettings;
 
+use crate::Route;
 use crate::components::app_ui::{ErrorCard, SectionHeader};
+use crate::components::button::{Button, ButtonVariant};
 use crate::views::profile::SettingsForm;
 use crate::{DEFAULT_USERNAME, ensure_user, to_error};
 use keikaku::settings::ApplicationEnvironment;
@@ -70,7 +72,13 @@ fn ProfileContent(
             SectionHeader {
                 title: "Профиль пользователя".to_string(),
                 subtitle: Some("Настройки приложения и сервисов".to_string()),
-                actions: None,
+                actions: Some(rsx! {
+                    Link { to: Route::Placement {},
+                        Button { variant: ButtonVariant::Outline, class: "w-auto px-6",
+                            "Тест на уровень"
+                        }
+                    }
+                }),
             }
 
             SettingsForm { settings, on_save, loading }
@@ -104,6 +112,8 @@ async fn save_user_settings(settings: UserSettings) -> Result<(), String> {
     let request = UpdateUserSettingsRequest {
         llm: Some(settings.llm().clone()),
         duolingo_jwt_token: Some(settings.duolingo_jwt_token().map(|s| s.to_string())),
+        vocabulary_review_directions: Some(settings.vocabulary_review_directions()),
+        furigana_mode: Some(settings.furigana_mode()),
     };
 
     UpdateUserSettingsUseCase::new(repo)